@@ -3,6 +3,16 @@
 use crate::core::{DayType, DiffType, Fields, MonthType};
 use crate::YearType;
 
+/// Unwraps an `Option` in const functions, returns `None` if it is `None`.
+macro_rules! try_opt {
+    ($e: expr) => {
+        match $e {
+            Some(v) => v,
+            None => return None,
+        }
+    };
+}
+
 // TODO(evenyag): Use trait once rust supports declaring const functions in trait.
 // See issue #57563 <https://github.com/rust-lang/rust/issues/57563>
 
@@ -27,6 +37,23 @@ impl Second {
         scale_add(Minute::difference(f1, f2), 60, (f1.ss - f2.ss) as DiffType)
     }
 
+    /// Increments the indicated (normalized) field by "n", wrapping the year
+    /// around on overflow.
+    pub(crate) const fn overflowing_step(f: Fields, n: DiffType) -> (Fields, bool) {
+        let (base, f) = split_year(f);
+        join_year(base, Self::step(f, n))
+    }
+
+    /// Returns the difference between fields structs using the indicated unit,
+    /// or `None` on overflow.
+    pub(crate) const fn checked_difference(f1: Fields, f2: Fields) -> Option<DiffType> {
+        checked_scale_add(
+            try_opt!(Minute::checked_difference(f1, f2)),
+            60,
+            (f1.ss - f2.ss) as DiffType,
+        )
+    }
+
     /// Aligns the (normalized) fields struct to the indicated field.
     pub(crate) const fn align(f: Fields) -> Fields {
         f
@@ -55,6 +82,23 @@ impl Minute {
         scale_add(Hour::difference(f1, f2), 60, (f1.mm - f2.mm) as DiffType)
     }
 
+    /// Increments the indicated (normalized) field by "n", wrapping the year
+    /// around on overflow.
+    pub(crate) const fn overflowing_step(f: Fields, n: DiffType) -> (Fields, bool) {
+        let (base, f) = split_year(f);
+        join_year(base, Self::step(f, n))
+    }
+
+    /// Returns the difference between fields structs using the indicated unit,
+    /// or `None` on overflow.
+    pub(crate) const fn checked_difference(f1: Fields, f2: Fields) -> Option<DiffType> {
+        checked_scale_add(
+            try_opt!(Hour::checked_difference(f1, f2)),
+            60,
+            (f1.mm - f2.mm) as DiffType,
+        )
+    }
+
     /// Aligns the (normalized) fields struct to the indicated field.
     pub(crate) const fn align(f: Fields) -> Fields {
         Fields {
@@ -90,6 +134,23 @@ impl Hour {
         scale_add(Day::difference(f1, f2), 24, (f1.hh - f2.hh) as DiffType)
     }
 
+    /// Increments the indicated (normalized) field by "n", wrapping the year
+    /// around on overflow.
+    pub(crate) const fn overflowing_step(f: Fields, n: DiffType) -> (Fields, bool) {
+        let (base, f) = split_year(f);
+        join_year(base, Self::step(f, n))
+    }
+
+    /// Returns the difference between fields structs using the indicated unit,
+    /// or `None` on overflow.
+    pub(crate) const fn checked_difference(f1: Fields, f2: Fields) -> Option<DiffType> {
+        checked_scale_add(
+            try_opt!(Day::checked_difference(f1, f2)),
+            24,
+            (f1.hh - f2.hh) as DiffType,
+        )
+    }
+
     /// Aligns the (normalized) fields struct to the indicated field.
     pub(crate) const fn align(f: Fields) -> Fields {
         Fields {
@@ -117,6 +178,19 @@ impl Day {
         day_difference(f1.y, f1.m, f1.d, f2.y, f2.m, f2.d)
     }
 
+    /// Increments the indicated (normalized) field by "n", wrapping the year
+    /// around on overflow.
+    pub(crate) const fn overflowing_step(f: Fields, n: DiffType) -> (Fields, bool) {
+        let (base, f) = split_year(f);
+        join_year(base, Self::step(f, n))
+    }
+
+    /// Returns the difference between fields structs using the indicated unit,
+    /// or `None` on overflow.
+    pub(crate) const fn checked_difference(f1: Fields, f2: Fields) -> Option<DiffType> {
+        checked_day_difference(f1.y, f1.m, f1.d, f2.y, f2.m, f2.d)
+    }
+
     /// Aligns the (normalized) fields struct to the indicated field.
     pub(crate) const fn align(f: Fields) -> Fields {
         Fields {
@@ -152,6 +226,23 @@ impl Month {
         scale_add(Year::difference(f1, f2), 12, (f1.m - f2.m) as DiffType)
     }
 
+    /// Increments the indicated (normalized) field by "n", wrapping the year
+    /// around on overflow.
+    pub(crate) const fn overflowing_step(f: Fields, n: DiffType) -> (Fields, bool) {
+        let (base, f) = split_year(f);
+        join_year(base, Self::step(f, n))
+    }
+
+    /// Returns the difference between fields structs using the indicated unit,
+    /// or `None` on overflow.
+    pub(crate) const fn checked_difference(f1: Fields, f2: Fields) -> Option<DiffType> {
+        checked_scale_add(
+            try_opt!(Year::checked_difference(f1, f2)),
+            12,
+            (f1.m - f2.m) as DiffType,
+        )
+    }
+
     /// Aligns the (normalized) fields struct to the indicated field.
     pub(crate) const fn align(f: Fields) -> Fields {
         Fields {
@@ -180,6 +271,20 @@ impl Year {
         f1.y - f2.y
     }
 
+    /// Increments the indicated (normalized) field by "n", wrapping the year
+    /// around on overflow.
+    pub(crate) const fn overflowing_step(mut f: Fields, n: DiffType) -> (Fields, bool) {
+        let (y, overflow) = f.y.overflowing_add(n);
+        f.y = y;
+        (f, overflow)
+    }
+
+    /// Returns the difference between fields structs using the indicated unit,
+    /// or `None` on overflow.
+    pub(crate) const fn checked_difference(f1: Fields, f2: Fields) -> Option<DiffType> {
+        f1.y.checked_sub(f2.y)
+    }
+
     /// Aligns the (normalized) fields struct to the indicated field.
     pub(crate) const fn align(f: Fields) -> Fields {
         Fields {
@@ -202,6 +307,32 @@ const fn scale_add(v: DiffType, f: DiffType, a: DiffType) -> DiffType {
     }
 }
 
+/// Returns (v * f + a) like [scale_add], or `None` on overflow.
+const fn checked_scale_add(v: DiffType, f: DiffType, a: DiffType) -> Option<DiffType> {
+    if v < 0 {
+        try_opt!(try_opt!((v + 1).checked_mul(f)).checked_add(a)).checked_sub(f)
+    } else {
+        try_opt!(try_opt!((v - 1).checked_mul(f)).checked_add(a)).checked_add(f)
+    }
+}
+
+/// Splits the year of the (normalized) fields into a multiple of 400 and the
+/// fields in the remaining year, which share the calendar of the Gregorian
+/// 400-year cycle, so the fields can be stepped without overflow.
+const fn split_year(mut f: Fields) -> (YearType, Fields) {
+    let base = f.y - f.y % 400;
+    f.y -= base;
+    (base, f)
+}
+
+/// Joins the year split by [split_year], wrapping the year around on
+/// overflow.
+const fn join_year(base: YearType, mut f: Fields) -> (Fields, bool) {
+    let (y, overflow) = base.overflowing_add(f.y);
+    f.y = y;
+    (f, overflow)
+}
+
 /// Map a (normalized) Y/M/D to the number of days before/after 1970-01-01.
 /// Probably overflows for years outside [-292277022656:292277026595].
 const fn ymd_ord(y: YearType, m: MonthType, d: DayType) -> DiffType {
@@ -240,3 +371,27 @@ const fn day_difference(
     }
     (c4_diff / 400 * 146097) + delta
 }
+
+/// Returns the difference in days like [day_difference], or `None` on
+/// overflow.
+const fn checked_day_difference(
+    y1: YearType,
+    m1: MonthType,
+    d1: DayType,
+    y2: YearType,
+    m2: MonthType,
+    d2: DayType,
+) -> Option<DiffType> {
+    let a_c4_off = y1 % 400;
+    let b_c4_off = y2 % 400;
+    let mut c4_diff = try_opt!((y1 - a_c4_off).checked_sub(y2 - b_c4_off));
+    let mut delta = ymd_ord(a_c4_off, m1, d1) - ymd_ord(b_c4_off, m2, d2);
+    if c4_diff > 0 && delta < 0 {
+        delta += 2 * 146097;
+        c4_diff -= 2 * 400;
+    } else if c4_diff < 0 && delta > 0 {
+        delta -= 2 * 146097;
+        c4_diff += 2 * 400;
+    }
+    try_opt!((c4_diff / 400).checked_mul(146097)).checked_add(delta)
+}
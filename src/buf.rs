@@ -0,0 +1,279 @@
+//! Allocation-free formatting of civil times into byte buffers.
+
+use crate::core::Fields;
+use crate::{CivilDay, CivilHour, CivilMinute, CivilMonth, CivilSecond, CivilYear};
+use std::fmt;
+use std::ops::Deref;
+
+/// Maximum length of the layout printed by the `Debug` impls, which is the
+/// length of "-9223372036854775808-12-31T23:59:59".
+pub const MAX_ISO_LEN: usize = 35;
+
+/// Two-digit representations of the numbers from 0 to 99.
+const DIGIT_PAIRS: &[u8; 200] = b"\
+    0001020304050607080910111213141516171819\
+    2021222324252627282930313233343536373839\
+    4041424344454647484950515253545556575859\
+    6061626364656667686970717273747576777879\
+    8081828384858687888990919293949596979899";
+
+/// Separators written before each field.
+const SEPARATORS: [u8; 6] = [0, b'-', b'-', b'T', b':', b':'];
+
+/// Writes the two digits of `v` (from 0 to 99) at `pos`.
+fn write_pair(buf: &mut [u8], pos: usize, v: usize) {
+    buf[pos] = DIGIT_PAIRS[v * 2];
+    buf[pos + 1] = DIGIT_PAIRS[v * 2 + 1];
+}
+
+/// Returns the length of the first `n` fields (from year to second) of
+/// `fields` in the layout printed by the `Debug` impls.
+fn iso_len(fields: Fields, n: usize) -> usize {
+    let mut digits = 1;
+    let mut y = fields.y.unsigned_abs();
+    while y >= 10 {
+        y /= 10;
+        digits += 1;
+    }
+    (fields.y < 0) as usize + digits + 3 * (n - 1)
+}
+
+/// Writes the first `n` fields (from year to second) of `fields` in the
+/// layout printed by the `Debug` impls, returns the number of bytes written,
+/// or `None` if `buf` is too short.
+fn write_fields(buf: &mut [u8], fields: Fields, n: usize) -> Option<usize> {
+    let len = iso_len(fields, n);
+    let buf = buf.get_mut(..len)?;
+
+    // Writes the digits of the year backwards, ending before the month.
+    let mut pos = len - 3 * (n - 1);
+    let mut y = fields.y.unsigned_abs();
+    while y >= 100 {
+        pos -= 2;
+        write_pair(buf, pos, (y % 100) as usize);
+        y /= 100;
+    }
+    if y >= 10 {
+        pos -= 2;
+        write_pair(buf, pos, y as usize);
+    } else {
+        pos -= 1;
+        buf[pos] = b'0' + y as u8;
+    }
+    if fields.y < 0 {
+        buf[0] = b'-';
+    }
+
+    let mut pos = len - 3 * (n - 1);
+    let values = [0, fields.m, fields.d, fields.hh, fields.mm, fields.ss];
+    for i in 1..n {
+        buf[pos] = SEPARATORS[i];
+        write_pair(buf, pos + 1, values[i] as usize);
+        pos += 3;
+    }
+    Some(len)
+}
+
+/// A civil time formatted on the stack in the layout printed by the `Debug`
+/// impls, e.g. "2015-02-03T04:05:06".
+///
+/// ```rust
+/// use civil_time::CivilSecond;
+///
+/// let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+/// let buf = ss.to_iso_buf();
+/// assert_eq!("2015-02-03T04:05:06", buf.as_str());
+/// assert_eq!(format!("{:?}", ss), buf.as_str());
+/// ```
+#[derive(Clone, Copy)]
+pub struct IsoBuf {
+    bytes: [u8; MAX_ISO_LEN],
+    len: usize,
+}
+
+impl IsoBuf {
+    /// Returns the formatted civil time.
+    pub fn as_str(&self) -> &str {
+        // Only ASCII digits and separators are written.
+        std::str::from_utf8(self.as_bytes()).unwrap()
+    }
+
+    /// Returns the bytes of the formatted civil time.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl Deref for IsoBuf {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for IsoBuf {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for IsoBuf {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl fmt::Display for IsoBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for IsoBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+macro_rules! impl_write_iso {
+    ($Type: ident, $n: expr) => {
+        impl $Type {
+            /// Formats the civil time on the stack in the layout printed by
+            /// the `Debug` impl, without allocation.
+            pub fn to_iso_buf(&self) -> IsoBuf {
+                let mut buf = IsoBuf {
+                    bytes: [0; MAX_ISO_LEN],
+                    len: 0,
+                };
+                // A civil time always fits in MAX_ISO_LEN bytes.
+                buf.len = write_fields(&mut buf.bytes, self.0, $n).unwrap();
+                buf
+            }
+
+            /// Writes the civil time into `buf` in the layout printed by the
+            /// `Debug` impl, returns the number of bytes written.
+            ///
+            /// Returns `None` and leaves `buf` untouched if it is too short, a
+            /// buffer of [MAX_ISO_LEN] bytes always suffices.
+            ///
+            /// ```rust
+            /// use civil_time::{CivilDay, MAX_ISO_LEN};
+            ///
+            /// let mut buf = [0; MAX_ISO_LEN];
+            /// let len = CivilDay::new(2015, 2, 3).write_iso_to(&mut buf).unwrap();
+            /// assert_eq!(b"2015-02-03", &buf[..len]);
+            /// assert_eq!(None, CivilDay::new(2015, 2, 3).write_iso_to(&mut buf[..9]));
+            /// ```
+            pub fn write_iso_to(&self, buf: &mut [u8]) -> Option<usize> {
+                write_fields(buf, self.0, $n)
+            }
+        }
+    };
+}
+
+impl_write_iso!(CivilSecond, 6);
+impl_write_iso!(CivilMinute, 5);
+impl_write_iso!(CivilHour, 4);
+impl_write_iso!(CivilDay, 3);
+impl_write_iso!(CivilMonth, 2);
+impl_write_iso!(CivilYear, 1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DiffType;
+
+    macro_rules! check_all {
+        ($v: expr) => {{
+            let v = $v;
+            assert_eq!(format!("{:?}", v), v.to_iso_buf().as_str());
+            let mm = CivilMinute::from(v);
+            assert_eq!(format!("{:?}", mm), mm.to_iso_buf().as_str());
+            let hh = CivilHour::from(v);
+            assert_eq!(format!("{:?}", hh), hh.to_iso_buf().as_str());
+            let d = CivilDay::from(v);
+            assert_eq!(format!("{:?}", d), d.to_iso_buf().as_str());
+            let m = CivilMonth::from(v);
+            assert_eq!(format!("{:?}", m), m.to_iso_buf().as_str());
+            let y = CivilYear::from(v);
+            assert_eq!(format!("{:?}", y), y.to_iso_buf().as_str());
+        }};
+    }
+
+    #[test]
+    fn test_to_iso_buf() {
+        let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+        let buf = ss.to_iso_buf();
+        assert_eq!("2015-02-03T04:05:06", buf.as_str());
+        assert_eq!(b"2015-02-03T04:05:06", buf.as_bytes());
+        assert_eq!("2015-02-03T04:05:06", buf.to_string());
+        assert_eq!("\"2015-02-03T04:05:06\"", format!("{:?}", buf));
+        assert_eq!(19, buf.len());
+
+        assert_eq!(MAX_ISO_LEN, CivilSecond::MIN.to_iso_buf().len());
+        assert_eq!(
+            "-9223372036854775808-01-01T00:00:00",
+            CivilSecond::MIN.to_iso_buf().as_str()
+        );
+    }
+
+    #[test]
+    fn test_write_iso_to() {
+        let mut buf = [b'x'; MAX_ISO_LEN + 1];
+        let len = CivilMinute::new(2015, 2, 3, 4, 5)
+            .write_iso_to(&mut buf)
+            .unwrap();
+        assert_eq!(b"2015-02-03T04:05x", &buf[..len + 1]);
+        let len = CivilYear::new(-1).write_iso_to(&mut buf).unwrap();
+        assert_eq!(b"-1", &buf[..len]);
+        let len = CivilSecond::MIN.write_iso_to(&mut buf).unwrap();
+        assert_eq!(b"-9223372036854775808-01-01T00:00:00x", &buf[..]);
+        assert_eq!(MAX_ISO_LEN, len);
+
+        let mut buf = [0; 10];
+        assert_eq!(Some(10), CivilDay::new(2015, 2, 3).write_iso_to(&mut buf));
+    }
+
+    #[test]
+    fn test_write_iso_to_short_buffer() {
+        let mut buf = [b'x'; 9];
+        assert_eq!(None, CivilDay::new(2015, 2, 3).write_iso_to(&mut buf));
+        assert_eq!(b"xxxxxxxxx", &buf);
+        assert_eq!(None, CivilYear::new(-1).write_iso_to(&mut buf[..1]));
+        assert_eq!(
+            None,
+            CivilSecond::MIN.write_iso_to(&mut [0; MAX_ISO_LEN - 1])
+        );
+        assert_eq!(None, CivilYear::new(0).write_iso_to(&mut []));
+    }
+
+    #[test]
+    fn test_same_as_debug() {
+        // Every second of a day.
+        let start = CivilSecond::new(2015, 2, 3, 0, 0, 0);
+        for i in 0..86400 {
+            let ss = start + i;
+            assert_eq!(format!("{:?}", ss), ss.to_iso_buf().as_str());
+        }
+
+        // Every day of a leap year.
+        let start = CivilDay::new(2016, 1, 1);
+        for i in 0..366 {
+            check_all!(CivilSecond::from(start + i));
+        }
+
+        // Years of every number of digits.
+        let mut y: DiffType = 1;
+        while let Some(next) = y.checked_mul(10) {
+            check_all!(CivilSecond::new(y - 1, 12, 31, 23, 59, 59));
+            check_all!(CivilSecond::new(y, 1, 1, 0, 0, 0));
+            check_all!(CivilSecond::new(-y, 1, 1, 0, 0, 0));
+            check_all!(CivilSecond::new(1 - y, 12, 31, 23, 59, 59));
+            y = next;
+        }
+        check_all!(CivilSecond::new(0, 1, 1, 0, 0, 0));
+        check_all!(CivilSecond::MAX);
+        check_all!(CivilSecond::MIN);
+    }
+}
@@ -0,0 +1,511 @@
+//! Bulk parsing of fixed-layout timestamp columns.
+
+use crate::core::{self, DiffType, Field, MonthType};
+use crate::format::{scan_item, FormatError, Item, Spec};
+use crate::parse::{check_range, Expected, ParseError, ParseErrorKind};
+use crate::CivilSecond;
+
+/// Number of rows per word of the error bitmap.
+const BITS: usize = u64::BITS as usize;
+
+/// Fields of a layout from year to second, and their widths.
+const FIELDS: [(Field, usize); 6] = [
+    (Field::Year, 4),
+    (Field::Month, 2),
+    (Field::Day, 2),
+    (Field::Hour, 2),
+    (Field::Minute, 2),
+    (Field::Second, 2),
+];
+
+/// A fixed layout of timestamps for bulk parsing, e.g. "%Y-%m-%d %H:%M:%S".
+///
+/// The layout is a strftime-like pattern of the directives `%Y` (exactly 4
+/// digits), `%m`, `%d`, `%H`, `%M` and `%S` (exactly 2 digits each), and
+/// literal text. Every row has the same length and the same positions of
+/// fields, so a row is validated by a single pass over its bytes without
+/// branching on the content.
+///
+/// Fields not in the layout take their default values (1970-01-01 00:00:00).
+///
+/// ```rust
+/// use civil_time::{CivilSecond, FixedLayout};
+///
+/// let layout = FixedLayout::new("%Y-%m-%d %H:%M:%S").unwrap();
+/// let rows = ["2015-02-03 04:05:06", "2015-02-30 04:05:06", "2016-02-29 23:59:59"];
+/// let parsed = layout.parse_bulk(&rows);
+/// assert_eq!(CivilSecond::new(2015, 2, 3, 4, 5, 6), parsed.values()[0]);
+/// assert_eq!(CivilSecond::new(2016, 2, 29, 23, 59, 59), parsed.values()[2]);
+/// assert!(parsed.is_error(1));
+/// assert_eq!(vec![1], parsed.error_rows().collect::<Vec<_>>());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedLayout {
+    /// Expected bytes of each row, digits are '0'.
+    template: Vec<u8>,
+    /// Whether each byte of a row is a digit.
+    digits: Vec<bool>,
+    /// Byte offsets of the fields from year to second.
+    offsets: [Option<usize>; 6],
+}
+
+impl FixedLayout {
+    /// Compiles the `layout`. Directives other than `%Y`, `%m`, `%d`, `%H`,
+    /// `%M` and `%S` are rejected as unknown, and a field appearing more than
+    /// once is rejected as a duplicate.
+    ///
+    /// ```rust
+    /// use civil_time::{FixedLayout, FormatError};
+    ///
+    /// assert!(FixedLayout::new("%Y%m%d").is_ok());
+    /// assert_eq!(
+    ///     FormatError::UnknownDirective { offset: 3, directive: 'b' },
+    ///     FixedLayout::new("%d %b %Y").unwrap_err()
+    /// );
+    /// assert_eq!(
+    ///     FormatError::DuplicateField { offset: 9 },
+    ///     FixedLayout::new("%Y-%m-%d %Y").unwrap_err()
+    /// );
+    /// ```
+    pub fn new(layout: &str) -> Result<Self, FormatError> {
+        let mut template = Vec::new();
+        let mut offsets = [None; 6];
+        let mut pos = 0;
+        while pos < layout.len() {
+            let (item, end) = scan_item(layout, pos)?;
+            match item {
+                Item::Literal(s) => template.extend_from_slice(s.as_bytes()),
+                Item::Spec(spec) => {
+                    let (i, width) = match spec {
+                        Spec::Year => (0, 4),
+                        Spec::Month => (1, 2),
+                        Spec::Day => (2, 2),
+                        Spec::Hour => (3, 2),
+                        Spec::Minute => (4, 2),
+                        Spec::Second => (5, 2),
+                        _ => (6, 0),
+                    };
+                    if i == 6 {
+                        return Err(FormatError::UnknownDirective {
+                            offset: pos,
+                            directive: layout[pos + 1..].chars().next().unwrap_or('%'),
+                        });
+                    }
+                    if offsets[i].is_some() {
+                        return Err(FormatError::DuplicateField { offset: pos });
+                    }
+                    offsets[i] = Some(template.len());
+                    template.resize(template.len() + width, b'0');
+                }
+            }
+            pos = end;
+        }
+
+        let mut digits = vec![false; template.len()];
+        for (i, &(_, width)) in FIELDS.iter().enumerate() {
+            if let Some(offset) = offsets[i] {
+                for digit in &mut digits[offset..offset + width] {
+                    *digit = true;
+                }
+            }
+        }
+
+        Ok(FixedLayout {
+            template,
+            digits,
+            offsets,
+        })
+    }
+
+    /// Returns the length of every row.
+    pub fn len(&self) -> usize {
+        self.template.len()
+    }
+
+    /// Returns whether the layout is empty, i.e. only matches empty rows.
+    pub fn is_empty(&self) -> bool {
+        self.template.is_empty()
+    }
+
+    /// Returns whether the bytes of `row` match the layout, i.e. the literals
+    /// are the same and the fields are digits.
+    fn matches(&self, row: &[u8]) -> bool {
+        if row.len() != self.template.len() {
+            return false;
+        }
+        let mut bad = false;
+        for ((&b, &t), &digit) in row.iter().zip(&self.template).zip(&self.digits) {
+            bad |= (digit & (b.wrapping_sub(b'0') > 9)) | (!digit & (b != t));
+        }
+        !bad
+    }
+
+    /// Returns the value of the field `i` from the digits of `row`.
+    fn field(&self, row: &[u8], i: usize, default: DiffType) -> DiffType {
+        let offset = match self.offsets[i] {
+            Some(offset) => offset,
+            None => return default,
+        };
+        row[offset..offset + FIELDS[i].1]
+            .iter()
+            .fold(0, |v, &b| v * 10 + (b - b'0') as DiffType)
+    }
+
+    /// Parses a single row, returns `None` if it is malformed or out of range.
+    fn parse_row(&self, row: &[u8]) -> Option<CivilSecond> {
+        if !self.matches(row) {
+            return None;
+        }
+        let y = self.field(row, 0, 1970);
+        let m = self.field(row, 1, 1);
+        let d = self.field(row, 2, 1);
+        let hh = self.field(row, 3, 0);
+        let mm = self.field(row, 4, 0);
+        let ss = self.field(row, 5, 0);
+        // The digits make the fields non-negative.
+        let in_range = (1..=12).contains(&m) && hh < 24 && mm < 60 && ss < 60 && d >= 1;
+        if !in_range || d > core::days_per_month(y, m as MonthType) {
+            return None;
+        }
+        // The fields are in range, which takes the fast path of normalization.
+        Some(CivilSecond::from_ymd_hms(y, m, d, hh, mm, ss))
+    }
+
+    /// Returns the field whose digits are at the byte `pos` of a row.
+    fn field_at(&self, pos: usize) -> Option<Field> {
+        FIELDS
+            .iter()
+            .zip(&self.offsets)
+            .find_map(|(&(field, width), offset)| match *offset {
+                Some(offset) if offset <= pos && pos < offset + width => Some(field),
+                _ => None,
+            })
+    }
+
+    /// Parses a single row, and locates the error in the row if it fails.
+    ///
+    /// Unlike [`parse_bulk()`](Self::parse_bulk), the row is checked byte by
+    /// byte, so that the error tells which byte or field is wrong.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilSecond, Expected, Field, FixedLayout, ParseErrorKind};
+    ///
+    /// let layout = FixedLayout::new("%Y-%m-%d %H:%M:%S").unwrap();
+    /// let ss = layout.parse(b"2015-02-03 04:05:06").unwrap();
+    /// assert_eq!(CivilSecond::new(2015, 2, 3, 4, 5, 6), ss);
+    ///
+    /// let e = layout.parse(b"2015-02-30 04:05:06").unwrap_err();
+    /// assert_eq!(ParseErrorKind::OutOfRange, e.kind());
+    /// assert_eq!(Some(Field::Day), e.field());
+    /// assert_eq!(8..10, e.span());
+    /// assert_eq!(Some(Expected::Range { min: 1, max: 28 }), e.expected());
+    /// ```
+    pub fn parse(&self, row: &[u8]) -> Result<CivilSecond, ParseError> {
+        for (pos, (&t, &digit)) in self.template.iter().zip(&self.digits).enumerate() {
+            let (field, expected) = if digit {
+                (self.field_at(pos), Expected::Digit)
+            } else {
+                (None, Expected::Char(t as char))
+            };
+            let kind = match row.get(pos) {
+                None => ParseErrorKind::TooShort,
+                Some(b) if digit && !b.is_ascii_digit() => ParseErrorKind::Invalid,
+                Some(&b) if !digit && b != t => ParseErrorKind::Invalid,
+                Some(_) => continue,
+            };
+            let end = (pos + 1).min(row.len());
+            return Err(ParseError::new(kind, field)
+                .at(pos.min(row.len()), end)
+                .expecting(expected));
+        }
+        if row.len() > self.template.len() {
+            return Err(ParseError::new(ParseErrorKind::TooLong, None)
+                .at(self.template.len(), row.len())
+                .expecting(Expected::End));
+        }
+
+        let mut values = [1970, 1, 1, 0, 0, 0];
+        for (i, v) in values.iter_mut().enumerate() {
+            *v = self.field(row, i, *v);
+        }
+        let [y, m, d, hh, mm, ss] = values;
+        let days = core::days_per_month(y, m.clamp(1, 12) as MonthType);
+        let ranges = [(1, 12), (1, days), (0, 23), (0, 59), (0, 59)];
+        for (i, &(min, max)) in ranges.iter().enumerate() {
+            let (field, width) = FIELDS[i + 1];
+            if let Err(e) = check_range(values[i + 1], min, max, field) {
+                // Fields not in the layout take their defaults, which are in
+                // range.
+                let offset = self.offsets[i + 1].unwrap_or(0);
+                return Err(e.at(offset, offset + width));
+            }
+        }
+        Ok(CivilSecond::from_ymd_hms(y, m, d, hh, mm, ss))
+    }
+
+    /// Parses each of the `rows`, failed rows are marked in the error bitmap
+    /// of the result and take the default value. Use
+    /// [`parse()`](Self::parse) to find out why a row failed.
+    pub fn parse_bulk<R: AsRef<[u8]>>(&self, rows: &[R]) -> BulkParsed {
+        let mut values = Vec::with_capacity(rows.len());
+        let mut errors = vec![0u64; rows.len().div_ceil(BITS)];
+        for (i, row) in rows.iter().enumerate() {
+            let value = self.parse_row(row.as_ref());
+            errors[i / BITS] |= (value.is_none() as u64) << (i % BITS);
+            values.push(value.unwrap_or_default());
+        }
+
+        BulkParsed { values, errors }
+    }
+}
+
+/// Result of [`FixedLayout::parse_bulk()`], parsed values with a per-row
+/// error bitmap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BulkParsed {
+    values: Vec<CivilSecond>,
+    errors: Vec<u64>,
+}
+
+impl BulkParsed {
+    /// Returns the parsed values, one per row. Failed rows take the default
+    /// value 1970-01-01 00:00:00.
+    pub fn values(&self) -> &[CivilSecond] {
+        &self.values
+    }
+
+    /// Consumes the result and returns the parsed values, `None` for the
+    /// failed rows.
+    pub fn into_values(self) -> Vec<Option<CivilSecond>> {
+        let errors = self.errors;
+        self.values
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| (errors[i / BITS] & (1 << (i % BITS)) == 0).then_some(v))
+            .collect()
+    }
+
+    /// Returns the error bitmap, where bit `i % 64` of word `i / 64` is set if
+    /// the row `i` failed.
+    pub fn error_bitmap(&self) -> &[u64] {
+        &self.errors
+    }
+
+    /// Returns whether the row `i` failed, panics if `i` is out of bounds.
+    pub fn is_error(&self, i: usize) -> bool {
+        assert!(i < self.values.len(), "row out of bounds");
+        self.errors[i / BITS] & (1 << (i % BITS)) != 0
+    }
+
+    /// Returns the number of failed rows.
+    pub fn error_count(&self) -> usize {
+        self.errors.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns an iterator over the indices of failed rows.
+    pub fn error_rows(&self) -> impl Iterator<Item = usize> + '_ {
+        self.errors.iter().enumerate().flat_map(|(i, &w)| {
+            (0..BITS)
+                .filter(move |bit| w & (1 << bit) != 0)
+                .map(move |bit| i * BITS + bit)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::expect_eq;
+    use crate::FormatError;
+
+    #[test]
+    fn test_layout() {
+        let layout = FixedLayout::new("%Y-%m-%d %H:%M:%S").unwrap();
+        assert_eq!(19, layout.len());
+        assert!(FixedLayout::new("").unwrap().is_empty());
+
+        let check = |layout: &str, offset, directive| {
+            assert_eq!(
+                FormatError::UnknownDirective { offset, directive },
+                FixedLayout::new(layout).unwrap_err()
+            );
+        };
+        check("%Y-%j", 3, 'j');
+        check("%y%m%d", 0, 'y');
+        assert_eq!(
+            FormatError::IncompleteDirective { offset: 2 },
+            FixedLayout::new("%Y%").unwrap_err()
+        );
+        assert_eq!(
+            FormatError::DuplicateField { offset: 9 },
+            FixedLayout::new("%Y-%m-%d %Y").unwrap_err()
+        );
+        assert_eq!(
+            "duplicate field at offset 2",
+            FixedLayout::new("%S%S").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_bulk() {
+        let layout = FixedLayout::new("%Y-%m-%d %H:%M:%S").unwrap();
+        let rows = [
+            "2015-02-03 04:05:06",
+            "2016-02-29 23:59:59",
+            "1000-01-01 00:00:00",
+            "9999-12-31 23:59:59",
+        ];
+        let parsed = layout.parse_bulk(&rows);
+        assert_eq!(0, parsed.error_count());
+        assert_eq!(&[0], parsed.error_bitmap());
+        for (row, value) in rows.iter().zip(parsed.values()) {
+            expect_eq(&row.replace(' ', "T"), value);
+        }
+
+        let rows: Vec<&[u8]> = vec![
+            b"2015-02-03 04:05:06",
+            b"2015-02-29 04:05:06",
+            b"2015-13-03 04:05:06",
+            b"2015-00-03 04:05:06",
+            b"2015-02-00 04:05:06",
+            b"2015-02-03 24:05:06",
+            b"2015-02-03 04:60:06",
+            b"2015-02-03 04:05:60",
+            b"2015-02-03T04:05:06",
+            b"2015-02-03 04:05:0x",
+            b"2015-02-03 04:05:06 ",
+            b"2015-02-03 04:05:6",
+            b"-015-02-03 04:05:06",
+            b"",
+        ];
+        let parsed = layout.parse_bulk(&rows);
+        assert_eq!(rows.len() - 1, parsed.error_count());
+        assert_eq!(&[0b11_1111_1111_1110], parsed.error_bitmap());
+        assert!(!parsed.is_error(0));
+        assert!(parsed.is_error(1));
+        assert_eq!(
+            (1..rows.len()).collect::<Vec<_>>(),
+            parsed.error_rows().collect::<Vec<_>>()
+        );
+        assert_eq!(CivilSecond::default(), parsed.values()[1]);
+        let values = parsed.into_values();
+        assert_eq!(rows.len(), values.len());
+        assert_eq!(Some(CivilSecond::new(2015, 2, 3, 4, 5, 6)), values[0]);
+        assert!(values[1..].iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn test_parse() {
+        use ParseErrorKind::*;
+
+        let layout = FixedLayout::new("%Y-%m-%d %H:%M:%S").unwrap();
+        let check = |row: &str, kind, field, span, expected| {
+            let e = layout.parse(row.as_bytes()).unwrap_err();
+            assert_eq!(kind, e.kind(), "row: {}", row);
+            assert_eq!(field, e.field(), "row: {}", row);
+            assert_eq!(span, e.span(), "row: {}", row);
+            assert_eq!(Some(expected), e.expected(), "row: {}", row);
+        };
+        check(
+            "2015-02-29 04:05:06",
+            OutOfRange,
+            Some(Field::Day),
+            8..10,
+            Expected::Range { min: 1, max: 28 },
+        );
+        check(
+            "2015-13-03 04:05:06",
+            OutOfRange,
+            Some(Field::Month),
+            5..7,
+            Expected::Range { min: 1, max: 12 },
+        );
+        check(
+            "2015-02-03 04:05:60",
+            OutOfRange,
+            Some(Field::Second),
+            17..19,
+            Expected::Range { min: 0, max: 59 },
+        );
+        check(
+            "2015-02-03T04:05:06",
+            Invalid,
+            None,
+            10..11,
+            Expected::Char(' '),
+        );
+        check(
+            "2015-02-03 04:0x:06",
+            Invalid,
+            Some(Field::Minute),
+            15..16,
+            Expected::Digit,
+        );
+        check(
+            "2015-02-03 04:05:0",
+            TooShort,
+            Some(Field::Second),
+            18..18,
+            Expected::Digit,
+        );
+        check("2015-02-03 04:05:06 ", TooLong, None, 19..20, Expected::End);
+        check("", TooShort, Some(Field::Year), 0..0, Expected::Digit);
+
+        // Every row failing in bulk fails alone.
+        let rows = [
+            "2015-02-03 04:05:06",
+            "2016-02-29 23:59:59",
+            "2015-00-03 04:05:06",
+        ];
+        let parsed = layout.parse_bulk(&rows);
+        for (i, row) in rows.iter().enumerate() {
+            let value = layout.parse(row.as_bytes());
+            assert_eq!(parsed.is_error(i), value.is_err());
+            assert_eq!(parsed.values()[i], value.unwrap_or_default());
+        }
+
+        let layout = FixedLayout::new("%d/%m").unwrap();
+        let e = layout.parse(b"31/04").unwrap_err();
+        assert_eq!(0..2, e.span());
+        expect_eq("1970-04-30T00:00:00", layout.parse(b"30/04").unwrap());
+    }
+
+    #[test]
+    fn test_parse_bulk_partial_layout() {
+        let layout = FixedLayout::new("%d/%m/%Y").unwrap();
+        let parsed = layout.parse_bulk(&["03/02/2015", "29/02/2016", "29/02/2015"]);
+        expect_eq("2015-02-03T00:00:00", parsed.values()[0]);
+        expect_eq("2016-02-29T00:00:00", parsed.values()[1]);
+        assert_eq!(vec![2], parsed.error_rows().collect::<Vec<_>>());
+
+        let layout = FixedLayout::new("%H%M").unwrap();
+        let parsed = layout.parse_bulk(&["0405"]);
+        expect_eq("1970-01-01T04:05:00", parsed.values()[0]);
+    }
+
+    #[test]
+    fn test_parse_bulk_bitmap() {
+        let layout = FixedLayout::new("%Y%m%d").unwrap();
+        let start = crate::CivilDay::new(2015, 1, 1);
+        let rows: Vec<String> = (0..200)
+            .map(|i| {
+                if i % 3 == 0 {
+                    String::from("bad")
+                } else {
+                    (start + i).format("%Y%m%d").to_string()
+                }
+            })
+            .collect();
+        let parsed = layout.parse_bulk(&rows);
+        assert_eq!(4, parsed.error_bitmap().len());
+        assert_eq!(67, parsed.error_count());
+        for (i, value) in parsed.values().iter().enumerate() {
+            assert_eq!(i % 3 == 0, parsed.is_error(i));
+            if i % 3 != 0 {
+                assert_eq!(CivilSecond::from(start + i as DiffType), *value);
+            }
+        }
+        assert!(parsed.error_rows().eq((0..200).filter(|i| i % 3 == 0)));
+        assert!(layout.parse_bulk::<&str>(&[]).error_bitmap().is_empty());
+    }
+}
@@ -42,26 +42,29 @@ macro_rules! impl_partial_ord_for {
     ($Type: ty, $Other: ty) => {
         impl PartialOrd<$Other> for $Type {
             fn partial_cmp(&self, other: &$Other) -> Option<Ordering> {
-                self.0.partial_cmp(&other.0)
+                Some(self.0.cmp(&other.0))
             }
         }
     };
 }
 
 macro_rules! impl_partial_ord {
-    ($($Type: ty)*) => ($(
-        impl_partial_ord_for!($Type, CivilSecond);
-        impl_partial_ord_for!($Type, CivilMinute);
-        impl_partial_ord_for!($Type, CivilHour);
-        impl_partial_ord_for!($Type, CivilDay);
-        impl_partial_ord_for!($Type, CivilMonth);
-        impl_partial_ord_for!($Type, CivilYear);
-    )*)
+    ($Type: ty; $($Other: ty)*) => {
+        impl PartialOrd for $Type {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        $(impl_partial_ord_for!($Type, $Other);)*
+    };
 }
 
-impl_partial_ord! {
-    CivilSecond CivilMinute CivilHour CivilDay CivilMonth CivilYear
-}
+impl_partial_ord!(CivilSecond; CivilMinute CivilHour CivilDay CivilMonth CivilYear);
+impl_partial_ord!(CivilMinute; CivilSecond CivilHour CivilDay CivilMonth CivilYear);
+impl_partial_ord!(CivilHour; CivilSecond CivilMinute CivilDay CivilMonth CivilYear);
+impl_partial_ord!(CivilDay; CivilSecond CivilMinute CivilHour CivilMonth CivilYear);
+impl_partial_ord!(CivilMonth; CivilSecond CivilMinute CivilHour CivilDay CivilYear);
+impl_partial_ord!(CivilYear; CivilSecond CivilMinute CivilHour CivilDay CivilMonth);
 
 macro_rules! impl_ord {
     ($($Type: ty)*) => ($(
@@ -1,5 +1,9 @@
 //! Core types, functions and field normalization (without avoidable overflow).
 
+use std::error::Error;
+use std::fmt;
+use std::ops::RangeInclusive;
+
 /// Support years that at least span the range of 64-bit time_t values.
 pub type YearType = i64;
 /// Type alias that indicates an argument is not normalized (e.g., the
@@ -17,6 +21,95 @@ pub(crate) type MinuteType = i8;
 /// Normalized second [0:59].
 pub(crate) type SecondType = i8;
 
+/// The six fields of a civil time: YYYY-MM-DD hh:mm:ss.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Field {
+    /// Year.
+    Year,
+    /// Month.
+    Month,
+    /// Day.
+    Day,
+    /// Hour.
+    Hour,
+    /// Minute.
+    Minute,
+    /// Second.
+    Second,
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match *self {
+            Field::Year => "year",
+            Field::Month => "month",
+            Field::Day => "day",
+            Field::Hour => "hour",
+            Field::Minute => "minute",
+            Field::Second => "second",
+        })
+    }
+}
+
+/// An error of a field outside its natural range, returned by the checked
+/// constructors such as [`CivilDay::try_new()`](crate::CivilDay::try_new).
+///
+/// ```rust
+/// use civil_time::{CivilDay, Field};
+///
+/// let e = CivilDay::try_new(2015, 4, 31).unwrap_err();
+/// assert_eq!(Field::Day, e.field());
+/// assert_eq!(31, e.value());
+/// assert_eq!(1..=30, e.range());
+/// assert_eq!("day 31 out of range, expected a value from 1 to 30", e.to_string());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldError {
+    field: Field,
+    value: DiffType,
+    min: DiffType,
+    max: DiffType,
+}
+
+impl FieldError {
+    pub(crate) const fn new(field: Field, value: DiffType, min: DiffType, max: DiffType) -> Self {
+        FieldError {
+            field,
+            value,
+            min,
+            max,
+        }
+    }
+
+    /// Returns the field out of its range.
+    pub const fn field(&self) -> Field {
+        self.field
+    }
+
+    /// Returns the value given to the field.
+    pub const fn value(&self) -> DiffType {
+        self.value
+    }
+
+    /// Returns the range the field allows, which depends on the year and
+    /// month for the day.
+    pub const fn range(&self) -> RangeInclusive<DiffType> {
+        RangeInclusive::new(self.min, self.max)
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} out of range, expected a value from {} to {}",
+            self.field, self.value, self.min, self.max
+        )
+    }
+}
+
+impl Error for FieldError {}
+
 pub(crate) const fn is_leap_year(y: YearType) -> bool {
     y % 4 == 0 && (y % 100 != 0 || y % 400 == 0)
 }
@@ -54,7 +147,7 @@ const fn days_per_year(y: YearType, m: MonthType) -> i64 {
     }
 }
 
-const fn days_per_month(y: YearType, m: MonthType) -> i64 {
+pub(crate) const fn days_per_month(y: YearType, m: MonthType) -> i64 {
     // non leap year
     const DAYS_PER_MONTH: [i64; 13] = [-1, 31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
 
@@ -74,6 +167,75 @@ pub struct Fields {
 }
 
 impl Fields {
+    /// Checks that the fields are in their natural ranges without
+    /// normalization.
+    pub const fn check(
+        y: YearType,
+        m: DiffType,
+        d: DiffType,
+        hh: DiffType,
+        mm: DiffType,
+        ss: DiffType,
+    ) -> Result<(), FieldError> {
+        const fn check_field(
+            field: Field,
+            value: DiffType,
+            min: DiffType,
+            max: DiffType,
+        ) -> Result<(), FieldError> {
+            if value < min || value > max {
+                return Err(FieldError::new(field, value, min, max));
+            }
+            Ok(())
+        }
+
+        if let Err(e) = check_field(Field::Month, m, 1, 12) {
+            return Err(e);
+        }
+        let days = days_per_month(y, m as MonthType);
+        if let Err(e) = check_field(Field::Day, d, 1, days) {
+            return Err(e);
+        }
+        if let Err(e) = check_field(Field::Hour, hh, 0, 23) {
+            return Err(e);
+        }
+        if let Err(e) = check_field(Field::Minute, mm, 0, 59) {
+            return Err(e);
+        }
+        check_field(Field::Second, ss, 0, 59)
+    }
+
+    /// Clamps the fields to their natural ranges, the day to the end of the
+    /// month.
+    pub const fn clamp(
+        y: YearType,
+        m: DiffType,
+        d: DiffType,
+        hh: DiffType,
+        mm: DiffType,
+        ss: DiffType,
+    ) -> Fields {
+        const fn clamp_field(value: DiffType, min: DiffType, max: DiffType) -> DiffType {
+            if value < min {
+                min
+            } else if value > max {
+                max
+            } else {
+                value
+            }
+        }
+
+        let m = clamp_field(m, 1, 12) as MonthType;
+        Fields {
+            y,
+            m,
+            d: clamp_field(d, 1, days_per_month(y, m)) as DayType,
+            hh: clamp_field(hh, 0, 23) as HourType,
+            mm: clamp_field(mm, 0, 59) as MinuteType,
+            ss: clamp_field(ss, 0, 59) as SecondType,
+        }
+    }
+
     pub const fn n_day(
         y: YearType,
         mut m: MonthType,
@@ -285,4 +447,26 @@ impl Fields {
             ss as SecondType,
         )
     }
+
+    /// Normalizes the fields like [n_sec()](Fields::n_sec), or returns
+    /// `None` if the year overflows.
+    ///
+    /// The fields are normalized in the year of the Gregorian 400-year cycle
+    /// that shares the calendar, so only the final year can overflow.
+    pub const fn checked_n_sec(
+        y: YearType,
+        m: DiffType,
+        d: DiffType,
+        hh: DiffType,
+        mm: DiffType,
+        ss: DiffType,
+    ) -> Option<Fields> {
+        let base = y - y % 400;
+        let mut f = Self::n_sec(y - base, m, d, hh, mm, ss);
+        f.y = match base.checked_add(f.y) {
+            Some(y) => y,
+            None => return None,
+        };
+        Some(f)
+    }
 }
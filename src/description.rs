@@ -0,0 +1,317 @@
+//! Precompiled format descriptions.
+
+use crate::format::{scan_item, FormatError, Item, ScanError};
+use crate::parse::{parse_items, ParseError, ParseMode, ParseOptions, TwoDigitYear};
+use crate::BuildCivilTime;
+use std::borrow::Cow;
+
+/// A strftime-like pattern compiled into a reusable description, which
+/// formats and parses civil times without scanning the pattern again.
+///
+/// The pattern supports the directives listed in
+/// [DelayedFormat](crate::DelayedFormat). Unlike
+/// [`format()`](crate::CivilSecond::format), unknown directives are rejected
+/// on compilation.
+///
+/// A description can also be compiled at build time by the
+/// [`format_description!`](crate::format_description) macro.
+///
+/// ```rust
+/// use civil_time::{CivilDay, CivilSecond, FormatDescription};
+///
+/// let desc = FormatDescription::compile("%Y-%m-%dT%H:%M:%S").unwrap();
+/// let ss: CivilSecond = desc.parse("2015-02-03T04:05:06").unwrap();
+/// assert_eq!("2015-02-03T04:05:06", ss.format_with(&desc).to_string());
+///
+/// let d: CivilDay = desc.parse("2015-02-03T04:05:06").unwrap();
+/// assert_eq!("2015-02-03T00:00:00", d.format_with(&desc).to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatDescription<'a> {
+    items: Cow<'a, [Item<'a>]>,
+    mode: ParseMode,
+    two_digit_year: TwoDigitYear,
+}
+
+impl<'a> FormatDescription<'a> {
+    /// Compiles the strftime-like `pattern`.
+    ///
+    /// ```rust
+    /// use civil_time::{FormatDescription, FormatError};
+    ///
+    /// assert!(FormatDescription::compile("%Y-%m-%d").is_ok());
+    /// assert_eq!(
+    ///     FormatError::UnknownDirective { offset: 3, directive: 'Q' },
+    ///     FormatDescription::compile("%Y-%Q").unwrap_err()
+    /// );
+    /// ```
+    pub fn compile(pattern: &'a str) -> Result<Self, FormatError> {
+        let mut items = Vec::new();
+        let mut pos = 0;
+        while pos < pattern.len() {
+            let (item, end) = scan_item(pattern, pos)?;
+            items.push(item);
+            pos = end;
+        }
+
+        Ok(FormatDescription {
+            items: Cow::Owned(items),
+            mode: ParseMode::Strict,
+            two_digit_year: TwoDigitYear::default(),
+        })
+    }
+
+    #[doc(hidden)]
+    pub const fn from_static_items(items: &'a [Item<'a>]) -> Self {
+        FormatDescription {
+            items: Cow::Borrowed(items),
+            mode: ParseMode::Strict,
+            two_digit_year: TwoDigitYear::POSIX,
+        }
+    }
+
+    /// Sets how [`parse()`](FormatDescription::parse) handles fields out of
+    /// their ranges, which is [ParseMode::Strict] by default.
+    pub fn parse_mode(self, mode: ParseMode) -> Self {
+        FormatDescription { mode, ..self }
+    }
+
+    /// Sets how [`parse()`](FormatDescription::parse) resolves two-digit
+    /// years, which is [`TwoDigitYear::Pivot(1969)`](TwoDigitYear::Pivot) by
+    /// default.
+    pub fn two_digit_year(self, two_digit_year: TwoDigitYear) -> Self {
+        FormatDescription {
+            two_digit_year,
+            ..self
+        }
+    }
+
+    pub(crate) fn items(&self) -> &[Item<'a>] {
+        &self.items
+    }
+
+    /// Parses a civil time from `input` by the description, in the same way
+    /// as [`parse_from_str()`](crate::CivilSecond::parse_from_str) but in the
+    /// [ParseMode] of the description.
+    pub fn parse<T: BuildCivilTime>(&self, input: &str) -> Result<T, ParseError> {
+        let options = ParseOptions {
+            mode: self.mode,
+            two_digit_year: self.two_digit_year,
+            ..ParseOptions::default()
+        };
+        let f = parse_items(input, &self.items, options)?;
+        Ok(T::build_from_ymd_hms(
+            f.y,
+            f.m.into(),
+            f.d.into(),
+            f.hh.into(),
+            f.mm.into(),
+            f.ss.into(),
+        ))
+    }
+}
+
+/// Returns the number of items of the `pattern`, panics if the pattern is
+/// invalid.
+#[doc(hidden)]
+pub const fn item_count(pattern: &str) -> usize {
+    let mut n = 0;
+    let mut pos = 0;
+    while pos < pattern.len() {
+        pos = match scan_item(pattern, pos) {
+            Ok((_, end)) => end,
+            Err(ScanError::UnknownDirective { .. }) => {
+                panic!("unknown directive in format description")
+            }
+            Err(ScanError::IncompleteDirective { .. }) => {
+                panic!("incomplete directive in format description")
+            }
+        };
+        n += 1;
+    }
+    n
+}
+
+/// Compiles the `pattern` of `N` items, panics if the pattern is invalid.
+#[doc(hidden)]
+pub const fn compile_items<const N: usize>(pattern: &str) -> [Item<'_>; N] {
+    let mut items = [Item::Literal(""); N];
+    let mut i = 0;
+    let mut pos = 0;
+    while i < N {
+        match scan_item(pattern, pos) {
+            Ok((item, end)) => {
+                items[i] = item;
+                pos = end;
+            }
+            Err(_) => panic!("invalid format description"),
+        }
+        i += 1;
+    }
+    items
+}
+
+/// Compiles a strftime-like pattern into a
+/// [`FormatDescription<'static>`](FormatDescription) at build time.
+///
+/// The macro can be used in constant expressions, and an invalid pattern is
+/// a compile error.
+///
+/// ```rust
+/// use civil_time::{format_description, CivilDay, FormatDescription};
+///
+/// const DESC: FormatDescription<'static> = format_description!("%Y/%j");
+/// let d = CivilDay::new(2015, 2, 3);
+/// assert_eq!("2015/034", d.format_with(&DESC).to_string());
+/// assert_eq!(d, DESC.parse::<CivilDay>("2015/034").unwrap());
+/// ```
+///
+/// ```compile_fail
+/// use civil_time::{format_description, FormatDescription};
+///
+/// // Fails to compile: unknown directive '%Q'.
+/// const DESC: FormatDescription<'static> = format_description!("%Y-%Q");
+/// ```
+#[macro_export]
+macro_rules! format_description {
+    ($pattern: expr) => {{
+        const ITEMS: &[$crate::__private::Item<'static>] = &$crate::__private::compile_items::<
+            { $crate::__private::item_count($pattern) },
+        >($pattern);
+        $crate::FormatDescription::from_static_items(ITEMS)
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::expect_eq;
+    use crate::{CivilDay, CivilHour, CivilMinute, CivilMonth, CivilSecond, CivilYear};
+
+    #[test]
+    fn test_compile() {
+        let desc = FormatDescription::compile("%Y-%m-%d %%").unwrap();
+        assert_eq!(desc, format_description!("%Y-%m-%d %%"));
+        assert_eq!(
+            FormatDescription::compile("").unwrap(),
+            format_description!("")
+        );
+    }
+
+    #[test]
+    fn test_compile_error() {
+        assert_eq!(
+            FormatError::UnknownDirective {
+                offset: 0,
+                directive: 'F'
+            },
+            FormatDescription::compile("%F").unwrap_err()
+        );
+        assert_eq!(
+            FormatError::UnknownDirective {
+                offset: 3,
+                directive: 'ä'
+            },
+            FormatDescription::compile("%Y %ä").unwrap_err()
+        );
+        assert_eq!(
+            FormatError::IncompleteDirective { offset: 2 },
+            FormatDescription::compile("%Y%").unwrap_err()
+        );
+
+        let e = FormatDescription::compile("%Y-%Q").unwrap_err();
+        assert_eq!("unknown directive '%Q' at offset 3", e.to_string());
+        let e = FormatDescription::compile("%").unwrap_err();
+        assert_eq!("incomplete directive at offset 0", e.to_string());
+    }
+
+    #[test]
+    fn test_format_and_parse() {
+        const DESC: FormatDescription<'static> = format_description!("%Y-%m-%d %H:%M:%S");
+        let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+        assert_eq!("2015-02-03 04:05:06", ss.format_with(&DESC).to_string());
+        let mm = CivilMinute::from(ss);
+        assert_eq!("2015-02-03 04:05:00", mm.format_with(&DESC).to_string());
+
+        let input = "2015-02-03 04:05:06";
+        expect_eq(
+            "2015-02-03T04:05:06",
+            DESC.parse::<CivilSecond>(input).unwrap(),
+        );
+        expect_eq(
+            "2015-02-03T04:05",
+            DESC.parse::<CivilMinute>(input).unwrap(),
+        );
+        expect_eq("2015-02-03T04", DESC.parse::<CivilHour>(input).unwrap());
+        expect_eq("2015-02-03", DESC.parse::<CivilDay>(input).unwrap());
+        expect_eq("2015-02", DESC.parse::<CivilMonth>(input).unwrap());
+        expect_eq("2015", DESC.parse::<CivilYear>(input).unwrap());
+
+        assert!(DESC.parse::<CivilDay>("2015-02-30 00:00:00").is_err());
+    }
+
+    #[test]
+    fn test_parse_mode() {
+        let desc = format_description!("%Y-%m-%d %H:%M:%S").parse_mode(ParseMode::Normalize);
+        expect_eq(
+            "2016-11-01T00:00:00",
+            desc.parse::<CivilSecond>("2016-10-32 00:00:00").unwrap(),
+        );
+        expect_eq(
+            "2016-03-01T00:01:00",
+            desc.parse::<CivilSecond>("2016-02-29 23:60:60").unwrap(),
+        );
+        // The day is normalized before the alignment.
+        expect_eq(
+            "2016-11",
+            desc.parse::<CivilMonth>("2016-10-32 00:00:00").unwrap(),
+        );
+
+        let desc = FormatDescription::compile("%Y-%m-%d %a").unwrap();
+        let e = desc.parse::<CivilDay>("2016-10-32 Tue").unwrap_err();
+        assert_eq!(Some(crate::Field::Day), e.field());
+        // Weekdays are checked against the normalized date.
+        let desc = desc.parse_mode(ParseMode::Normalize);
+        expect_eq(
+            "2016-11-01",
+            desc.parse::<CivilDay>("2016-10-32 Tue").unwrap(),
+        );
+        assert!(desc.parse::<CivilDay>("2016-10-32 Mon").is_err());
+
+        let desc = FormatDescription::compile("%Y/%j")
+            .unwrap()
+            .parse_mode(ParseMode::Normalize);
+        expect_eq("2016-01-01", desc.parse::<CivilDay>("2015/366").unwrap());
+        expect_eq("2014-12-31", desc.parse::<CivilDay>("2015/000").unwrap());
+
+        // The year overflowing by normalization is out of range.
+        let check = |desc: &FormatDescription<'_>, input: &str| {
+            let e = desc.parse::<CivilDay>(input).unwrap_err();
+            assert_eq!(crate::ParseErrorKind::OutOfRange, e.kind(), "{}", input);
+            assert_eq!(Some(crate::Field::Year), e.field(), "{}", input);
+        };
+        check(&desc, "9223372036854775807/366");
+        check(&desc, "-9223372036854775808/000");
+        assert_eq!(
+            Ok(CivilDay::MAX),
+            desc.parse::<CivilDay>("9223372036854775807/365")
+        );
+        let desc = format_description!("%Y-%m-%d %H:%M:%S").parse_mode(ParseMode::Normalize);
+        check(&desc, "9223372036854775807-12-31 24:00:00");
+        check(&desc, "-9223372036854775808-01-00 00:00:00");
+        check(&desc, "9223372036854775807-99-01 00:00:00");
+    }
+
+    #[test]
+    fn test_reuse() {
+        let pattern = String::from("%a, %d %b %Y");
+        let desc = FormatDescription::compile(&pattern).unwrap();
+        let start = CivilDay::new(2015, 1, 1);
+        for i in 0..400 {
+            let d = start + i;
+            let s = d.format_with(&desc).to_string();
+            assert_eq!(d.format(&pattern).to_string(), s);
+            assert_eq!(d, desc.parse::<CivilDay>(&s).unwrap());
+        }
+    }
+}
@@ -0,0 +1,487 @@
+//! Detection of timestamp layouts from samples.
+
+use crate::core::DiffType;
+use crate::description::FormatDescription;
+use crate::format_description;
+use crate::offset::UtcOffset;
+use crate::parse::{Expected, ParseError, ParseErrorKind, Scanner};
+use crate::syslog::SyslogTimestamp;
+use crate::{BuildCivilTime, CivilSecond};
+use std::cmp::Reverse;
+
+/// Layouts of ISO 8601 extended format.
+const ISO_EXTENDED: [FormatDescription<'static>; 3] = [
+    format_description!("%Y-%m-%dT%H:%M:%S"),
+    format_description!("%Y-%m-%d %H:%M:%S"),
+    format_description!("%Y-%m-%d"),
+];
+
+/// Layouts of ISO 8601 basic format.
+const ISO_BASIC: [FormatDescription<'static>; 2] = [
+    format_description!("%Y%m%dT%H%M%S"),
+    format_description!("%Y%m%d"),
+];
+
+/// Layouts of US dates.
+const MONTH_DAY_YEAR: [FormatDescription<'static>; 3] = [
+    format_description!("%m/%d/%Y %H:%M:%S"),
+    format_description!("%m/%d/%Y %H:%M"),
+    format_description!("%m/%d/%Y"),
+];
+
+/// Layouts of European dates.
+const DAY_MONTH_YEAR: [FormatDescription<'static>; 6] = [
+    format_description!("%d.%m.%Y %H:%M:%S"),
+    format_description!("%d.%m.%Y %H:%M"),
+    format_description!("%d.%m.%Y"),
+    format_description!("%d/%m/%Y %H:%M:%S"),
+    format_description!("%d/%m/%Y %H:%M"),
+    format_description!("%d/%m/%Y"),
+];
+
+/// Layout of Apache Common Log Format without the zone.
+const CLF: FormatDescription<'static> = format_description!("%d/%b/%Y:%H:%M:%S");
+
+/// Candidate layouts of timestamps, in the order of preference when they are
+/// equally consistent with the samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimestampLayout {
+    /// ISO 8601 extended format, e.g. "2015-02-03T04:05:06",
+    /// "2015-02-03 04:05:06" and "2015-02-03".
+    IsoExtended,
+    /// ISO 8601 basic format with four-digit years, e.g. "20150203T040506"
+    /// and "20150203".
+    IsoBasic,
+    /// US dates of month, day and year, e.g. "02/03/2015 04:05:06" and
+    /// "2/3/2015".
+    MonthDayYear,
+    /// European dates of day, month and year, e.g. "03.02.2015 04:05:06" and
+    /// "03/02/2015".
+    DayMonthYear,
+    /// Seconds since 1970-01-01T00:00:00 of 9 to 11 digits, with an optional
+    /// fraction that is truncated, e.g. "1422936306" and "1422936306.789".
+    EpochSeconds,
+    /// Milliseconds since 1970-01-01T00:00:00 of 12 to 14 digits, which are
+    /// truncated to seconds, e.g. "1422936306789".
+    EpochMillis,
+    /// Syslog timestamps of RFC 3164 or RFC 5424, see [SyslogTimestamp].
+    Syslog,
+    /// Apache Common Log Format, optionally in brackets, e.g.
+    /// "[03/Feb/2015:04:05:06 +0800]".
+    ApacheClf,
+}
+
+/// All candidate layouts in the order of preference.
+const LAYOUTS: [TimestampLayout; 8] = [
+    TimestampLayout::IsoExtended,
+    TimestampLayout::IsoBasic,
+    TimestampLayout::MonthDayYear,
+    TimestampLayout::DayMonthYear,
+    TimestampLayout::EpochSeconds,
+    TimestampLayout::EpochMillis,
+    TimestampLayout::Syslog,
+    TimestampLayout::ApacheClf,
+];
+
+/// Parses the `input` by the first of `descs` that matches, or returns the
+/// error of the first one.
+fn parse_any(input: &str, descs: &[FormatDescription<'_>]) -> Result<CivilSecond, ParseError> {
+    let mut error = None;
+    for desc in descs {
+        match desc.parse(input) {
+            Ok(cs) => return Ok(cs),
+            Err(e) => {
+                error.get_or_insert(e);
+            }
+        }
+    }
+    Err(error.unwrap_or(ParseError::new(ParseErrorKind::TooShort, None)))
+}
+
+/// Parses seconds since the epoch of `min` to `max` integral digits, divided
+/// by `scale`.
+fn parse_epoch(
+    input: &str,
+    min: usize,
+    max: usize,
+    scale: DiffType,
+    fraction: bool,
+) -> Result<CivilSecond, ParseError> {
+    let mut sc = Scanner::new(input);
+    let n = sc.digits();
+    if n < min {
+        return Err(sc.unexpected(None, Expected::Digit));
+    }
+    if n > max {
+        return Err(ParseError::new(ParseErrorKind::TooLong, None)
+            .at(max, n)
+            .expecting(Expected::End));
+    }
+    if fraction && sc.peek() == Some(b'.') {
+        sc.literal(".")?;
+        if sc.digits() == 0 {
+            return Err(sc.unexpected(None, Expected::Digit));
+        }
+    }
+    if !sc.at_end() {
+        return Err(sc.trailing());
+    }
+    // At most 14 digits never overflow.
+    let v: DiffType = input[..n].parse().unwrap_or(0);
+    Ok(CivilSecond::new(1970, 1, 1, 0, 0, 0) + v / scale)
+}
+
+/// Parses Apache Common Log Format, ignoring the zone after checking it.
+fn parse_clf(input: &str) -> Result<CivilSecond, ParseError> {
+    let end = |expected| {
+        ParseError::new(ParseErrorKind::TooShort, None)
+            .at(input.len(), input.len())
+            .expecting(expected)
+    };
+    let (offset, s) = match input.strip_prefix('[') {
+        Some(s) => (
+            1,
+            s.strip_suffix(']')
+                .ok_or_else(|| end(Expected::Char(']')))?,
+        ),
+        None => (0, input),
+    };
+    let (date, zone) = s.split_once(' ').ok_or_else(|| end(Expected::Char(' ')))?;
+    let cs = CLF.parse(date).map_err(|e| e.shift(offset))?;
+    let mut sc = Scanner::new(zone);
+    UtcOffset::parse(&mut sc, false)
+        .and_then(|_| {
+            if sc.at_end() {
+                Ok(cs)
+            } else {
+                Err(sc.trailing())
+            }
+        })
+        .map_err(|e| e.shift(offset + date.len() + 1))
+}
+
+impl TimestampLayout {
+    /// Parses the `input` in the layout, `reference` is only used to infer the
+    /// year of RFC 3164 syslog timestamps.
+    fn parse(self, input: &str, reference: CivilSecond) -> Result<CivilSecond, ParseError> {
+        let trimmed = input.trim();
+        let offset = input.len() - input.trim_start().len();
+        self.parse_trimmed(trimmed, reference)
+            .map_err(|e| e.at(0, trimmed.len()).shift(offset))
+    }
+
+    fn parse_trimmed(self, input: &str, reference: CivilSecond) -> Result<CivilSecond, ParseError> {
+        match self {
+            TimestampLayout::IsoExtended => parse_any(input, &ISO_EXTENDED),
+            TimestampLayout::IsoBasic => {
+                if !matches!(input.len(), 8 | 15) {
+                    return Err(ParseError::new(ParseErrorKind::Invalid, None));
+                }
+                parse_any(input, &ISO_BASIC)
+            }
+            TimestampLayout::MonthDayYear => parse_any(input, &MONTH_DAY_YEAR),
+            TimestampLayout::DayMonthYear => parse_any(input, &DAY_MONTH_YEAR),
+            TimestampLayout::EpochSeconds => parse_epoch(input, 9, 11, 1, true),
+            TimestampLayout::EpochMillis => parse_epoch(input, 12, 14, 1000, false),
+            TimestampLayout::Syslog => {
+                SyslogTimestamp::parse(input, reference).map(|ts| ts.civil_second())
+            }
+            TimestampLayout::ApacheClf => parse_clf(input),
+        }
+    }
+}
+
+/// A candidate layout ranked by [FormatDetector::rank()].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Candidate {
+    layout: TimestampLayout,
+    matched: usize,
+    ordered: usize,
+}
+
+impl Candidate {
+    /// Returns the layout.
+    pub const fn layout(&self) -> TimestampLayout {
+        self.layout
+    }
+
+    /// Returns the number of samples parsed by the layout.
+    pub const fn matched(&self) -> usize {
+        self.matched
+    }
+
+    /// Returns the number of parsed samples that are not earlier than the
+    /// parsed sample before them.
+    pub const fn ordered(&self) -> usize {
+        self.ordered
+    }
+}
+
+/// Detects the layout of timestamps from samples.
+///
+/// The candidate layouts are ranked by the number of samples they parse,
+/// then by how many parsed samples are in chronological order as log lines
+/// usually are, and then by the order of [TimestampLayout]. So an ambiguous
+/// sample like "03/04/2015" is read as a US date, unless another sample like
+/// "13/04/2015" is only a European date.
+///
+/// ```rust
+/// use civil_time::{CivilDay, CivilSecond, FormatDetector, TimestampLayout};
+///
+/// let detector = FormatDetector::new(CivilSecond::new(2015, 6, 1, 0, 0, 0));
+/// let format = detector.detect(&["03/04/2015", "13/04/2015"]).unwrap();
+/// assert_eq!(TimestampLayout::DayMonthYear, format.layout());
+///
+/// let d: CivilDay = format.parse("03/04/2015").unwrap();
+/// assert_eq!(CivilDay::new(2015, 4, 3), d);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatDetector {
+    reference: CivilSecond,
+}
+
+impl FormatDetector {
+    /// Creates a detector, `reference` is used to infer the year of RFC 3164
+    /// syslog timestamps, which is usually the time the logs are read.
+    pub const fn new(reference: CivilSecond) -> Self {
+        FormatDetector { reference }
+    }
+
+    /// Ranks the candidate layouts that parse at least one of the `samples`,
+    /// the most consistent first.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilSecond, FormatDetector, TimestampLayout};
+    ///
+    /// let detector = FormatDetector::new(CivilSecond::new(2015, 6, 1, 0, 0, 0));
+    /// let ranked = detector.rank(&["03/04/2015", "04/04/2015"]);
+    /// assert_eq!(2, ranked.len());
+    /// assert_eq!(TimestampLayout::MonthDayYear, ranked[0].layout());
+    /// assert_eq!(TimestampLayout::DayMonthYear, ranked[1].layout());
+    /// ```
+    pub fn rank<S: AsRef<str>>(&self, samples: &[S]) -> Vec<Candidate> {
+        let mut candidates: Vec<_> = LAYOUTS
+            .iter()
+            .map(|&layout| {
+                let mut matched = 0;
+                let mut ordered = 0;
+                let mut last = None;
+                for sample in samples {
+                    if let Ok(cs) = layout.parse(sample.as_ref(), self.reference) {
+                        matched += 1;
+                        if last.is_some_and(|last| last <= cs) {
+                            ordered += 1;
+                        }
+                        last = Some(cs);
+                    }
+                }
+                Candidate {
+                    layout,
+                    matched,
+                    ordered,
+                }
+            })
+            .filter(|c| c.matched > 0)
+            .collect();
+        // The sort is stable, so equally consistent layouts keep their order.
+        candidates.sort_by_key(|c| Reverse((c.matched, c.ordered)));
+        candidates
+    }
+
+    /// Returns a parser of the most consistent layout with the `samples`, or
+    /// `None` if no layout parses any of them.
+    pub fn detect<S: AsRef<str>>(&self, samples: &[S]) -> Option<DetectedFormat> {
+        self.rank(samples).first().map(|c| DetectedFormat {
+            layout: c.layout,
+            reference: self.reference,
+        })
+    }
+}
+
+/// A reusable parser of the layout detected by [FormatDetector].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DetectedFormat {
+    layout: TimestampLayout,
+    reference: CivilSecond,
+}
+
+impl DetectedFormat {
+    /// Returns the detected layout.
+    pub const fn layout(&self) -> TimestampLayout {
+        self.layout
+    }
+
+    /// Parses a civil time from `input` in the detected layout. Surrounding
+    /// white space is ignored, as are the UTC offsets and fractional
+    /// seconds of the layouts that carry them.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilMinute, CivilSecond, FormatDetector};
+    ///
+    /// let detector = FormatDetector::new(CivilSecond::new(2015, 6, 1, 0, 0, 0));
+    /// let format = detector.detect(&["[03/Feb/2015:04:05:06 +0800]"]).unwrap();
+    /// let m: CivilMinute = format.parse("[04/Feb/2015:05:06:07 +0800]").unwrap();
+    /// assert_eq!(CivilMinute::new(2015, 2, 4, 5, 6), m);
+    /// ```
+    pub fn parse<T: BuildCivilTime>(&self, input: &str) -> Result<T, ParseError> {
+        let cs = self.layout.parse(input, self.reference)?;
+        Ok(T::build_from_ymd_hms(
+            cs.year(),
+            cs.month().into(),
+            cs.day().into(),
+            cs.hour().into(),
+            cs.minute().into(),
+            cs.second().into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REFERENCE: CivilSecond = CivilSecond::new(2015, 6, 1, 0, 0, 0);
+
+    #[test]
+    fn test_layout_parse() {
+        use TimestampLayout::*;
+
+        let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+        let d = CivilSecond::new(2015, 2, 3, 0, 0, 0);
+        let table = [
+            (IsoExtended, "2015-02-03T04:05:06", ss),
+            (IsoExtended, " 2015-02-03 04:05:06 ", ss),
+            (IsoExtended, "2015-02-03", d),
+            (IsoBasic, "20150203T040506", ss),
+            (IsoBasic, "20150203", d),
+            (MonthDayYear, "02/03/2015 04:05:06", ss),
+            (MonthDayYear, "2/3/2015 04:05", ss - 6),
+            (MonthDayYear, "02/03/2015", d),
+            (DayMonthYear, "03.02.2015 04:05:06", ss),
+            (DayMonthYear, "3.2.2015", d),
+            (DayMonthYear, "03/02/2015 04:05:06", ss),
+            (EpochSeconds, "1422936306", ss),
+            (EpochSeconds, "1422936306.999", ss),
+            (
+                EpochSeconds,
+                "000000000",
+                CivilSecond::new(1970, 1, 1, 0, 0, 0),
+            ),
+            (EpochMillis, "1422936306789", ss),
+            (Syslog, "Feb  3 04:05:06", ss),
+            (Syslog, "2015-02-03T04:05:06.789+08:00", ss),
+            (ApacheClf, "[03/Feb/2015:04:05:06 +0800]", ss),
+            (ApacheClf, "03/Feb/2015:04:05:06 -0000", ss),
+        ];
+        for (layout, input, expect) in table.iter() {
+            let cs = layout.parse(input, REFERENCE);
+            assert_eq!(Ok(*expect), cs, "{:?} {}", layout, input);
+        }
+
+        let errors = [
+            (IsoBasic, "1422936306"),
+            (MonthDayYear, "13/03/2015"),
+            (DayMonthYear, "03-02-2015"),
+            (EpochSeconds, "20150203"),
+            (EpochSeconds, "1422936306789"),
+            (EpochSeconds, "1422936306."),
+            (EpochSeconds, "-1422936306"),
+            (EpochMillis, "1422936306.789"),
+            (Syslog, "Feb 3 04:05:06"),
+            (ApacheClf, "[03/Feb/2015:04:05:06 +0800"),
+            (ApacheClf, "03/Feb/2015:04:05:06"),
+            (ApacheClf, "03/Feb/2015:04:05:06 +08:00"),
+        ];
+        for (layout, input) in errors.iter() {
+            assert!(
+                layout.parse(input, REFERENCE).is_err(),
+                "{:?} {}",
+                layout,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_rank() {
+        use TimestampLayout::*;
+
+        let detector = FormatDetector::new(REFERENCE);
+        let ranked = detector.rank(&["02/03/2015", "02/13/2015", "12/03/2015"]);
+        assert_eq!(
+            vec![
+                Candidate {
+                    layout: MonthDayYear,
+                    matched: 3,
+                    ordered: 2,
+                },
+                Candidate {
+                    layout: DayMonthYear,
+                    matched: 2,
+                    ordered: 1,
+                },
+            ],
+            ranked
+        );
+
+        // Ambiguous dates are in chronological order as European dates.
+        let ranked = detector.rank(&["02/01/2015", "03/01/2015", "01/02/2015"]);
+        assert_eq!(DayMonthYear, ranked[0].layout());
+        assert_eq!(2, ranked[0].ordered());
+        assert_eq!(MonthDayYear, ranked[1].layout());
+        assert_eq!(1, ranked[1].ordered());
+
+        // Digits are epoch seconds rather than a basic date in year 142293.
+        let ranked = detector.rank(&["1422936306", "1422936307"]);
+        assert_eq!(EpochSeconds, ranked[0].layout());
+
+        assert!(detector.rank(&["not a timestamp"]).is_empty());
+        assert!(detector.rank::<&str>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_detect() {
+        use TimestampLayout::*;
+
+        let detector = FormatDetector::new(CivilSecond::new(2016, 1, 2, 0, 0, 0));
+        let table = [
+            (
+                vec!["2015-12-31T23:59:59", "2016-01-01 00:00:00"],
+                IsoExtended,
+            ),
+            (vec!["20151231T235959", "20160101"], IsoBasic),
+            (vec!["12/31/2015 23:59:59", "1/1/2016 00:00"], MonthDayYear),
+            (vec!["31.12.2015 23:59:59", "01.01.2016"], DayMonthYear),
+            (vec!["1451606399", "1451606400"], EpochSeconds),
+            (vec!["1451606399000", "1451606400000"], EpochMillis),
+            (vec!["Dec 31 23:59:59", "Jan  1 00:00:00"], Syslog),
+            (
+                vec![
+                    "[31/Dec/2015:23:59:59 +0000]",
+                    "[01/Jan/2016:00:00:00 +0000]",
+                ],
+                ApacheClf,
+            ),
+        ];
+        let expect = [
+            CivilSecond::new(2015, 12, 31, 23, 59, 59),
+            CivilSecond::new(2016, 1, 1, 0, 0, 0),
+        ];
+        for (samples, layout) in table.iter() {
+            let format = detector.detect(samples).unwrap();
+            assert_eq!(*layout, format.layout(), "{:?}", samples);
+            let parsed: Vec<CivilSecond> =
+                samples.iter().map(|s| format.parse(s).unwrap()).collect();
+            assert_eq!(expect[..], parsed[..], "{:?}", samples);
+        }
+
+        // The majority wins over an inconsistent sample.
+        let format = detector
+            .detect(&["2015-12-31 23:59:59", "1451606400", "2016-01-01 00:00:01"])
+            .unwrap();
+        assert_eq!(IsoExtended, format.layout());
+        assert!(format.parse::<CivilSecond>("1451606400").is_err());
+
+        assert_eq!(None, detector.detect(&["", "-"]));
+    }
+}
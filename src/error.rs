@@ -0,0 +1,167 @@
+//! The error type of the crate.
+
+use std::error;
+use std::fmt;
+
+use crate::core::FieldError;
+use crate::format::FormatError;
+use crate::locale::LocalePatternError;
+use crate::parse::ParseError;
+
+/// Any error of the fallible APIs of the crate, so that they can be
+/// propagated by `?` uniformly.
+///
+/// Each specific error converts into it by [From], and the `Display` impl
+/// writes to the formatter without allocation.
+///
+/// ```rust
+/// use civil_time::{CivilDay, Error};
+///
+/// fn next_month(input: &str) -> Result<CivilDay, Error> {
+///     let d: CivilDay = input.parse()?;
+///     let d = CivilDay::try_new(d.year(), d.month() as i64 + 1, d.day() as i64)?;
+///     Ok(d)
+/// }
+///
+/// assert_eq!(CivilDay::new(2015, 3, 3), next_month("2015-02-03").unwrap());
+/// assert!(matches!(next_month("2015-02-30"), Err(Error::Parse(_))));
+/// assert!(matches!(next_month("2015-01-30"), Err(Error::Field(_))));
+/// assert_eq!(
+///     "day 30 out of range, expected a value from 1 to 28",
+///     next_month("2015-01-30").unwrap_err().to_string()
+/// );
+/// ```
+///
+/// More variants may be added, e.g. failures of time zone lookups.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// A field is outside its natural range, see [FieldError].
+    Field(FieldError),
+    /// The result of arithmetic is beyond the representable range, e.g.
+    /// [`checked_add()`](crate::CivilDay::checked_add) returns `None`.
+    Overflow,
+    /// The input can not be parsed, see [ParseError].
+    Parse(ParseError),
+    /// The format pattern is malformed, see [FormatError].
+    Format(FormatError),
+    /// The localized pattern or skeleton is malformed, see
+    /// [LocalePatternError].
+    LocalePattern(LocalePatternError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Field(e) => fmt::Display::fmt(e, f),
+            Error::Overflow => f.write_str("civil time out of range"),
+            Error::Parse(e) => fmt::Display::fmt(e, f),
+            Error::Format(e) => fmt::Display::fmt(e, f),
+            Error::LocalePattern(e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Field(e) => Some(e),
+            Error::Overflow => None,
+            Error::Parse(e) => Some(e),
+            Error::Format(e) => Some(e),
+            Error::LocalePattern(e) => Some(e),
+        }
+    }
+}
+
+impl From<FieldError> for Error {
+    fn from(e: FieldError) -> Self {
+        Error::Field(e)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+impl From<FormatError> for Error {
+    fn from(e: FormatError) -> Self {
+        Error::Format(e)
+    }
+}
+
+impl From<LocalePatternError> for Error {
+    fn from(e: LocalePatternError) -> Self {
+        Error::LocalePattern(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        CivilDay, CivilSecond, Field, FormatDescription, Locale, LocalePattern, ParseErrorKind,
+    };
+    use std::error::Error as _;
+
+    fn parse_and_add(input: &str, pattern: &str, n: i64) -> Result<CivilDay, Error> {
+        let desc = FormatDescription::compile(pattern)?;
+        let d: CivilDay = desc.parse(input)?;
+        d.checked_add(n).ok_or(Error::Overflow)
+    }
+
+    #[test]
+    fn test_from() {
+        assert_eq!(
+            Ok(CivilDay::new(2015, 2, 4)),
+            parse_and_add("2015-02-03", "%Y-%m-%d", 1)
+        );
+
+        let e = parse_and_add("2015-02-03", "%Y-%m-%Q", 1).unwrap_err();
+        assert!(matches!(e, Error::Format(_)));
+        let e = parse_and_add("2015-02-30", "%Y-%m-%d", 1).unwrap_err();
+        match e {
+            Error::Parse(e) => assert_eq!(ParseErrorKind::OutOfRange, e.kind()),
+            e => panic!("unexpected error: {:?}", e),
+        }
+        let e = CivilDay::MAX
+            .checked_add(1)
+            .ok_or(Error::Overflow)
+            .unwrap_err();
+        assert_eq!(Error::Overflow, e);
+
+        let e: Error = CivilSecond::try_new(2015, 2, 3, 24, 0, 0)
+            .unwrap_err()
+            .into();
+        match e {
+            Error::Field(e) => assert_eq!(Field::Hour, e.field()),
+            e => panic!("unexpected error: {:?}", e),
+        }
+
+        let e: Error = LocalePattern::from_skeleton(Locale::En, "yQ")
+            .unwrap_err()
+            .into();
+        assert_eq!(
+            Error::LocalePattern(LocalePatternError::UnsupportedSkeleton { offset: 0 }),
+            e
+        );
+        assert_eq!("unsupported skeleton at offset 0", e.to_string());
+        assert!(e.source().is_some());
+    }
+
+    #[test]
+    fn test_display_and_source() {
+        let e = parse_and_add("2015-02-03", "%Y-%m-%Q", 1).unwrap_err();
+        assert_eq!("unknown directive '%Q' at offset 6", e.to_string());
+        assert!(e.source().is_some());
+
+        let e = parse_and_add("2015-02-30", "%Y-%m-%d", 1).unwrap_err();
+        assert_eq!("day out of range", e.to_string());
+        assert_eq!(e.to_string(), e.source().unwrap().to_string());
+
+        assert_eq!("civil time out of range", Error::Overflow.to_string());
+        assert!(Error::Overflow.source().is_none());
+    }
+}
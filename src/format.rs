@@ -0,0 +1,686 @@
+//! Formatting civil time types with strftime-like patterns.
+
+use crate::core::Field;
+use crate::description::FormatDescription;
+use crate::iso::IsoItems;
+use crate::locale::LocalePattern;
+use crate::offset::UtcOffset;
+use crate::weekday::{iso_week, week_of_year};
+use crate::{
+    CivilDay, CivilHour, CivilMinute, CivilMonth, CivilSecond, CivilYear, Weekday, YearType,
+};
+use std::error::Error;
+use std::fmt;
+
+/// Full English names of months.
+pub(crate) const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// A conversion directive of format patterns.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spec {
+    /// `%Y`: Year, e.g. "2015".
+    Year,
+    /// `%m`: Month, from "01" to "12".
+    Month,
+    /// `%d`: Day of month, from "01" to "31".
+    Day,
+    /// `%e`: Day of month padded with space, from " 1" to "31".
+    DaySpacePadded,
+    /// `%H`: Hour, from "00" to "23".
+    Hour,
+    /// `%M`: Minute, from "00" to "59".
+    Minute,
+    /// `%S`: Second, from "00" to "59".
+    Second,
+    /// `%j`: Day of year, from "001" to "366".
+    YearDay,
+    /// `%a`: Abbreviated weekday name, e.g. "Tue".
+    WeekdayShort,
+    /// `%A`: Full weekday name, e.g. "Tuesday".
+    WeekdayLong,
+    /// `%b`: Abbreviated month name, e.g. "Feb".
+    MonthShort,
+    /// `%B`: Full month name, e.g. "February".
+    MonthLong,
+    /// `%u`: Weekday number, from "1" (Monday) to "7" (Sunday).
+    WeekdayFromMonday,
+    /// `%w`: Weekday number, from "0" (Sunday) to "6" (Saturday).
+    WeekdayFromSunday,
+    /// `%U`: Week of year with Sunday as the first day of week, from "00" to "53".
+    WeekFromSunday,
+    /// `%W`: Week of year with Monday as the first day of week, from "00" to "53".
+    WeekFromMonday,
+    /// `%V`: ISO 8601 week of year, from "01" to "53", or "00" for the days
+    /// of the minimum year before its first week.
+    IsoWeek,
+    /// `%G`: ISO 8601 week-numbering year.
+    IsoYear,
+    /// `%y`: Year of century, from "00" to "99".
+    YearOfCentury,
+    /// `%C`: Century, e.g. "20".
+    Century,
+    /// Year padded to at least 4 digits, e.g. "0015" and "-0015". Used by
+    /// ISO 8601 basic format and has no conversion character.
+    YearPadded,
+    /// ISO 8601 week-numbering year padded to at least 4 digits. Used by ISO
+    /// 8601 basic format and has no conversion character.
+    IsoYearPadded,
+    /// Year with a sign and at least 6 digits, e.g. "+002015" and "-000015".
+    /// Used by ISO 8601 expanded representation and has no conversion
+    /// character.
+    YearExpanded,
+    /// ISO 8601 week-numbering year with a sign and at least 6 digits. Used by
+    /// ISO 8601 expanded representation and has no conversion character.
+    IsoYearExpanded,
+}
+
+impl Spec {
+    /// Returns the number of digits of a fixed-width numeric directive.
+    pub(crate) const fn width(&self) -> Option<usize> {
+        match *self {
+            Spec::Month
+            | Spec::Day
+            | Spec::DaySpacePadded
+            | Spec::Hour
+            | Spec::Minute
+            | Spec::Second
+            | Spec::WeekFromSunday
+            | Spec::WeekFromMonday
+            | Spec::IsoWeek
+            | Spec::YearOfCentury => Some(2),
+            Spec::YearDay => Some(3),
+            Spec::WeekdayFromMonday | Spec::WeekdayFromSunday => Some(1),
+            _ => None,
+        }
+    }
+
+    /// Returns the minimum number of digits the directive is formatted in.
+    pub(crate) const fn padded_width(&self) -> usize {
+        match *self {
+            Spec::YearExpanded | Spec::IsoYearExpanded => 6,
+            Spec::YearPadded | Spec::IsoYearPadded => 4,
+            Spec::Year | Spec::IsoYear | Spec::Century => 1,
+            _ => match self.width() {
+                Some(width) => width,
+                None => 0,
+            },
+        }
+    }
+
+    /// Returns the civil-time field that the directive relates to.
+    pub(crate) const fn field(&self) -> Field {
+        match *self {
+            Spec::Year
+            | Spec::YearPadded
+            | Spec::IsoYear
+            | Spec::IsoYearPadded
+            | Spec::YearExpanded
+            | Spec::IsoYearExpanded
+            | Spec::YearOfCentury
+            | Spec::Century => Field::Year,
+            Spec::Month | Spec::MonthShort | Spec::MonthLong => Field::Month,
+            Spec::Hour => Field::Hour,
+            Spec::Minute => Field::Minute,
+            Spec::Second => Field::Second,
+            _ => Field::Day,
+        }
+    }
+
+    /// Returns the directive indicated by the conversion character `c`.
+    pub(crate) const fn from_byte(c: u8) -> Option<Spec> {
+        Some(match c {
+            b'Y' => Spec::Year,
+            b'm' => Spec::Month,
+            b'd' => Spec::Day,
+            b'e' => Spec::DaySpacePadded,
+            b'H' => Spec::Hour,
+            b'M' => Spec::Minute,
+            b'S' => Spec::Second,
+            b'j' => Spec::YearDay,
+            b'a' => Spec::WeekdayShort,
+            b'A' => Spec::WeekdayLong,
+            b'b' => Spec::MonthShort,
+            b'B' => Spec::MonthLong,
+            b'u' => Spec::WeekdayFromMonday,
+            b'w' => Spec::WeekdayFromSunday,
+            b'U' => Spec::WeekFromSunday,
+            b'W' => Spec::WeekFromMonday,
+            b'V' => Spec::IsoWeek,
+            b'G' => Spec::IsoYear,
+            b'y' => Spec::YearOfCentury,
+            b'C' => Spec::Century,
+            _ => return None,
+        })
+    }
+}
+
+/// An item of a format pattern.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Item<'a> {
+    /// Literal text.
+    Literal(&'a str),
+    /// A conversion directive.
+    Spec(Spec),
+}
+
+/// An error in a format pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatError {
+    /// The conversion directive at the byte `offset` is unknown.
+    UnknownDirective {
+        /// Byte offset of the `%` that starts the directive.
+        offset: usize,
+        /// The unknown conversion character.
+        directive: char,
+    },
+    /// The pattern ends with a single `%` at the byte `offset`.
+    IncompleteDirective {
+        /// Byte offset of the trailing `%`.
+        offset: usize,
+    },
+    /// The directive at the byte `offset` repeats a field of a layout that
+    /// allows each field only once, see [FixedLayout](crate::FixedLayout).
+    DuplicateField {
+        /// Byte offset of the `%` that starts the repeated directive.
+        offset: usize,
+    },
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            FormatError::UnknownDirective { offset, directive } => {
+                write!(f, "unknown directive '%{}' at offset {}", directive, offset)
+            }
+            FormatError::IncompleteDirective { offset } => {
+                write!(f, "incomplete directive at offset {}", offset)
+            }
+            FormatError::DuplicateField { offset } => {
+                write!(f, "duplicate field at offset {}", offset)
+            }
+        }
+    }
+}
+
+impl Error for FormatError {}
+
+/// An error in scanning a directive of a strftime-like pattern, the subset
+/// of [FormatError] that [scan_item()] returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ScanError {
+    /// See [`FormatError::UnknownDirective`].
+    UnknownDirective { offset: usize, directive: char },
+    /// See [`FormatError::IncompleteDirective`].
+    IncompleteDirective { offset: usize },
+}
+
+impl From<ScanError> for FormatError {
+    fn from(e: ScanError) -> Self {
+        match e {
+            ScanError::UnknownDirective { offset, directive } => {
+                FormatError::UnknownDirective { offset, directive }
+            }
+            ScanError::IncompleteDirective { offset } => {
+                FormatError::IncompleteDirective { offset }
+            }
+        }
+    }
+}
+
+/// Returns the substring of `s` in the byte range [`start`, `end`).
+const fn substr(s: &str, start: usize, end: usize) -> &str {
+    let (_, rest) = s.as_bytes().split_at(start);
+    let (bytes, _) = rest.split_at(end - start);
+    match std::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(_) => panic!("not a char boundary"),
+    }
+}
+
+/// Decodes the char that starts at byte `pos` of `s`.
+const fn char_at(s: &str, pos: usize) -> char {
+    let bytes = s.as_bytes();
+    let (len, mut c) = match bytes[pos] {
+        b if b < 0x80 => (1, b as u32),
+        b if b < 0xE0 => (2, (b & 0x1F) as u32),
+        b if b < 0xF0 => (3, (b & 0x0F) as u32),
+        b => (4, (b & 0x07) as u32),
+    };
+    let mut i = 1;
+    while i < len {
+        c = (c << 6) | (bytes[pos + i] & 0x3F) as u32;
+        i += 1;
+    }
+    match char::from_u32(c) {
+        Some(c) => c,
+        None => char::REPLACEMENT_CHARACTER,
+    }
+}
+
+/// Scans the item that starts at byte `pos` of a non-empty `pattern`, returns
+/// the item and the position after it.
+pub(crate) const fn scan_item(pattern: &str, pos: usize) -> Result<(Item<'_>, usize), ScanError> {
+    let bytes = pattern.as_bytes();
+    if bytes[pos] != b'%' {
+        let mut end = pos + 1;
+        while end < bytes.len() && bytes[end] != b'%' {
+            end += 1;
+        }
+        return Ok((Item::Literal(substr(pattern, pos, end)), end));
+    }
+
+    if pos + 1 >= bytes.len() {
+        return Err(ScanError::IncompleteDirective { offset: pos });
+    }
+    if bytes[pos + 1] == b'%' {
+        return Ok((Item::Literal(substr(pattern, pos + 1, pos + 2)), pos + 2));
+    }
+    match Spec::from_byte(bytes[pos + 1]) {
+        Some(spec) => Ok((Item::Spec(spec), pos + 2)),
+        None => Err(ScanError::UnknownDirective {
+            offset: pos,
+            directive: char_at(pattern, pos + 1),
+        }),
+    }
+}
+
+/// Iterator over the items of a format pattern.
+///
+/// Unknown directives and a trailing `%` are treated as literal text.
+#[derive(Debug, Clone)]
+pub(crate) struct Items<'a> {
+    pattern: &'a str,
+    pos: usize,
+}
+
+impl<'a> Items<'a> {
+    pub(crate) fn new(pattern: &'a str) -> Self {
+        Items { pattern, pos: 0 }
+    }
+}
+
+impl<'a> Iterator for Items<'a> {
+    type Item = Item<'a>;
+
+    fn next(&mut self) -> Option<Item<'a>> {
+        if self.pos >= self.pattern.len() {
+            return None;
+        }
+
+        let (item, end) = match scan_item(self.pattern, self.pos) {
+            Ok(v) => v,
+            // Keeps the '%' and the (possibly multibyte) character.
+            Err(ScanError::UnknownDirective { offset, directive }) => {
+                let end = offset + 1 + directive.len_utf8();
+                (Item::Literal(&self.pattern[offset..end]), end)
+            }
+            Err(ScanError::IncompleteDirective { offset }) => {
+                (Item::Literal(&self.pattern[offset..]), self.pattern.len())
+            }
+        };
+        self.pos = end;
+
+        Some(item)
+    }
+}
+
+/// Writes the value of the directive `spec` of the civil time `cs`.
+pub(crate) fn write_spec<W: fmt::Write>(w: &mut W, cs: CivilSecond, spec: Spec) -> fmt::Result {
+    match spec {
+        Spec::Year => write!(w, "{}", cs.year()),
+        Spec::Month => write!(w, "{:02}", cs.month()),
+        Spec::Day => write!(w, "{:02}", cs.day()),
+        Spec::DaySpacePadded => write!(w, "{:>2}", cs.day()),
+        Spec::Hour => write!(w, "{:02}", cs.hour()),
+        Spec::Minute => write!(w, "{:02}", cs.minute()),
+        Spec::Second => write!(w, "{:02}", cs.second()),
+        Spec::YearDay => write!(w, "{:03}", cs.yearday()),
+        Spec::WeekdayShort => w.write_str(cs.weekday().short_name()),
+        Spec::WeekdayLong => w.write_str(cs.weekday().long_name()),
+        Spec::MonthShort => w.write_str(&MONTH_NAMES[cs.month() as usize - 1][..3]),
+        Spec::MonthLong => w.write_str(MONTH_NAMES[cs.month() as usize - 1]),
+        Spec::WeekdayFromMonday => write!(w, "{}", cs.weekday().number_from_monday()),
+        Spec::WeekdayFromSunday => write!(w, "{}", cs.weekday().days_from_sunday()),
+        Spec::WeekFromSunday => write!(w, "{:02}", week_of_year(cs, Weekday::Sun)),
+        Spec::WeekFromMonday => write!(w, "{:02}", week_of_year(cs, Weekday::Mon)),
+        Spec::IsoWeek => write!(w, "{:02}", iso_week(CivilDay::from_second(cs)).1),
+        Spec::IsoYear => write!(w, "{}", iso_week(CivilDay::from_second(cs)).0),
+        Spec::YearOfCentury => write!(w, "{:02}", cs.year().rem_euclid(100)),
+        Spec::Century => write!(w, "{:02}", cs.year().div_euclid(100)),
+        Spec::YearPadded => write_padded_year(w, cs.year()),
+        Spec::IsoYearPadded => write_padded_year(w, iso_week(CivilDay::from_second(cs)).0),
+        Spec::YearExpanded => write_expanded_year(w, cs.year()),
+        Spec::IsoYearExpanded => write_expanded_year(w, iso_week(CivilDay::from_second(cs)).0),
+    }
+}
+
+/// Writes the year `y` padded to at least 4 digits.
+fn write_padded_year<W: fmt::Write>(w: &mut W, y: YearType) -> fmt::Result {
+    if y < 0 {
+        write!(w, "-{:04}", y.unsigned_abs())
+    } else {
+        write!(w, "{:04}", y)
+    }
+}
+
+/// Writes the year `y` with a sign and padded to at least 6 digits.
+fn write_expanded_year<W: fmt::Write>(w: &mut W, y: YearType) -> fmt::Result {
+    let sign = if y < 0 { '-' } else { '+' };
+    write!(w, "{}{:06}", sign, y.unsigned_abs())
+}
+
+/// A civil time formatted by a strftime-like pattern, which is rendered only
+/// when it is displayed.
+///
+/// The following directives are supported, others are printed as is:
+///
+/// | Directive | Meaning | Example |
+/// |-----------|---------|---------|
+/// | `%Y` | Year | `2015` |
+/// | `%C` | Century, as year divided by 100 and rounded down | `20` |
+/// | `%y` | Year of century, from `00` to `99` | `15` |
+/// | `%m` | Month, from `01` to `12` | `02` |
+/// | `%b` | Abbreviated month name | `Feb` |
+/// | `%B` | Full month name | `February` |
+/// | `%d` | Day of month, from `01` to `31` | `03` |
+/// | `%e` | Day of month padded with space, from ` 1` to `31` | ` 3` |
+/// | `%j` | Day of year, from `001` to `366` | `034` |
+/// | `%H` | Hour, from `00` to `23` | `04` |
+/// | `%M` | Minute, from `00` to `59` | `05` |
+/// | `%S` | Second, from `00` to `59` | `06` |
+/// | `%a` | Abbreviated weekday name | `Tue` |
+/// | `%A` | Full weekday name | `Tuesday` |
+/// | `%u` | Weekday number, from `1` (Monday) to `7` (Sunday) | `2` |
+/// | `%w` | Weekday number, from `0` (Sunday) to `6` (Saturday) | `2` |
+/// | `%U` | Week of year, weeks start on Sunday, from `00` to `53` | `05` |
+/// | `%W` | Week of year, weeks start on Monday, from `00` to `53` | `05` |
+/// | `%V` | ISO 8601 week of year, from `01` to `53` (`00` before the first week of the minimum year) | `06` |
+/// | `%G` | ISO 8601 week-numbering year | `2015` |
+/// | `%%` | A literal `%` | `%` |
+#[derive(Debug, Clone, Copy)]
+pub struct DelayedFormat<'a> {
+    cs: CivilSecond,
+    source: Source<'a>,
+}
+
+/// Source of the items to format.
+#[derive(Debug, Clone, Copy)]
+enum Source<'a> {
+    /// A pattern that is scanned on display.
+    Pattern(&'a str),
+    /// Items of a compiled [FormatDescription].
+    Items(&'a [Item<'a>]),
+    /// Items of an ISO 8601 representation.
+    Iso(IsoItems),
+    /// A localized pattern.
+    Locale(&'a LocalePattern),
+    /// Items followed by a UTC offset in the form of "+hhmm".
+    Offset(&'a [Item<'a>], UtcOffset),
+}
+
+impl DelayedFormat<'static> {
+    pub(crate) fn from_iso(cs: CivilSecond, items: IsoItems) -> Self {
+        DelayedFormat {
+            cs,
+            source: Source::Iso(items),
+        }
+    }
+}
+
+impl<'a> DelayedFormat<'a> {
+    pub(crate) fn from_items(cs: CivilSecond, items: &'a [Item<'a>]) -> Self {
+        DelayedFormat {
+            cs,
+            source: Source::Items(items),
+        }
+    }
+
+    pub(crate) fn from_offset(cs: CivilSecond, items: &'a [Item<'a>], offset: UtcOffset) -> Self {
+        DelayedFormat {
+            cs,
+            source: Source::Offset(items, offset),
+        }
+    }
+
+    pub(crate) fn from_locale(cs: CivilSecond, pattern: &'a LocalePattern) -> Self {
+        DelayedFormat {
+            cs,
+            source: Source::Locale(pattern),
+        }
+    }
+}
+
+impl fmt::Display for DelayedFormat<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.source {
+            Source::Pattern(pattern) => write_items(f, self.cs, Items::new(pattern)),
+            Source::Items(items) => write_items(f, self.cs, items.iter().copied()),
+            Source::Iso(items) => write_items(f, self.cs, items.iter().copied()),
+            Source::Locale(pattern) => pattern.write(f, self.cs),
+            Source::Offset(items, offset) => {
+                write_items(f, self.cs, items.iter().copied())?;
+                offset.write(f, false)
+            }
+        }
+    }
+}
+
+fn write_items<'a, I>(f: &mut fmt::Formatter<'_>, cs: CivilSecond, items: I) -> fmt::Result
+where
+    I: Iterator<Item = Item<'a>>,
+{
+    for item in items {
+        match item {
+            Item::Literal(s) => f.write_str(s)?,
+            Item::Spec(spec) => write_spec(f, cs, spec)?,
+        }
+    }
+    Ok(())
+}
+
+macro_rules! impl_format {
+    ($Type: ty) => {
+        impl $Type {
+            /// Formats the civil time by the strftime-like `pattern`. See
+            /// [DelayedFormat] for supported directives.
+            ///
+            /// ```rust
+            /// use civil_time::CivilSecond;
+            ///
+            /// let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+            /// assert_eq!("03 Feb 2015", ss.format("%d %b %Y").to_string());
+            /// ```
+            pub fn format<'a>(&self, pattern: &'a str) -> DelayedFormat<'a> {
+                DelayedFormat {
+                    cs: CivilSecond::from_fields(self.0),
+                    source: Source::Pattern(pattern),
+                }
+            }
+
+            /// Formats the civil time by the compiled format description `desc`.
+            ///
+            /// ```rust
+            /// use civil_time::{CivilSecond, FormatDescription};
+            ///
+            /// let desc = FormatDescription::compile("%d %b %Y").unwrap();
+            /// let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+            /// assert_eq!("03 Feb 2015", ss.format_with(&desc).to_string());
+            /// ```
+            pub fn format_with<'a>(&self, desc: &'a FormatDescription<'_>) -> DelayedFormat<'a> {
+                DelayedFormat {
+                    cs: CivilSecond::from_fields(self.0),
+                    source: Source::Items(desc.items()),
+                }
+            }
+        }
+    };
+}
+
+impl_format!(CivilSecond);
+impl_format!(CivilMinute);
+impl_format!(CivilHour);
+impl_format!(CivilDay);
+impl_format!(CivilMonth);
+impl_format!(CivilYear);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+        assert_eq!(
+            "2015-02-03T04:05:06",
+            ss.format("%Y-%m-%dT%H:%M:%S").to_string()
+        );
+        assert_eq!("03 Feb 2015", ss.format("%d %b %Y").to_string());
+        assert_eq!("2015/034", ss.format("%Y/%j").to_string());
+        assert_eq!(
+            "Tuesday, February  3, 15",
+            ss.format("%A, %B %e, %y").to_string()
+        );
+        assert_eq!("Tue 2 2 20", ss.format("%a %u %w %C").to_string());
+        assert_eq!("05 05 2015-W06", ss.format("%U %W %G-W%V").to_string());
+        assert_eq!("", ss.format("").to_string());
+        assert_eq!("no directives", ss.format("no directives").to_string());
+    }
+
+    #[test]
+    fn test_format_alignment() {
+        // Fields finer than the alignment are formatted as their minimum value.
+        let pattern = "%Y-%m-%d %H:%M:%S";
+        let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+        assert_eq!("2015-02-03 04:05:06", ss.format(pattern).to_string());
+        let mm = CivilMinute::from(ss);
+        assert_eq!("2015-02-03 04:05:00", mm.format(pattern).to_string());
+        let hh = CivilHour::from(ss);
+        assert_eq!("2015-02-03 04:00:00", hh.format(pattern).to_string());
+        let d = CivilDay::from(ss);
+        assert_eq!("2015-02-03 00:00:00", d.format(pattern).to_string());
+        let m = CivilMonth::from(ss);
+        assert_eq!("2015-02-01 00:00:00", m.format(pattern).to_string());
+        let y = CivilYear::from(ss);
+        assert_eq!("2015-01-01 00:00:00", y.format(pattern).to_string());
+    }
+
+    #[test]
+    fn test_format_escape() {
+        let d = CivilDay::new(2015, 2, 3);
+        assert_eq!("100%", d.format("100%%").to_string());
+        assert_eq!("%", d.format("%").to_string());
+        assert_eq!("2015%", d.format("%Y%").to_string());
+        assert_eq!("%q %ä 2015", d.format("%q %ä %Y").to_string());
+    }
+
+    #[test]
+    fn test_format_names() {
+        let months = [
+            "Jan January",
+            "Feb February",
+            "Mar March",
+            "Apr April",
+            "May May",
+            "Jun June",
+            "Jul July",
+            "Aug August",
+            "Sep September",
+            "Oct October",
+            "Nov November",
+            "Dec December",
+        ];
+        for (i, expect) in months.iter().enumerate() {
+            let m = CivilMonth::new(2015, i as i64 + 1);
+            assert_eq!(*expect, m.format("%b %B").to_string());
+        }
+
+        // 2015-08-10 is a Monday.
+        let weekdays = [
+            "Mon Monday 1 1",
+            "Tue Tuesday 2 2",
+            "Wed Wednesday 3 3",
+            "Thu Thursday 4 4",
+            "Fri Friday 5 5",
+            "Sat Saturday 6 6",
+            "Sun Sunday 7 0",
+        ];
+        for (i, expect) in weekdays.iter().enumerate() {
+            let d = CivilDay::new(2015, 8, 10 + i as i64);
+            assert_eq!(*expect, d.format("%a %A %u %w").to_string());
+        }
+    }
+
+    #[test]
+    fn test_format_week() {
+        // (date, %U %W %G-%V)
+        let table = [
+            ((2014, 12, 28), "52 51 2014-52"),
+            ((2014, 12, 29), "52 52 2015-01"),
+            ((2015, 1, 1), "00 00 2015-01"),
+            ((2015, 1, 4), "01 00 2015-01"),
+            ((2015, 1, 5), "01 01 2015-02"),
+            ((2015, 12, 31), "52 52 2015-53"),
+            ((2016, 1, 3), "01 00 2015-53"),
+            ((2016, 1, 4), "01 01 2016-01"),
+            ((2008, 12, 29), "52 52 2009-01"),
+            ((2010, 1, 3), "01 00 2009-53"),
+        ];
+        for ((y, m, d), expect) in table.iter() {
+            let d = CivilDay::new(*y, *m, *d);
+            assert_eq!(*expect, d.format("%U %W %G-%V").to_string(), "{:?}", d);
+        }
+    }
+
+    #[test]
+    fn test_format_week_limits() {
+        // The first day of the minimum year is before its first ISO week.
+        assert_eq!(
+            "-9223372036854775808-00 01 00",
+            CivilSecond::MIN.format("%G-%V %U %W").to_string()
+        );
+        assert_eq!(
+            "9223372036854775807-53 52 52",
+            CivilSecond::MAX.format("%G-%V %U %W").to_string()
+        );
+
+        // Every directive formats the limits without panicking.
+        let pattern = "%Y %m %d %e %H %M %S %j %a %A %b %B %u %w %U %W %V %G %y %C";
+        for cs in [CivilSecond::MIN, CivilSecond::MAX].iter() {
+            assert!(!cs.format(pattern).to_string().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_format_years() {
+        let pattern = "%Y %C %y";
+        assert_eq!("0 00 00", CivilYear::new(0).format(pattern).to_string());
+        assert_eq!("-1 -1 99", CivilYear::new(-1).format(pattern).to_string());
+        assert_eq!(
+            "-101 -2 99",
+            CivilYear::new(-101).format(pattern).to_string()
+        );
+        assert_eq!(
+            "9223372036854775807 92233720368547758 07",
+            CivilYear::MAX.format(pattern).to_string()
+        );
+        assert_eq!(
+            "-9223372036854775808 -92233720368547759 92",
+            CivilYear::MIN.format(pattern).to_string()
+        );
+    }
+}
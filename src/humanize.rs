@@ -0,0 +1,562 @@
+//! Human-friendly relative time phrases, e.g. "3 days ago" and "in 2 hours".
+
+use crate::core::Fields;
+use crate::locale::Locale;
+use crate::{CivilDay, CivilHour, CivilMinute, CivilMonth, CivilSecond, CivilYear, DiffType};
+use std::fmt;
+
+/// A unit of relative time phrases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelativeUnit {
+    /// Seconds.
+    Second,
+    /// Minutes.
+    Minute,
+    /// Hours.
+    Hour,
+    /// Days.
+    Day,
+    /// Months.
+    Month,
+    /// Years.
+    Year,
+}
+
+/// All units, from the finest to the coarsest.
+const UNITS: [RelativeUnit; 6] = [
+    RelativeUnit::Second,
+    RelativeUnit::Minute,
+    RelativeUnit::Hour,
+    RelativeUnit::Day,
+    RelativeUnit::Month,
+    RelativeUnit::Year,
+];
+
+/// How the difference is rounded to a whole number of units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Rounding {
+    /// Counts the complete units, e.g. 1 day and 23 hours is 1 day.
+    Truncate,
+    /// Rounds to the nearest number of units, e.g. 1 day and 12 hours is 2
+    /// days.
+    #[default]
+    Round,
+    /// Counts the unit boundaries crossed, which is the difference of the
+    /// civil times in the alignment of the unit, e.g. from 23:00 to 01:00 of
+    /// the next day is 1 day.
+    Calendar,
+}
+
+/// Renders the difference between two civil times as a localized phrase,
+/// e.g. "3 days ago" and "in 2 hours".
+///
+/// The unit is the finest one in which the difference is less than its
+/// threshold, and years are used otherwise. The default thresholds are 45
+/// seconds, 45 minutes, 22 hours, 26 days and 11 months. If the difference
+/// rounds to 0 in that unit, the finer unit is used instead, so only equal
+/// civil times are rendered as "now".
+///
+/// Months and years are counted by the civil calendar rather than
+/// approximated by days, e.g. from January 31 to February 28 is 1 month when
+/// rounded and 28 days when truncated.
+///
+/// ```rust
+/// use civil_time::{CivilSecond, Locale, RelativeFormat, RelativeUnit};
+///
+/// let base = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+/// let format = RelativeFormat::new(Locale::En);
+/// assert_eq!("3 days ago", format.between(base, base - 3 * 86400).to_string());
+/// assert_eq!("in 2 hours", format.between(base, base + 7200).to_string());
+/// assert_eq!("now", format.between(base, base).to_string());
+///
+/// let format = RelativeFormat::new(Locale::De).threshold(RelativeUnit::Hour, 48);
+/// assert_eq!("in 36 Stunden", format.between(base, base + 36 * 3600).to_string());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelativeFormat {
+    locale: Locale,
+    /// Thresholds of the units from second to month.
+    thresholds: [DiffType; 5],
+    rounding: Rounding,
+}
+
+impl RelativeFormat {
+    /// Creates a format of the `locale` with the default thresholds and
+    /// [Rounding::Round].
+    pub const fn new(locale: Locale) -> Self {
+        RelativeFormat {
+            locale,
+            thresholds: [45, 45, 22, 26, 11],
+            rounding: Rounding::Round,
+        }
+    }
+
+    /// Sets the threshold of `unit`, a difference of at least `limit` units
+    /// is rendered in a coarser unit. The threshold of years is ignored.
+    pub const fn threshold(mut self, unit: RelativeUnit, limit: DiffType) -> Self {
+        if (unit as usize) < self.thresholds.len() {
+            self.thresholds[unit as usize] = limit;
+        }
+        self
+    }
+
+    /// Sets how the difference is rounded, which is [Rounding::Round] by
+    /// default.
+    pub const fn rounding(self, rounding: Rounding) -> Self {
+        RelativeFormat { rounding, ..self }
+    }
+
+    /// Returns the phrase of the civil time `target` relative to `base`,
+    /// which is in the past if `target` is earlier than `base`.
+    ///
+    /// A difference that does not fit in [DiffType] in a unit is rendered in
+    /// a coarser unit, and saturates in years.
+    pub fn between<T, U>(&self, base: T, target: U) -> RelativeTime
+    where
+        T: Into<CivilSecond>,
+        U: Into<CivilSecond>,
+    {
+        let (base, target) = (base.into(), target.into());
+        let mut finer = None;
+        for (i, unit) in UNITS.iter().enumerate() {
+            let last = i == self.thresholds.len();
+            let amount = match self.amount(base, target, *unit) {
+                Some(amount) => amount,
+                None if !last => continue,
+                None if target < base => DiffType::MIN,
+                None => DiffType::MAX,
+            };
+            if amount == 0 && base != target {
+                if let Some(finer) = finer {
+                    return finer;
+                }
+            }
+            let time = RelativeTime {
+                locale: self.locale,
+                amount,
+                unit: *unit,
+            };
+            if last || matches!(amount.checked_abs(), Some(n) if n < self.thresholds[i]) {
+                return time;
+            }
+            finer = Some(time);
+        }
+        unreachable!()
+    }
+
+    /// Returns the difference from `base` to `target` in `unit`, or `None`
+    /// if it does not fit.
+    fn amount(
+        &self,
+        base: CivilSecond,
+        target: CivilSecond,
+        unit: RelativeUnit,
+    ) -> Option<DiffType> {
+        let calendar = match unit {
+            RelativeUnit::Second => target.checked_difference(base),
+            RelativeUnit::Minute => CivilMinute::from(target).checked_difference(base.into()),
+            RelativeUnit::Hour => CivilHour::from(target).checked_difference(base.into()),
+            RelativeUnit::Day => CivilDay::from(target).checked_difference(base.into()),
+            RelativeUnit::Month => CivilMonth::from(target).checked_difference(base.into()),
+            RelativeUnit::Year => CivilYear::from(target).checked_difference(base.into()),
+        }?;
+        if self.rounding == Rounding::Calendar {
+            return Some(calendar);
+        }
+
+        // The calendar difference overshoots by at most one unit.
+        let sign = if target < base { -1 } else { 1 };
+        let mut n = calendar;
+        let lower = add(base, unit, n)?;
+        let lower = if (sign > 0 && lower > target) || (sign < 0 && lower < target) {
+            n -= sign;
+            add(base, unit, n)?
+        } else {
+            lower
+        };
+        if self.rounding == Rounding::Round {
+            // A unit spans at most a year, so the differences within it fit.
+            // If the next unit is beyond the range, the target is rounded
+            // towards `base`.
+            let upper = n.checked_add(sign).and_then(|m| add(base, unit, m));
+            if let Some(upper) = upper {
+                if (target - lower) * 2 * sign >= (upper - lower) * sign {
+                    n += sign;
+                }
+            }
+        }
+        Some(n)
+    }
+}
+
+/// Returns `n` units after `base`, months and years keep the day of month
+/// and the time of day and are normalized like the fields of
+/// [`CivilSecond::new()`], or `None` on overflow.
+fn add(base: CivilSecond, unit: RelativeUnit, n: DiffType) -> Option<CivilSecond> {
+    let (y, m) = (base.year(), base.month() as DiffType);
+    let (d, hh, mm, ss) = (
+        base.day() as DiffType,
+        base.hour() as DiffType,
+        base.minute() as DiffType,
+        base.second() as DiffType,
+    );
+    let fields = match unit {
+        RelativeUnit::Second => return base.checked_add(n),
+        RelativeUnit::Minute => return base.checked_add(n.checked_mul(60)?),
+        RelativeUnit::Hour => return base.checked_add(n.checked_mul(3600)?),
+        RelativeUnit::Day => Fields::checked_n_sec(y, m, d.checked_add(n)?, hh, mm, ss),
+        RelativeUnit::Month => Fields::checked_n_sec(y, m.checked_add(n)?, d, hh, mm, ss),
+        RelativeUnit::Year => Fields::checked_n_sec(y.checked_add(n)?, m, d, hh, mm, ss),
+    };
+    fields.map(CivilSecond::from_fields)
+}
+
+/// A localized relative time phrase, e.g. "3 days ago".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelativeTime {
+    locale: Locale,
+    amount: DiffType,
+    unit: RelativeUnit,
+}
+
+impl RelativeTime {
+    /// Returns the number of units, which is negative in the past.
+    pub const fn amount(&self) -> DiffType {
+        self.amount
+    }
+
+    /// Returns the unit.
+    pub const fn unit(&self) -> RelativeUnit {
+        self.unit
+    }
+}
+
+/// Phrases of a locale.
+struct Phrases {
+    /// The phrase of a zero difference.
+    now: &'static str,
+    /// Text before and after the amount and the unit in the future.
+    future: (&'static str, &'static str),
+    /// Text before and after the amount and the unit in the past.
+    past: (&'static str, &'static str),
+    /// Separator between the amount and the unit.
+    separator: &'static str,
+    /// Singular and plural names of the units.
+    units: [(&'static str, &'static str); 6],
+}
+
+impl Locale {
+    fn phrases(&self) -> &'static Phrases {
+        match *self {
+            Locale::En => &Phrases {
+                now: "now",
+                future: ("in ", ""),
+                past: ("", " ago"),
+                separator: " ",
+                units: [
+                    ("second", "seconds"),
+                    ("minute", "minutes"),
+                    ("hour", "hours"),
+                    ("day", "days"),
+                    ("month", "months"),
+                    ("year", "years"),
+                ],
+            },
+            Locale::De => &Phrases {
+                now: "jetzt",
+                future: ("in ", ""),
+                past: ("vor ", ""),
+                separator: " ",
+                units: [
+                    ("Sekunde", "Sekunden"),
+                    ("Minute", "Minuten"),
+                    ("Stunde", "Stunden"),
+                    ("Tag", "Tagen"),
+                    ("Monat", "Monaten"),
+                    ("Jahr", "Jahren"),
+                ],
+            },
+            Locale::Fr => &Phrases {
+                now: "maintenant",
+                future: ("dans ", ""),
+                past: ("il y a ", ""),
+                separator: " ",
+                units: [
+                    ("seconde", "secondes"),
+                    ("minute", "minutes"),
+                    ("heure", "heures"),
+                    ("jour", "jours"),
+                    ("mois", "mois"),
+                    ("an", "ans"),
+                ],
+            },
+            Locale::Es => &Phrases {
+                now: "ahora",
+                future: ("dentro de ", ""),
+                past: ("hace ", ""),
+                separator: " ",
+                units: [
+                    ("segundo", "segundos"),
+                    ("minuto", "minutos"),
+                    ("hora", "horas"),
+                    ("día", "días"),
+                    ("mes", "meses"),
+                    ("año", "años"),
+                ],
+            },
+            Locale::Zh => &Phrases {
+                now: "现在",
+                future: ("", "后"),
+                past: ("", "前"),
+                separator: "",
+                units: [
+                    ("秒钟", "秒钟"),
+                    ("分钟", "分钟"),
+                    ("小时", "小时"),
+                    ("天", "天"),
+                    ("个月", "个月"),
+                    ("年", "年"),
+                ],
+            },
+            Locale::Ja => &Phrases {
+                now: "今",
+                future: ("", "後"),
+                past: ("", "前"),
+                separator: " ",
+                units: [
+                    ("秒", "秒"),
+                    ("分", "分"),
+                    ("時間", "時間"),
+                    ("日", "日"),
+                    ("か月", "か月"),
+                    ("年", "年"),
+                ],
+            },
+        }
+    }
+
+    /// Returns whether the amount `n` takes the singular form.
+    fn is_singular(&self, n: u64) -> bool {
+        match *self {
+            Locale::Fr => n < 2,
+            _ => n == 1,
+        }
+    }
+}
+
+impl fmt::Display for RelativeTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let phrases = self.locale.phrases();
+        if self.amount == 0 {
+            return f.write_str(phrases.now);
+        }
+        let n = self.amount.unsigned_abs();
+        let (singular, plural) = phrases.units[self.unit as usize];
+        let unit = if self.locale.is_singular(n) {
+            singular
+        } else {
+            plural
+        };
+        let (before, after) = if self.amount > 0 {
+            phrases.future
+        } else {
+            phrases.past
+        };
+        write!(f, "{}{}{}{}{}", before, n, phrases.separator, unit, after)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: CivilSecond = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+
+    fn render(format: RelativeFormat, target: CivilSecond) -> String {
+        format.between(BASE, target).to_string()
+    }
+
+    #[test]
+    fn test_units() {
+        let format = RelativeFormat::new(Locale::En);
+        let table = [
+            (0, "now"),
+            (1, "in 1 second"),
+            (-44, "44 seconds ago"),
+            (45, "in 1 minute"),
+            (-89, "1 minute ago"),
+            (90, "in 2 minutes"),
+            (44 * 60, "in 44 minutes"),
+            (45 * 60, "in 1 hour"),
+            (-21 * 3600, "21 hours ago"),
+            (22 * 3600, "in 1 day"),
+            (-25 * 86400, "25 days ago"),
+            (26 * 86400, "in 1 month"),
+            (-320 * 86400, "10 months ago"),
+            (340 * 86400, "in 1 year"),
+            (-800 * 86400, "2 years ago"),
+        ];
+        for (diff, expect) in table.iter() {
+            assert_eq!(*expect, render(format, BASE + *diff), "{}", diff);
+        }
+    }
+
+    #[test]
+    fn test_rounding() {
+        let format = RelativeFormat::new(Locale::En);
+        let truncate = format.rounding(Rounding::Truncate);
+        let calendar = format.rounding(Rounding::Calendar);
+
+        // 1 day and 12 hours.
+        let target = BASE + 36 * 3600;
+        assert_eq!("in 2 days", render(format, target));
+        assert_eq!("in 1 day", render(truncate, target));
+        assert_eq!("in 1 day", render(calendar, target));
+
+        // From 23:00 to 01:00 of the next day.
+        let base = CivilSecond::new(2015, 2, 3, 23, 0, 0);
+        let target = CivilSecond::new(2015, 2, 4, 1, 0, 0);
+        let hours = format.threshold(RelativeUnit::Hour, 1);
+        assert_eq!("in 2 hours", hours.between(base, target).to_string());
+        assert_eq!(
+            "in 1 day",
+            hours
+                .rounding(Rounding::Calendar)
+                .between(base, target)
+                .to_string()
+        );
+        assert_eq!(
+            "1 day ago",
+            hours
+                .rounding(Rounding::Calendar)
+                .between(target, base)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_truncate_thresholds() {
+        let format = RelativeFormat::new(Locale::En).rounding(Rounding::Truncate);
+        let table = [
+            (45, "in 45 seconds"),
+            (-45, "45 seconds ago"),
+            (60, "in 1 minute"),
+            (45 * 60, "in 45 minutes"),
+            (60 * 60, "in 1 hour"),
+            (22 * 3600, "in 22 hours"),
+            (-22 * 3600, "22 hours ago"),
+            (24 * 3600, "in 1 day"),
+            (26 * 86400, "in 26 days"),
+            (-26 * 86400, "26 days ago"),
+            (28 * 86400, "in 1 month"),
+            (11 * 31 * 86400, "in 11 months"),
+            (-11 * 31 * 86400, "11 months ago"),
+            (365 * 86400, "in 1 year"),
+        ];
+        for (diff, expect) in table.iter() {
+            assert_eq!(*expect, render(format, BASE + *diff), "{}", diff);
+        }
+
+        // Calendar differences may also be 0 in the coarser unit.
+        let calendar = RelativeFormat::new(Locale::En).rounding(Rounding::Calendar);
+        assert_eq!("in 45 seconds", render(calendar, BASE + 45));
+    }
+
+    #[test]
+    fn test_calendar_units() {
+        let format = RelativeFormat::new(Locale::En).threshold(RelativeUnit::Day, 1);
+        let jan31 = CivilDay::new(2015, 1, 31);
+        let feb28 = CivilDay::new(2015, 2, 28);
+        assert_eq!("in 1 month", format.between(jan31, feb28).to_string());
+        let truncate = format.rounding(Rounding::Truncate);
+        assert_eq!("in 28 days", truncate.between(jan31, feb28).to_string());
+        assert_eq!(28, truncate.between(jan31, feb28).amount());
+        assert_eq!(RelativeUnit::Day, truncate.between(jan31, feb28).unit());
+
+        // Leap days are exact.
+        let a = CivilDay::new(2015, 3, 1);
+        let b = CivilDay::new(2016, 2, 29);
+        let years = format.threshold(RelativeUnit::Month, 1);
+        assert_eq!("in 1 year", years.between(a, b).to_string());
+        assert_eq!(
+            "in 11 months",
+            years.rounding(Rounding::Truncate).between(a, b).to_string()
+        );
+        assert_eq!(
+            "in 1 year",
+            years
+                .rounding(Rounding::Truncate)
+                .between(a, b + 1)
+                .to_string()
+        );
+        assert_eq!(
+            "1 year ago",
+            years
+                .rounding(Rounding::Truncate)
+                .between(b + 1, a)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_locales() {
+        let table = [
+            (Locale::En, ["in 1 day", "2 days ago", "now"]),
+            (Locale::De, ["in 1 Tag", "vor 2 Tagen", "jetzt"]),
+            (Locale::Fr, ["dans 1 jour", "il y a 2 jours", "maintenant"]),
+            (Locale::Es, ["dentro de 1 día", "hace 2 días", "ahora"]),
+            (Locale::Zh, ["1天后", "2天前", "现在"]),
+            (Locale::Ja, ["1 日後", "2 日前", "今"]),
+        ];
+        for (locale, expects) in table.iter() {
+            let format = RelativeFormat::new(*locale);
+            assert_eq!(expects[0], render(format, BASE + 86400));
+            assert_eq!(expects[1], render(format, BASE - 2 * 86400));
+            assert_eq!(expects[2], render(format, BASE));
+        }
+
+        let format = RelativeFormat::new(Locale::Fr).threshold(RelativeUnit::Second, 0);
+        let minutes = format.threshold(RelativeUnit::Minute, 100);
+        assert_eq!(
+            "dans 1 minute",
+            minutes.between(BASE, BASE + 60).to_string()
+        );
+        assert_eq!("il y a 99 minutes", render(minutes, BASE - 99 * 60));
+        assert_eq!("il y a 3 mois", render(format, BASE - 90 * 86400));
+    }
+
+    #[test]
+    fn test_limits() {
+        let format = RelativeFormat::new(Locale::En);
+        let check = |base: CivilSecond, target: CivilSecond, amount, unit| {
+            for rounding in [Rounding::Truncate, Rounding::Round, Rounding::Calendar] {
+                let t = format.rounding(rounding).between(base, target);
+                assert_eq!((amount, unit), (t.amount(), t.unit()), "{:?}", rounding);
+            }
+        };
+
+        let y = 1_000_000_000_000;
+        let t = format.between(CivilYear::new(0), CivilYear::new(y));
+        assert_eq!("in 1000000000000 years", t.to_string());
+        check(
+            CivilSecond::from(CivilYear::new(y)),
+            CivilSecond::from(CivilYear::new(0)),
+            -y,
+            RelativeUnit::Year,
+        );
+
+        let max = CivilSecond::MAX;
+        let min = CivilSecond::MIN;
+        check(min, max, DiffType::MAX, RelativeUnit::Year);
+        check(max, min, DiffType::MIN, RelativeUnit::Year);
+        check(max - 30, max, 30, RelativeUnit::Second);
+        check(min + 30, min, -30, RelativeUnit::Second);
+        check(max, max, 0, RelativeUnit::Second);
+        check(BASE, max, DiffType::MAX - 2015, RelativeUnit::Year);
+        check(BASE, min, DiffType::MIN, RelativeUnit::Year);
+
+        // Near the limits, the rounding does not look beyond the range.
+        let t = format.between(CivilSecond::new(DiffType::MAX - 1, 12, 31, 0, 0, 0), max);
+        assert_eq!((1, RelativeUnit::Year), (t.amount(), t.unit()));
+    }
+}
@@ -0,0 +1,753 @@
+//! ISO 8601 representations of civil time types.
+
+use crate::format::{DelayedFormat, Item, Spec};
+use crate::parse::{parse_items, ParseError, ParseMode, ParseOptions};
+use crate::{CivilDay, CivilHour, CivilMinute, CivilMonth, CivilSecond, CivilYear};
+use std::ops::Deref;
+
+/// An ISO 8601 representation of civil times.
+///
+/// Civil times are represented by the calendar date followed by the time of
+/// day, up to the field of their alignment, and fields finer than the
+/// alignment are omitted.
+///
+/// | Type | Extended | Basic |
+/// |------|----------|-------|
+/// | [CivilSecond] | `2015-02-03T04:05:06` | `20150203T040506` |
+/// | [CivilMinute] | `2015-02-03T04:05` | `20150203T0405` |
+/// | [CivilHour] | `2015-02-03T04` | `20150203T04` |
+/// | [CivilDay] | `2015-02-03` | `20150203` |
+/// | [CivilMonth] | `2015-02` | `201502` |
+/// | [CivilYear] | `2015` | `2015` |
+///
+/// The extended format is the layout printed by the `Debug` impls. The basic
+/// format pads years to at least 4 digits, e.g. "00150203" and "-00150203".
+///
+/// Years out of the range from 0 to 9999 are unambiguous only in the
+/// expanded representation, see [`expanded()`](IsoFormat::expanded).
+///
+/// The date can also be represented by the week date or the ordinal date, see
+/// [`week_date()`](IsoFormat::week_date) and
+/// [`ordinal_date()`](IsoFormat::ordinal_date).
+///
+/// Parsing requires every field to be padded to the width it is formatted in,
+/// and rejects fields out of their ranges unless another
+/// [`parse_mode()`](IsoFormat::parse_mode) is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IsoFormat {
+    basic: bool,
+    expanded: bool,
+    date: DateRepr,
+    mode: ParseMode,
+}
+
+/// Representation of the date part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum DateRepr {
+    /// Year, month and day, e.g. "2015-02-03".
+    #[default]
+    Calendar,
+    /// Week-numbering year, week and weekday, e.g. "2015-W06-2".
+    Week,
+    /// Year and day of year, e.g. "2015-034".
+    Ordinal,
+}
+
+impl IsoFormat {
+    /// The extended format, which separates fields by '-' and ':', e.g.
+    /// "2015-02-03T04:05:06".
+    pub const fn extended() -> Self {
+        IsoFormat {
+            basic: false,
+            expanded: false,
+            date: DateRepr::Calendar,
+            mode: ParseMode::Strict,
+        }
+    }
+
+    /// The basic format, which has no separators except 'T', e.g.
+    /// "20150203T040506".
+    pub const fn basic() -> Self {
+        IsoFormat {
+            basic: true,
+            expanded: false,
+            date: DateRepr::Calendar,
+            mode: ParseMode::Strict,
+        }
+    }
+
+    /// Represents years by the expanded representation, which has a sign and
+    /// at least 6 digits, e.g. "+002015-02-03" and "-0000010203".
+    ///
+    /// Every civil time, including `MIN` and `MAX`, is represented
+    /// unambiguously, and parsing requires the sign.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilDay, CivilYear, IsoFormat};
+    ///
+    /// let format = IsoFormat::extended().expanded();
+    /// let d = CivilDay::new(-1, 2, 3);
+    /// assert_eq!("-000001-02-03", d.format_iso(format).to_string());
+    /// assert_eq!(d, CivilDay::parse_iso("-000001-02-03", format).unwrap());
+    ///
+    /// let s = CivilYear::MAX.format_iso(format).to_string();
+    /// assert_eq!("+9223372036854775807", s);
+    /// assert_eq!(CivilYear::MAX, CivilYear::parse_iso(&s, format).unwrap());
+    /// ```
+    pub const fn expanded(self) -> Self {
+        IsoFormat {
+            expanded: true,
+            ..self
+        }
+    }
+
+    /// Represents the date by the ISO 8601 week date, which is the
+    /// week-numbering year, the week (from 1 to 53) and the weekday number
+    /// (from 1 for Monday to 7 for Sunday), e.g. "2015-W06-2" and "2015W062".
+    ///
+    /// The week-numbering year differs from the calendar year near the start
+    /// and the end of a year, e.g. 2014-12-29 is "2015-W01-1". The first day
+    /// of the minimum year, whose week-numbering year is not representable,
+    /// is in week 0, see [`iso_week_date()`](crate::CivilDay::iso_week_date).
+    /// [CivilMonth] and [CivilYear] have no week date and are represented as
+    /// calendar dates.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilDay, IsoFormat};
+    ///
+    /// let format = IsoFormat::extended().week_date();
+    /// let d = CivilDay::new(2014, 12, 29);
+    /// assert_eq!("2015-W01-1", d.format_iso(format).to_string());
+    /// assert_eq!(d, CivilDay::parse_iso("2015-W01-1", format).unwrap());
+    /// ```
+    pub const fn week_date(self) -> Self {
+        IsoFormat {
+            date: DateRepr::Week,
+            ..self
+        }
+    }
+
+    /// Represents the date by the ISO 8601 ordinal date, which is the year
+    /// and the day of year (from 1 to 366), e.g. "2015-034" and "2015034".
+    ///
+    /// [CivilMonth] and [CivilYear] have no ordinal date and are represented
+    /// as calendar dates.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilDay, IsoFormat};
+    ///
+    /// let format = IsoFormat::basic().ordinal_date();
+    /// let d = CivilDay::new(2015, 2, 3);
+    /// assert_eq!("2015034", d.format_iso(format).to_string());
+    /// assert_eq!(d, CivilDay::parse_iso("2015034", format).unwrap());
+    /// ```
+    pub const fn ordinal_date(self) -> Self {
+        IsoFormat {
+            date: DateRepr::Ordinal,
+            ..self
+        }
+    }
+
+    /// Sets how parsing handles fields out of their ranges, which is
+    /// [ParseMode::Strict] by default.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilDay, IsoFormat, ParseMode};
+    ///
+    /// assert!(CivilDay::parse_iso("2016-10-32", IsoFormat::extended()).is_err());
+    ///
+    /// let format = IsoFormat::extended().parse_mode(ParseMode::Normalize);
+    /// let d = CivilDay::parse_iso("2016-10-32", format).unwrap();
+    /// assert_eq!(CivilDay::new(2016, 11, 1), d);
+    /// ```
+    pub const fn parse_mode(self, mode: ParseMode) -> Self {
+        IsoFormat { mode, ..self }
+    }
+
+    /// Returns the items to represent the first `n` fields (from year to
+    /// second).
+    fn items(&self, n: usize) -> IsoItems {
+        const SPECS: [Spec; 6] = [
+            Spec::Year,
+            Spec::Month,
+            Spec::Day,
+            Spec::Hour,
+            Spec::Minute,
+            Spec::Second,
+        ];
+        const SEPARATORS: [&str; 6] = ["", "-", "-", "T", ":", ":"];
+
+        let mut items = IsoItems::default();
+        let start = match self.date {
+            DateRepr::Week if n >= 3 => {
+                self.push_week_date(&mut items);
+                3
+            }
+            DateRepr::Ordinal if n >= 3 => {
+                items.push(Item::Spec(self.year_spec()));
+                if !self.basic {
+                    items.push(Item::Literal("-"));
+                }
+                items.push(Item::Spec(Spec::YearDay));
+                3
+            }
+            _ => {
+                items.push(Item::Spec(self.year_spec()));
+                1
+            }
+        };
+        for i in start..n {
+            if !self.basic || SEPARATORS[i] == "T" {
+                items.push(Item::Literal(SEPARATORS[i]));
+            }
+            items.push(Item::Spec(SPECS[i]));
+        }
+        items
+    }
+
+    /// Returns the directive of the calendar year.
+    const fn year_spec(&self) -> Spec {
+        if self.expanded {
+            Spec::YearExpanded
+        } else if self.basic {
+            Spec::YearPadded
+        } else {
+            Spec::Year
+        }
+    }
+
+    /// Returns the directive of the week-numbering year.
+    const fn iso_year_spec(&self) -> Spec {
+        if self.expanded {
+            Spec::IsoYearExpanded
+        } else if self.basic {
+            Spec::IsoYearPadded
+        } else {
+            Spec::IsoYear
+        }
+    }
+
+    /// Pushes the items of the week date.
+    fn push_week_date(&self, items: &mut IsoItems) {
+        items.push(Item::Spec(self.iso_year_spec()));
+        if self.basic {
+            items.push(Item::Literal("W"));
+            items.push(Item::Spec(Spec::IsoWeek));
+        } else {
+            items.push(Item::Literal("-W"));
+            items.push(Item::Spec(Spec::IsoWeek));
+            items.push(Item::Literal("-"));
+        }
+        items.push(Item::Spec(Spec::WeekdayFromMonday));
+    }
+}
+
+/// Maximum number of items of ISO 8601 representations.
+const MAX_ITEMS: usize = 11;
+
+/// Items of an ISO 8601 representation, stored without allocation.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct IsoItems {
+    items: [Item<'static>; MAX_ITEMS],
+    len: usize,
+}
+
+impl IsoItems {
+    fn push(&mut self, item: Item<'static>) {
+        self.items[self.len] = item;
+        self.len += 1;
+    }
+}
+
+impl Default for IsoItems {
+    fn default() -> Self {
+        IsoItems {
+            items: [Item::Literal(""); MAX_ITEMS],
+            len: 0,
+        }
+    }
+}
+
+impl Deref for IsoItems {
+    type Target = [Item<'static>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.items[..self.len]
+    }
+}
+
+macro_rules! impl_iso {
+    ($Type: ident, $n: expr) => {
+        impl $Type {
+            /// Formats the civil time in the ISO 8601 representation `format`.
+            ///
+            /// ```rust
+            /// use civil_time::{CivilSecond, IsoFormat};
+            ///
+            /// let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+            /// assert_eq!("20150203T040506", ss.format_iso(IsoFormat::basic()).to_string());
+            /// ```
+            pub fn format_iso(&self, format: IsoFormat) -> DelayedFormat<'static> {
+                DelayedFormat::from_iso(CivilSecond::from_fields(self.0), format.items($n))
+            }
+
+            /// Parses a civil time in the ISO 8601 representation `format`.
+            ///
+            /// ```rust
+            /// use civil_time::{CivilSecond, IsoFormat};
+            ///
+            /// let ss = CivilSecond::parse_iso("20150203T040506", IsoFormat::basic()).unwrap();
+            /// assert_eq!(CivilSecond::new(2015, 2, 3, 4, 5, 6), ss);
+            /// ```
+            pub fn parse_iso(input: &str, format: IsoFormat) -> Result<Self, ParseError> {
+                let options = ParseOptions {
+                    exact_width: true,
+                    mode: format.mode,
+                    ..ParseOptions::default()
+                };
+                parse_items(input, &format.items($n), options).map(Self::from_fields)
+            }
+        }
+    };
+}
+
+impl_iso!(CivilSecond, 6);
+impl_iso!(CivilMinute, 5);
+impl_iso!(CivilHour, 4);
+impl_iso!(CivilDay, 3);
+impl_iso!(CivilMonth, 2);
+impl_iso!(CivilYear, 1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DiffType, Field, ParseErrorKind};
+
+    macro_rules! check_round_trip {
+        ($Type: ident, $v: expr, $format: expr) => {{
+            let v = $v;
+            let s = v.format_iso($format).to_string();
+            assert_eq!(v, $Type::parse_iso(&s, $format).unwrap(), "{}", s);
+        }};
+    }
+
+    const ALL_FORMATS: [IsoFormat; 10] = [
+        IsoFormat::extended(),
+        IsoFormat::basic(),
+        IsoFormat::extended().expanded(),
+        IsoFormat::basic().expanded(),
+        IsoFormat::extended().week_date(),
+        IsoFormat::basic().week_date(),
+        IsoFormat::extended().ordinal_date(),
+        IsoFormat::basic().ordinal_date(),
+        IsoFormat::extended().week_date().expanded(),
+        IsoFormat::basic().ordinal_date().expanded(),
+    ];
+
+    macro_rules! check_all {
+        ($v: expr) => {
+            check_all!($v, ALL_FORMATS)
+        };
+        ($v: expr, $formats: expr) => {{
+            let v = $v;
+            for format in &$formats {
+                check_round_trip!(CivilSecond, v, *format);
+                check_round_trip!(CivilMinute, CivilMinute::from(v), *format);
+                check_round_trip!(CivilHour, CivilHour::from(v), *format);
+                check_round_trip!(CivilDay, CivilDay::from(v), *format);
+                check_round_trip!(CivilMonth, CivilMonth::from(v), *format);
+                check_round_trip!(CivilYear, CivilYear::from(v), *format);
+            }
+        }};
+    }
+
+    #[test]
+    fn test_format_iso() {
+        let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+        let basic = IsoFormat::basic();
+        assert_eq!("20150203T040506", ss.format_iso(basic).to_string());
+        assert_eq!(
+            "20150203T0405",
+            CivilMinute::from(ss).format_iso(basic).to_string()
+        );
+        assert_eq!(
+            "20150203T04",
+            CivilHour::from(ss).format_iso(basic).to_string()
+        );
+        assert_eq!("20150203", CivilDay::from(ss).format_iso(basic).to_string());
+        assert_eq!("201502", CivilMonth::from(ss).format_iso(basic).to_string());
+        assert_eq!("2015", CivilYear::from(ss).format_iso(basic).to_string());
+
+        // The extended format is the same as Debug.
+        let extended = IsoFormat::extended();
+        assert_eq!(IsoFormat::default(), extended);
+        assert_eq!("2015-02-03T04:05:06", ss.format_iso(extended).to_string());
+        assert_eq!(
+            "2015-02",
+            CivilMonth::from(ss).format_iso(extended).to_string()
+        );
+        assert_eq!(
+            "5-01-01",
+            CivilDay::new(5, 1, 1).format_iso(extended).to_string()
+        );
+
+        // Years are padded in the basic format.
+        assert_eq!(
+            "00050101",
+            CivilDay::new(5, 1, 1).format_iso(basic).to_string()
+        );
+        assert_eq!(
+            "-00050101",
+            CivilDay::new(-5, 1, 1).format_iso(basic).to_string()
+        );
+        assert_eq!(
+            "123450101",
+            CivilDay::new(12345, 1, 1).format_iso(basic).to_string()
+        );
+        assert_eq!(
+            "-92233720368547758081231",
+            CivilDay::new(DiffType::MIN, 12, 31)
+                .format_iso(basic)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_iso() {
+        let basic = IsoFormat::basic();
+        let ss = CivilSecond::parse_iso("20150203T040506", basic).unwrap();
+        assert_eq!(CivilSecond::new(2015, 2, 3, 4, 5, 6), ss);
+        let d = CivilDay::parse_iso("20150203", basic).unwrap();
+        assert_eq!(CivilDay::new(2015, 2, 3), d);
+        let m = CivilMonth::parse_iso("201502", basic).unwrap();
+        assert_eq!(CivilMonth::new(2015, 2), m);
+        let d = CivilDay::parse_iso("-00050101", basic).unwrap();
+        assert_eq!(CivilDay::new(-5, 1, 1), d);
+        let d = CivilDay::parse_iso("123450101", basic).unwrap();
+        assert_eq!(CivilDay::new(12345, 1, 1), d);
+
+        let extended = IsoFormat::extended();
+        let ss = CivilSecond::parse_iso("2015-02-03T04:05:06", extended).unwrap();
+        assert_eq!(CivilSecond::new(2015, 2, 3, 4, 5, 6), ss);
+        let d = CivilDay::parse_iso("5-01-01", extended).unwrap();
+        assert_eq!(CivilDay::new(5, 1, 1), d);
+    }
+
+    #[test]
+    fn test_parse_iso_error() {
+        use ParseErrorKind::*;
+
+        let check = |input: &str, format, kind, field| {
+            let e = CivilSecond::parse_iso(input, format).unwrap_err();
+            assert_eq!(kind, e.kind(), "input: {}", input);
+            assert_eq!(field, e.field(), "input: {}", input);
+        };
+
+        let basic = IsoFormat::basic();
+        check("150203T040506", basic, Invalid, Some(Field::Year));
+        check("20150203T04056", basic, TooShort, Some(Field::Second));
+        check("20150203040506", basic, TooShort, None);
+        check("2015-02-03T04:05:06", basic, Invalid, Some(Field::Year));
+        check("20150229T040506", basic, OutOfRange, Some(Field::Day));
+        check("20150203T240506", basic, OutOfRange, Some(Field::Hour));
+        check("20150203T040506Z", basic, TooLong, None);
+
+        let extended = IsoFormat::extended();
+        check("2015-2-03T04:05:06", extended, Invalid, Some(Field::Month));
+        check("2015-02-03T4:05:06", extended, Invalid, Some(Field::Hour));
+        check("20150203T040506", extended, Invalid, None);
+    }
+
+    #[test]
+    fn test_week_date() {
+        let extended = IsoFormat::extended().week_date();
+        let basic = IsoFormat::basic().week_date();
+        let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+        assert_eq!("2015-W06-2T04:05:06", ss.format_iso(extended).to_string());
+        assert_eq!("2015W062T040506", ss.format_iso(basic).to_string());
+        assert_eq!(
+            "2015-W06-2T04",
+            CivilHour::from(ss).format_iso(extended).to_string()
+        );
+        assert_eq!("2015W062", CivilDay::from(ss).format_iso(basic).to_string());
+
+        // Months and years are represented as calendar dates.
+        assert_eq!(
+            "2015-02",
+            CivilMonth::from(ss).format_iso(extended).to_string()
+        );
+        assert_eq!("2015", CivilYear::from(ss).format_iso(basic).to_string());
+
+        // Rollover of the week-numbering year.
+        let d = CivilDay::new(2014, 12, 29);
+        assert_eq!("2015-W01-1", d.format_iso(extended).to_string());
+        assert_eq!(d, CivilDay::parse_iso("2015-W01-1", extended).unwrap());
+        let d = CivilDay::new(2016, 1, 3);
+        assert_eq!("2015W537", d.format_iso(basic).to_string());
+        assert_eq!(d, CivilDay::parse_iso("2015W537", basic).unwrap());
+        assert_eq!(
+            "-0001W521",
+            CivilDay::new(-1, 12, 27).format_iso(basic).to_string()
+        );
+
+        let ss = CivilSecond::parse_iso("2015-W06-2T04:05:06", extended).unwrap();
+        assert_eq!(CivilSecond::new(2015, 2, 3, 4, 5, 6), ss);
+    }
+
+    #[test]
+    fn test_week_date_error() {
+        use ParseErrorKind::*;
+
+        let check = |input: &str, format, kind, field| {
+            let e = CivilDay::parse_iso(input, format).unwrap_err();
+            assert_eq!(kind, e.kind(), "input: {}", input);
+            assert_eq!(field, e.field(), "input: {}", input);
+        };
+
+        let extended = IsoFormat::extended().week_date();
+        check("2015-W06-8", extended, OutOfRange, Some(Field::Day));
+        check("2015-W00-1", extended, OutOfRange, Some(Field::Day));
+        check("2015-W6-2", extended, Invalid, Some(Field::Day));
+        check("2015-02-03", extended, Invalid, None);
+        // 2014 has 52 weeks.
+        check("2014-W53-1", extended, OutOfRange, Some(Field::Day));
+        assert!(CivilDay::parse_iso("2015-W53-1", extended).is_ok());
+
+        // Week 0 is only valid in the minimum year.
+        let expanded = extended.expanded();
+        check(
+            "-9223372036854775807-W00-7",
+            expanded,
+            OutOfRange,
+            Some(Field::Day),
+        );
+        assert_eq!(
+            Ok(CivilDay::MIN),
+            CivilDay::parse_iso("-9223372036854775808-W00-7", expanded)
+        );
+
+        let basic = IsoFormat::basic().week_date();
+        check("2015W06", basic, TooShort, Some(Field::Day));
+        check("2015-W06-2", basic, Invalid, None);
+    }
+
+    #[test]
+    fn test_ordinal_date() {
+        let extended = IsoFormat::extended().ordinal_date();
+        let basic = IsoFormat::basic().ordinal_date();
+        let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+        assert_eq!("2015-034T04:05:06", ss.format_iso(extended).to_string());
+        assert_eq!("2015034T040506", ss.format_iso(basic).to_string());
+        assert_eq!(
+            "2015-034",
+            CivilDay::from(ss).format_iso(extended).to_string()
+        );
+        assert_eq!(
+            "2015-02",
+            CivilMonth::from(ss).format_iso(extended).to_string()
+        );
+        assert_eq!(
+            "-0001365",
+            CivilDay::new(-1, 12, 31).format_iso(basic).to_string()
+        );
+        assert_eq!(
+            "2016-366",
+            CivilDay::new(2016, 12, 31).format_iso(extended).to_string()
+        );
+
+        let ss = CivilSecond::parse_iso("2015-034T04:05:06", extended).unwrap();
+        assert_eq!(CivilSecond::new(2015, 2, 3, 4, 5, 6), ss);
+        let d = CivilDay::parse_iso("2016366", basic).unwrap();
+        assert_eq!(CivilDay::new(2016, 12, 31), d);
+
+        // The ordinal date is the inverse of from_ordinal().
+        assert_eq!(
+            CivilDay::from_ordinal(2015, 34),
+            CivilDay::parse_iso("2015-034", extended).unwrap()
+        );
+
+        use ParseErrorKind::*;
+        let check = |input: &str, format, kind, field| {
+            let e = CivilDay::parse_iso(input, format).unwrap_err();
+            assert_eq!(kind, e.kind(), "input: {}", input);
+            assert_eq!(field, e.field(), "input: {}", input);
+        };
+        check("2015-366", extended, OutOfRange, Some(Field::Day));
+        check("2015-000", extended, OutOfRange, Some(Field::Day));
+        check("2015-34", extended, TooShort, Some(Field::Day));
+        check("2015-02-03", extended, Invalid, Some(Field::Day));
+        check("201534", basic, TooShort, Some(Field::Year));
+    }
+
+    #[test]
+    fn test_expanded() {
+        let extended = IsoFormat::extended().expanded();
+        let basic = IsoFormat::basic().expanded();
+        let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+        assert_eq!(
+            "+002015-02-03T04:05:06",
+            ss.format_iso(extended).to_string()
+        );
+        assert_eq!("+0020150203T040506", ss.format_iso(basic).to_string());
+        assert_eq!("+002015", CivilYear::from(ss).format_iso(basic).to_string());
+        assert_eq!(
+            "+002015-W06-2",
+            CivilDay::from(ss)
+                .format_iso(extended.week_date())
+                .to_string()
+        );
+        assert_eq!(
+            "+002015034",
+            CivilDay::from(ss)
+                .format_iso(basic.ordinal_date())
+                .to_string()
+        );
+
+        // (year, extended)
+        let table = [
+            (0, "+000000-01-01"),
+            (-1, "-000001-01-01"),
+            (999_999, "+999999-01-01"),
+            (1_000_000, "+1000000-01-01"),
+            (-1_000_000, "-1000000-01-01"),
+            (DiffType::MAX, "+9223372036854775807-01-01"),
+            (DiffType::MIN, "-9223372036854775808-01-01"),
+        ];
+        for (y, expect) in table.iter() {
+            let d = CivilDay::new(*y, 1, 1);
+            assert_eq!(*expect, d.format_iso(extended).to_string());
+            assert_eq!(d, CivilDay::parse_iso(expect, extended).unwrap());
+        }
+
+        let d = CivilDay::parse_iso("-0000010203", basic).unwrap();
+        assert_eq!(CivilDay::new(-1, 2, 3), d);
+
+        use ParseErrorKind::*;
+        let check = |input: &str, format, kind, field| {
+            let e = CivilDay::parse_iso(input, format).unwrap_err();
+            assert_eq!(kind, e.kind(), "input: {}", input);
+            assert_eq!(field, e.field(), "input: {}", input);
+        };
+        check("002015-02-03", extended, Invalid, Some(Field::Year));
+        check("+02015-02-03", extended, Invalid, Some(Field::Year));
+        check("+2015-02-03", extended, Invalid, Some(Field::Year));
+        check(
+            "+9223372036854775808-01-01",
+            extended,
+            OutOfRange,
+            Some(Field::Year),
+        );
+        check("+00201502", basic, TooShort, Some(Field::Year));
+        check(
+            "2015-02-03",
+            IsoFormat::extended().expanded(),
+            Invalid,
+            Some(Field::Year),
+        );
+    }
+
+    #[test]
+    fn test_parse_mode() {
+        let format = IsoFormat::extended().parse_mode(ParseMode::Normalize);
+        let check = |input: &str, expect: CivilSecond| {
+            let e = CivilSecond::parse_iso(input, IsoFormat::extended()).unwrap_err();
+            assert_eq!(ParseErrorKind::OutOfRange, e.kind(), "input: {}", input);
+            assert_eq!(expect, CivilSecond::parse_iso(input, format).unwrap());
+        };
+        check(
+            "2016-10-32T00:00:00",
+            CivilSecond::new(2016, 11, 1, 0, 0, 0),
+        );
+        check("2016-13-01T00:00:00", CivilSecond::new(2017, 1, 1, 0, 0, 0));
+        check(
+            "2016-00-00T00:00:00",
+            CivilSecond::new(2015, 11, 30, 0, 0, 0),
+        );
+        check("2015-02-29T24:60:60", CivilSecond::new(2015, 3, 2, 1, 1, 0));
+        check(
+            "2016-12-31T23:59:99",
+            CivilSecond::new(2017, 1, 1, 0, 0, 39),
+        );
+
+        let format = format.ordinal_date();
+        let d = CivilDay::parse_iso("2015-366", format).unwrap();
+        assert_eq!(CivilDay::new(2016, 1, 1), d);
+        let format = IsoFormat::extended()
+            .week_date()
+            .parse_mode(ParseMode::Normalize);
+        let d = CivilDay::parse_iso("2014-W53-1", format).unwrap();
+        assert_eq!(CivilDay::new(2014, 12, 29), d);
+        // Days beyond the maximum year are not wrapped around.
+        let expanded = format.expanded();
+        let d = CivilDay::parse_iso("+9223372036854775807-W53-4", expanded).unwrap();
+        assert_eq!(CivilDay::MAX, d);
+        let e = CivilDay::parse_iso("+9223372036854775807-W53-5", expanded).unwrap_err();
+        assert_eq!(ParseErrorKind::OutOfRange, e.kind());
+        assert_eq!(Some(Field::Year), e.field());
+
+        // Weekdays are not normalized.
+        let e = CivilDay::parse_iso("2015-W01-8", format).unwrap_err();
+        assert_eq!(ParseErrorKind::OutOfRange, e.kind());
+        // Malformed input is still an error.
+        let e = CivilDay::parse_iso("2016-10-3", format).unwrap_err();
+        assert_eq!(ParseErrorKind::Invalid, e.kind());
+    }
+
+    #[test]
+    fn test_round_trip_fields() {
+        // Every month and day of a leap year and a common year.
+        for y in &[2015, 2016] {
+            let start = CivilDay::new(*y, 1, 1);
+            for i in 0..366 {
+                check_all!(CivilSecond::from(start + i) + 3723);
+            }
+        }
+
+        // Every second of a day.
+        let start = CivilSecond::new(2015, 2, 3, 0, 0, 0);
+        for i in 0..86400 {
+            let ss = start + i;
+            let s = ss.format_iso(IsoFormat::basic()).to_string();
+            assert_eq!(ss, CivilSecond::parse_iso(&s, IsoFormat::basic()).unwrap());
+            let s = format!("{:?}", ss);
+            assert_eq!(
+                ss,
+                CivilSecond::parse_iso(&s, IsoFormat::extended()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_trip_normalized() {
+        check_all!(CivilSecond::default());
+        check_all!(CivilSecond::new(0, 1, 1, 0, 0, 0));
+        check_all!(CivilSecond::new(-1, 12, 31, 23, 59, 59));
+        check_all!(CivilSecond::new(9999, 12, 31, 23, 59, 59));
+        check_all!(CivilSecond::new(10000, 1, 1, 0, 0, 0));
+
+        // Normalization edge cases.
+        check_all!(CivilSecond::new(2016, 1, 28, 17, 14, 121));
+        check_all!(CivilSecond::new(2016, 1, 28, 17, 14, -121));
+        check_all!(CivilSecond::new(2016, 1, 28, 17, 121, 12));
+        check_all!(CivilSecond::new(2016, 1, 28, 17, -121, 12));
+        check_all!(CivilSecond::new(2016, 1, 28, 49, 14, 12));
+        check_all!(CivilSecond::new(2016, 1, 28, -49, 14, 12));
+        check_all!(CivilSecond::new(2016, 25, 28, 17, 14, 12));
+        check_all!(CivilSecond::new(2016, -25, 28, 17, 14, 12));
+        check_all!(CivilSecond::new(2016, 1, 292195, 17, 14, 12));
+        check_all!(CivilSecond::new(2016, 1, -292195, 17, 14, 12));
+        check_all!(CivilSecond::new(2016, -42, 122, 99, -147, 4949));
+
+        let max = DiffType::from(i32::MAX);
+        check_all!(CivilSecond::new(1970, max, max, max, max, max));
+        let min = DiffType::from(i32::MIN);
+        check_all!(CivilSecond::new(1970, min, min, min, min, min));
+
+        // Limits of years. The first day is in week 0 of the minimum year.
+        check_all!(CivilSecond::MAX);
+        check_all!(CivilSecond::MIN);
+        check_all!(CivilSecond::MIN + 86400);
+        check_all!(CivilSecond::new(DiffType::MAX, 1, 1, 0, 0, 0));
+        check_all!(CivilSecond::new(DiffType::MIN, 12, 31, 23, 59, 59));
+    }
+}
@@ -116,6 +116,19 @@
 //! assert_eq!("2015", format!("{:?}", y));
 //! ```
 //!
+//! Constants can also be written as literals by macros such as
+//! [`civil_day!`], which reject out-of-range fields at compile time instead of
+//! normalizing them.
+//!
+//! ```rust
+//! use civil_time::{civil_day, civil_second, CivilDay, CivilSecond};
+//!
+//! const D: CivilDay = civil_day!("2015-02-03");
+//! assert_eq!(CivilDay::new(2015, 2, 3), D);
+//! const SS: CivilSecond = civil_second!("2015-02-03T04:05:06");
+//! assert_eq!(CivilSecond::new(2015, 2, 3, 4, 5, 6), SS);
+//! ```
+//!
 //! ### Conversion
 //!
 //! The alignment of a civil-time struct cannot change, but the struct may be
@@ -160,9 +173,167 @@
 //! assert_eq!("2016-11-01", format!("{:?}", d));
 //! ```
 //!
-//! Note: If normalization is undesired, you can signal an error by comparing
-//! the `new()` arguments to the normalized values returned by the YMDHMS
-//! properties.
+//! A single field of a value can be replaced by the `with_*()` methods, which
+//! keep the alignment, with a [`ReplacePolicy`] for a field out of range or a
+//! day beyond the end of the month.
+//!
+//! ```rust
+//! use civil_time::{CivilDay, ReplacePolicy};
+//!
+//! let d = CivilDay::new(2015, 1, 31);
+//! let e = d.with_month(2, ReplacePolicy::Clamp).unwrap();
+//! assert_eq!(CivilDay::new(2015, 2, 28), e);
+//! ```
+//!
+//! To find out what was normalized, `new_with_report()` returns a
+//! [`Normalization`] record along with the value.
+//!
+//! ```rust
+//! use civil_time::{CivilDay, Field};
+//!
+//! let (d, report) = CivilDay::new_with_report(2016, 1, 32);
+//! assert_eq!(CivilDay::new(2016, 2, 1), d);
+//! assert!(report.out_of_range(Field::Day));
+//! assert_eq!(1, report.carry(Field::Month));
+//! ```
+//!
+//! Note: If normalization is undesired, use `try_new()` (or
+//! [`Builder::try_build()`]) instead, which returns a [`FieldError`] naming
+//! the field outside its range.
+//!
+//! ```rust
+//! use civil_time::{CivilDay, Field};
+//!
+//! let e = CivilDay::try_new(2016, 4, 31).unwrap_err();
+//! assert_eq!(Field::Day, e.field());
+//! assert_eq!(1..=30, e.range());
+//! ```
+//!
+//! ### Parsing
+//!
+//! Each civil-time type can be parsed from a string in the layout printed by
+//! its `Debug` impl. Unlike `new()`, parsing never normalizes: a field out of
+//! its range is an error that reports the failed [`Field`].
+//!
+//! ```rust
+//! use civil_time::{CivilDay, CivilSecond, Field, ParseErrorKind};
+//!
+//! let ss: CivilSecond = "2015-02-03T04:05:06".parse().unwrap();
+//! assert_eq!(CivilSecond::new(2015, 2, 3, 4, 5, 6), ss);
+//!
+//! let err = "2016-10-32".parse::<CivilDay>().unwrap_err();
+//! assert_eq!(ParseErrorKind::OutOfRange, err.kind());
+//! assert_eq!(Some(Field::Day), err.field());
+//! assert_eq!(8..10, err.span());
+//! ```
+//!
+//! The error also carries the byte span of the offending input and what was
+//! expected there, and [`ParseError::diagnostic()`] renders both with carets
+//! under the input.
+//!
+//! A strftime-like pattern can also be given to parse other layouts, see
+//! [`CivilSecond::parse_from_str()`].
+//!
+//! ```rust
+//! use civil_time::{CivilDay, CivilSecond};
+//!
+//! let d = CivilDay::parse_from_str("03 Feb 2015", "%d %b %Y").unwrap();
+//! assert_eq!(CivilDay::new(2015, 2, 3), d);
+//!
+//! let ss = CivilSecond::parse_from_str("20150203 0405", "%Y%m%d %H%M").unwrap();
+//! assert_eq!(CivilSecond::new(2015, 2, 3, 4, 5, 0), ss);
+//! ```
+//!
+//! Timestamps of syslog messages are parsed as a [`SyslogTimestamp`], which
+//! infers the missing year of RFC 3164 timestamps from a reference time.
+//!
+//! ```rust
+//! use civil_time::{CivilSecond, SyslogTimestamp};
+//!
+//! let reference = CivilSecond::new(2016, 1, 2, 0, 0, 0);
+//! let ts = SyslogTimestamp::parse("Dec 31 23:59:59", reference).unwrap();
+//! assert_eq!(CivilSecond::new(2015, 12, 31, 23, 59, 59), ts.civil_second());
+//! ```
+//!
+//! When the layout of timestamps is unknown, a [`FormatDetector`] picks the
+//! candidate layout most consistent with a few samples.
+//!
+//! ```rust
+//! use civil_time::{CivilDay, CivilSecond, FormatDetector};
+//!
+//! let detector = FormatDetector::new(CivilSecond::new(2015, 6, 1, 0, 0, 0));
+//! let format = detector.detect(&["03.02.2015", "04.02.2015"]).unwrap();
+//! let d: CivilDay = format.parse("05.02.2015").unwrap();
+//! assert_eq!(CivilDay::new(2015, 2, 5), d);
+//! ```
+//!
+//! ### Formatting
+//!
+//! Besides the `Debug` layout, civil-time types can be formatted by a
+//! strftime-like pattern. See [`DelayedFormat`] for supported directives.
+//!
+//! ```rust
+//! use civil_time::CivilDay;
+//!
+//! let d = CivilDay::new(2015, 2, 3);
+//! assert_eq!("03 Feb 2015", d.format("%d %b %Y").to_string());
+//! assert_eq!("2015/034", d.format("%Y/%j").to_string());
+//! ```
+//!
+//! A pattern used repeatedly can be compiled once into a [`FormatDescription`],
+//! either at runtime or at build time by the [`format_description!`] macro,
+//! and then used to both format and parse any civil-time type.
+//!
+//! ```rust
+//! use civil_time::{format_description, CivilDay, FormatDescription};
+//!
+//! const DESC: FormatDescription<'static> = format_description!("%d %b %Y");
+//! let d: CivilDay = DESC.parse("03 Feb 2015").unwrap();
+//! assert_eq!("03 Feb 2015", d.format_with(&DESC).to_string());
+//! ```
+//!
+//! ISO 8601 representations other than the `Debug` layout are chosen by an
+//! [`IsoFormat`].
+//!
+//! ```rust
+//! use civil_time::{CivilSecond, IsoFormat};
+//!
+//! let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+//! assert_eq!("20150203T040506", ss.format_iso(IsoFormat::basic()).to_string());
+//! assert_eq!(ss, CivilSecond::parse_iso("20150203T040506", IsoFormat::basic()).unwrap());
+//!
+//! let week_date = IsoFormat::extended().week_date();
+//! assert_eq!("2015-W06-2T04:05:06", ss.format_iso(week_date).to_string());
+//! let ordinal_date = IsoFormat::extended().ordinal_date();
+//! assert_eq!("2015-034T04:05:06", ss.format_iso(ordinal_date).to_string());
+//! ```
+//!
+//! Localized month and weekday names are formatted and parsed by a
+//! CLDR-style [`LocalePattern`] of a [`Locale`], which can also be looked up
+//! by a skeleton of the fields to show.
+//!
+//! ```rust
+//! use civil_time::{CivilDay, Locale, LocalePattern};
+//!
+//! let pattern = LocalePattern::from_skeleton(Locale::De, "yMMMMd").unwrap();
+//! let d = CivilDay::new(2015, 2, 3);
+//! assert_eq!("3. Februar 2015", d.format_locale(&pattern).to_string());
+//! assert_eq!(d, CivilDay::parse_locale("3. Februar 2015", &pattern).unwrap());
+//! ```
+//!
+//! A [`CivilSecond`] can be formatted and parsed as an HTTP-date, or as an
+//! RFC 2822 email date together with its [`UtcOffset`].
+//!
+//! ```rust
+//! use civil_time::{CivilSecond, UtcOffset};
+//!
+//! let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+//! assert_eq!("Tue, 03 Feb 2015 04:05:06 GMT", ss.format_http_date().unwrap().to_string());
+//!
+//! let (ss, offset) = CivilSecond::parse_rfc2822("Tue, 3 Feb 2015 04:05:06 -0500").unwrap();
+//! assert_eq!(CivilSecond::new(2015, 2, 3, 4, 5, 6), ss);
+//! assert_eq!(UtcOffset::from_minutes(-300), offset);
+//! ```
 //!
 //! ### Properties
 //! All civil-time types have accessors for all six of the civil-time fields:
@@ -226,6 +397,21 @@
 //! // let m = c - CivilMonth::from(c); // Won't compile: different types.
 //! ```
 //!
+//! The operators do not detect results beyond `MAX` or `MIN`. The checked,
+//! saturating and overflowing methods, in the manner of the primitive
+//! integers, do so instead.
+//!
+//! ```rust
+//! use civil_time::{CivilDay, CivilSecond};
+//!
+//! let max = CivilDay::MAX;
+//! assert_eq!(None, max.checked_add(1));
+//! assert_eq!(Some(max), (max - 1).checked_add(1));
+//! assert_eq!(CivilDay::MAX, (max - 1).saturating_add(2));
+//! assert_eq!((CivilDay::MIN, true), max.overflowing_add(1));
+//! assert_eq!(None, CivilSecond::MAX.checked_difference(CivilSecond::MIN));
+//! ```
+//!
 //! ### Example: Adding a month to January 31.
 //!
 //! One of the classic questions that arises when considering a civil-time
@@ -329,6 +515,18 @@
 //! assert_eq!("2015-08-13", format!("{:?}", thurs2));
 //! ```
 //!
+//! Expressions such as "next friday" and "last business day of quarter" can be
+//! resolved against a [CivilSecond] by
+//! [`resolve_relative()`](CivilSecond::resolve_relative).
+//!
+//! ```rust
+//! use civil_time::{CivilDay, CivilSecond, CivilTime};
+//!
+//! let a = CivilSecond::new(2015, 8, 13, 12, 0, 0);
+//! let b = a.resolve_relative("next thursday").unwrap();
+//! assert_eq!(CivilTime::Day(CivilDay::new(2015, 8, 20)), b);
+//! ```
+//!
 //! ### Yearday
 //!
 //! Returns the day-of-year for the given civil-time value.
@@ -348,14 +546,54 @@ use std::fmt;
 use std::ops::{Add, AddAssign, Sub, SubAssign};
 
 mod alignment;
+mod buf;
+mod bulk;
 mod compare;
 mod convert;
 mod core;
+mod description;
+mod detect;
+mod error;
+mod format;
+mod humanize;
+mod iso;
+mod literal;
+mod locale;
+mod normalize;
+mod offset;
+mod parse;
+mod relative;
+mod replace;
+mod rfc2822;
+mod syslog;
 mod weekday;
 
-pub use crate::core::{DiffType, YearType};
+pub use crate::buf::{IsoBuf, MAX_ISO_LEN};
+pub use crate::bulk::{BulkParsed, FixedLayout};
+pub use crate::core::{DiffType, Field, FieldError, YearType};
+pub use crate::description::FormatDescription;
+pub use crate::detect::{Candidate, DetectedFormat, FormatDetector, TimestampLayout};
+pub use crate::error::Error;
+pub use crate::format::{DelayedFormat, FormatError};
+pub use crate::humanize::{RelativeFormat, RelativeTime, RelativeUnit, Rounding};
+pub use crate::iso::IsoFormat;
+pub use crate::locale::{Locale, LocalePattern, LocalePatternError, NameContext, NameWidth};
+pub use crate::normalize::Normalization;
+pub use crate::offset::UtcOffset;
+pub use crate::parse::{
+    Diagnostic, Expected, ParseError, ParseErrorKind, ParseMode, ParseOptions, TwoDigitYear,
+};
+pub use crate::relative::CivilTime;
+pub use crate::replace::ReplacePolicy;
+pub use crate::syslog::SyslogTimestamp;
 pub use crate::weekday::Weekday;
 
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::description::{compile_items, item_count};
+    pub use crate::format::Item;
+}
+
 /// Helper trait to construct a civil time type.
 pub trait BuildCivilTime {
     /// Build civil time types by given year `y`, month `m`, day `d`,
@@ -447,6 +685,34 @@ macro_rules! impl_civil_time_type {
                 Self::from_fields(fields)
             }
 
+            const fn try_from_ymd_hms(
+                y: YearType,
+                m: DiffType,
+                d: DiffType,
+                hh: DiffType,
+                mm: DiffType,
+                ss: DiffType,
+            ) -> Result<Self, FieldError> {
+                match Fields::check(y, m, d, hh, mm, ss) {
+                    Ok(()) => Ok(Self::from_ymd_hms(y, m, d, hh, mm, ss)),
+                    Err(e) => Err(e),
+                }
+            }
+
+            const fn from_ymd_hms_with_report(
+                y: YearType,
+                m: DiffType,
+                d: DiffType,
+                hh: DiffType,
+                mm: DiffType,
+                ss: DiffType,
+            ) -> (Self, Normalization) {
+                let fields = Fields::n_sec(y, m, d, hh, mm, ss);
+                let report = Normalization::new(y, m, d, hh, mm, ss, fields);
+
+                (Self::from_fields(fields), report)
+            }
+
             const fn add_diff(self, n: DiffType) -> Self {
                 let fields = $Alignment::step(self.0, n);
 
@@ -466,6 +732,68 @@ macro_rules! impl_civil_time_type {
             const fn difference(self, other: Self) -> DiffType {
                 $Alignment::difference(self.0, other.0)
             }
+
+            /// Adds `n` units of the alignment, returns `None` if the result
+            /// is beyond [`MAX`](Self::MAX) or [`MIN`](Self::MIN).
+            pub const fn checked_add(self, n: DiffType) -> Option<Self> {
+                match self.overflowing_add(n) {
+                    (v, false) => Some(v),
+                    (_, true) => None,
+                }
+            }
+
+            /// Subtracts `n` units of the alignment, returns `None` if the
+            /// result is beyond [`MAX`](Self::MAX) or [`MIN`](Self::MIN).
+            pub const fn checked_sub(self, n: DiffType) -> Option<Self> {
+                match self.overflowing_sub(n) {
+                    (v, false) => Some(v),
+                    (_, true) => None,
+                }
+            }
+
+            /// Returns the difference from `other` in units of the alignment,
+            /// or `None` if it does not fit in [DiffType].
+            pub const fn checked_difference(self, other: Self) -> Option<DiffType> {
+                $Alignment::checked_difference(self.0, other.0)
+            }
+
+            /// Adds `n` units of the alignment, clamping the result to
+            /// [`MAX`](Self::MAX) or [`MIN`](Self::MIN).
+            pub const fn saturating_add(self, n: DiffType) -> Self {
+                match self.checked_add(n) {
+                    Some(v) => v,
+                    None if n > 0 => Self::MAX,
+                    None => Self::MIN,
+                }
+            }
+
+            /// Subtracts `n` units of the alignment, clamping the result to
+            /// [`MAX`](Self::MAX) or [`MIN`](Self::MIN).
+            pub const fn saturating_sub(self, n: DiffType) -> Self {
+                match self.checked_sub(n) {
+                    Some(v) => v,
+                    None if n > 0 => Self::MIN,
+                    None => Self::MAX,
+                }
+            }
+
+            /// Adds `n` units of the alignment, returns the result with the
+            /// year wrapped around and whether an overflow occurred.
+            pub const fn overflowing_add(self, n: DiffType) -> (Self, bool) {
+                let (fields, overflow) = $Alignment::overflowing_step(self.0, n);
+                (Self::from_fields(fields), overflow)
+            }
+
+            /// Subtracts `n` units of the alignment, returns the result with
+            /// the year wrapped around and whether an overflow occurred.
+            pub const fn overflowing_sub(self, n: DiffType) -> (Self, bool) {
+                if n != DiffType::MIN {
+                    return self.overflowing_add(-n);
+                }
+                let (fields, o1) = $Alignment::overflowing_step(self.0, -(n + 1));
+                let (fields, o2) = $Alignment::overflowing_step(fields, 1);
+                (Self::from_fields(fields), o1 || o2)
+            }
         }
 
         impl Add<DiffType> for $Type {
@@ -542,6 +870,53 @@ impl CivilSecond {
     ) -> Self {
         Self::from_ymd_hms(y, m, d, hh, mm, ss)
     }
+
+    /// Construct a [CivilSecond] instance like [`new()`](CivilSecond::new),
+    /// but returns an error instead of normalizing a field outside its
+    /// natural range.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilSecond, Field};
+    ///
+    /// let ss = CivilSecond::try_new(2015, 2, 3, 4, 5, 6).unwrap();
+    /// assert_eq!(CivilSecond::new(2015, 2, 3, 4, 5, 6), ss);
+    ///
+    /// let e = CivilSecond::try_new(2015, 2, 3, 4, 5, 60).unwrap_err();
+    /// assert_eq!(Field::Second, e.field());
+    /// ```
+    pub const fn try_new(
+        y: YearType,
+        m: DiffType,
+        d: DiffType,
+        hh: DiffType,
+        mm: DiffType,
+        ss: DiffType,
+    ) -> Result<Self, FieldError> {
+        Self::try_from_ymd_hms(y, m, d, hh, mm, ss)
+    }
+
+    /// Construct a [CivilSecond] instance like [`new()`](CivilSecond::new),
+    /// together with a [Normalization] record of the fields that were out of
+    /// range and carried.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilSecond, Field};
+    ///
+    /// let (ss, report) = CivilSecond::new_with_report(2015, 2, 3, 4, 5, 75);
+    /// assert_eq!(CivilSecond::new(2015, 2, 3, 4, 6, 15), ss);
+    /// assert!(report.out_of_range(Field::Second));
+    /// assert_eq!(1, report.carry(Field::Minute));
+    /// ```
+    pub const fn new_with_report(
+        y: YearType,
+        m: DiffType,
+        d: DiffType,
+        hh: DiffType,
+        mm: DiffType,
+        ss: DiffType,
+    ) -> (Self, Normalization) {
+        Self::from_ymd_hms_with_report(y, m, d, hh, mm, ss)
+    }
 }
 
 impl fmt::Debug for CivilSecond {
@@ -569,6 +944,30 @@ impl CivilMinute {
     pub const fn new(y: YearType, m: DiffType, d: DiffType, hh: DiffType, mm: DiffType) -> Self {
         Self::from_ymd_hms(y, m, d, hh, mm, 0)
     }
+
+    /// Construct a [CivilMinute] instance like [`new()`](CivilMinute::new),
+    /// returns an error if any field is outside its natural range.
+    pub const fn try_new(
+        y: YearType,
+        m: DiffType,
+        d: DiffType,
+        hh: DiffType,
+        mm: DiffType,
+    ) -> Result<Self, FieldError> {
+        Self::try_from_ymd_hms(y, m, d, hh, mm, 0)
+    }
+
+    /// Construct a [CivilMinute] instance like [`new()`](CivilMinute::new),
+    /// together with a [Normalization] record.
+    pub const fn new_with_report(
+        y: YearType,
+        m: DiffType,
+        d: DiffType,
+        hh: DiffType,
+        mm: DiffType,
+    ) -> (Self, Normalization) {
+        Self::from_ymd_hms_with_report(y, m, d, hh, mm, 0)
+    }
 }
 
 impl fmt::Debug for CivilMinute {
@@ -595,6 +994,28 @@ impl CivilHour {
     pub const fn new(y: YearType, m: DiffType, d: DiffType, hh: DiffType) -> Self {
         Self::from_ymd_hms(y, m, d, hh, 0, 0)
     }
+
+    /// Construct a [CivilHour] instance like [`new()`](CivilHour::new),
+    /// returns an error if any field is outside its natural range.
+    pub const fn try_new(
+        y: YearType,
+        m: DiffType,
+        d: DiffType,
+        hh: DiffType,
+    ) -> Result<Self, FieldError> {
+        Self::try_from_ymd_hms(y, m, d, hh, 0, 0)
+    }
+
+    /// Construct a [CivilHour] instance like [`new()`](CivilHour::new),
+    /// together with a [Normalization] record.
+    pub const fn new_with_report(
+        y: YearType,
+        m: DiffType,
+        d: DiffType,
+        hh: DiffType,
+    ) -> (Self, Normalization) {
+        Self::from_ymd_hms_with_report(y, m, d, hh, 0, 0)
+    }
 }
 
 impl fmt::Debug for CivilHour {
@@ -619,6 +1040,69 @@ impl CivilDay {
     pub const fn new(y: YearType, m: DiffType, d: DiffType) -> Self {
         Self::from_ymd_hms(y, m, d, 0, 0, 0)
     }
+
+    /// Construct a [CivilDay] instance like [`new()`](CivilDay::new),
+    /// returns an error if any field is outside its natural range, e.g. the
+    /// 31st of a 30-day month.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilDay, Field};
+    ///
+    /// assert_eq!(Ok(CivilDay::new(2016, 2, 29)), CivilDay::try_new(2016, 2, 29));
+    /// let e = CivilDay::try_new(2015, 2, 29).unwrap_err();
+    /// assert_eq!((Field::Day, 29, 1..=28), (e.field(), e.value(), e.range()));
+    /// ```
+    pub const fn try_new(y: YearType, m: DiffType, d: DiffType) -> Result<Self, FieldError> {
+        Self::try_from_ymd_hms(y, m, d, 0, 0, 0)
+    }
+
+    /// Construct a [CivilDay] instance like [`new()`](CivilDay::new),
+    /// together with a [Normalization] record.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilDay, Field};
+    ///
+    /// let (d, report) = CivilDay::new_with_report(2015, 1, 32);
+    /// assert_eq!(CivilDay::new(2015, 2, 1), d);
+    /// assert_eq!("day 32 carried 1 into month", report.to_string());
+    /// ```
+    pub const fn new_with_report(y: YearType, m: DiffType, d: DiffType) -> (Self, Normalization) {
+        Self::from_ymd_hms_with_report(y, m, d, 0, 0, 0)
+    }
+
+    /// Construct a [CivilDay] instance by given year `y` and day of year `yd`,
+    /// i.e. the ordinal date. Out-of-range days are normalized like
+    /// [`new()`](CivilDay::new), so day 366 of a common year is January 1st
+    /// of the next year.
+    ///
+    /// ```rust
+    /// use civil_time::CivilDay;
+    ///
+    /// assert_eq!(CivilDay::new(2015, 2, 3), CivilDay::from_ordinal(2015, 34));
+    /// assert_eq!(CivilDay::new(2016, 1, 1), CivilDay::from_ordinal(2015, 366));
+    /// ```
+    pub const fn from_ordinal(y: YearType, yd: DiffType) -> Self {
+        Self::new(y, 1, yd)
+    }
+
+    /// Construct a [CivilDay] instance by given year `y` and day of year `yd`,
+    /// returns an error of [Field::Day] if `yd` is not a day of the year.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilDay, Field};
+    ///
+    /// assert_eq!(Ok(CivilDay::new(2016, 12, 31)), CivilDay::from_ordinal_strict(2016, 366));
+    /// let e = CivilDay::from_ordinal_strict(2015, 366).unwrap_err();
+    /// assert_eq!((Field::Day, 366, 1..=365), (e.field(), e.value(), e.range()));
+    /// assert!(CivilDay::from_ordinal_strict(2015, 0).is_err());
+    /// ```
+    pub const fn from_ordinal_strict(y: YearType, yd: DiffType) -> Result<Self, FieldError> {
+        let days = if core::is_leap_year(y) { 366 } else { 365 };
+        if yd < 1 || yd > days {
+            return Err(FieldError::new(Field::Day, yd, 1, days));
+        }
+        Ok(Self::from_ordinal(y, yd))
+    }
 }
 
 impl fmt::Debug for CivilDay {
@@ -636,6 +1120,18 @@ impl CivilMonth {
     pub const fn new(y: YearType, m: DiffType) -> Self {
         Self::from_ymd_hms(y, m, 1, 0, 0, 0)
     }
+
+    /// Construct a [CivilMonth] instance like [`new()`](CivilMonth::new),
+    /// returns an error if the month is outside its natural range.
+    pub const fn try_new(y: YearType, m: DiffType) -> Result<Self, FieldError> {
+        Self::try_from_ymd_hms(y, m, 1, 0, 0, 0)
+    }
+
+    /// Construct a [CivilMonth] instance like [`new()`](CivilMonth::new),
+    /// together with a [Normalization] record.
+    pub const fn new_with_report(y: YearType, m: DiffType) -> (Self, Normalization) {
+        Self::from_ymd_hms_with_report(y, m, 1, 0, 0, 0)
+    }
 }
 
 impl fmt::Debug for CivilMonth {
@@ -653,6 +1149,21 @@ impl CivilYear {
     pub const fn new(y: YearType) -> Self {
         Self::from_ymd_hms(y, 1, 1, 0, 0, 0)
     }
+
+    /// Construct a [CivilYear] instance like [`new()`](CivilYear::new).
+    ///
+    /// Every year is representable, so this never fails, and is provided
+    /// for symmetry with the other civil time types.
+    pub const fn try_new(y: YearType) -> Result<Self, FieldError> {
+        Self::try_from_ymd_hms(y, 1, 1, 0, 0, 0)
+    }
+
+    /// Construct a [CivilYear] instance like [`new()`](CivilYear::new),
+    /// together with a [Normalization] record, which never records any
+    /// normalization.
+    pub const fn new_with_report(y: YearType) -> (Self, Normalization) {
+        Self::from_ymd_hms_with_report(y, 1, 1, 0, 0, 0)
+    }
 }
 
 impl fmt::Debug for CivilYear {
@@ -749,6 +1260,26 @@ impl Builder {
     pub fn build<T: BuildCivilTime>(self) -> T {
         T::build_from_ymd_hms(self.y, self.m, self.d, self.hh, self.mm, self.ss)
     }
+
+    /// Build a new civil time instance, returns an error instead of
+    /// normalizing if any field is outside its natural range.
+    ///
+    /// All the fields are checked, even those finer than the alignment of
+    /// `T`.
+    ///
+    /// ```rust
+    /// use civil_time::{Builder, CivilDay, Field};
+    ///
+    /// let d: CivilDay = Builder::new().year(2015).month(2).day(3).try_build().unwrap();
+    /// assert_eq!(CivilDay::new(2015, 2, 3), d);
+    ///
+    /// let e = Builder::new().month(13).try_build::<CivilDay>().unwrap_err();
+    /// assert_eq!(Field::Month, e.field());
+    /// ```
+    pub fn try_build<T: BuildCivilTime>(self) -> Result<T, FieldError> {
+        Fields::check(self.y, self.m, self.d, self.hh, self.mm, self.ss)?;
+        Ok(self.build())
+    }
 }
 
 impl Default for Builder {
@@ -1064,8 +1595,138 @@ pub mod tests {
         sa::const_assert_eq!(28, _YD);
     }
 
+    #[test]
+    fn test_from_ordinal() {
+        const _CD: CivilDay = CivilDay::from_ordinal(2016, 60);
+        sa::const_assert_eq!(2, _CD.month());
+        sa::const_assert_eq!(29, _CD.day());
+        sa::const_assert_eq!(60, _CD.yearday());
+        const _STRICT: Result<CivilDay, FieldError> = CivilDay::from_ordinal_strict(2016, 366);
+        sa::const_assert!(_STRICT.is_ok());
+        const _ERR: Result<CivilDay, FieldError> = CivilDay::from_ordinal_strict(2015, 366);
+        sa::const_assert!(_ERR.is_err());
+    }
+
+    #[test]
+    fn test_try_new() {
+        const _CS: Result<CivilSecond, FieldError> = CivilSecond::try_new(2016, 2, 29, 23, 59, 59);
+        sa::const_assert!(_CS.is_ok());
+        const _ERR: Result<CivilSecond, FieldError> = CivilSecond::try_new(2015, 2, 29, 0, 0, 0);
+        sa::const_assert!(_ERR.is_err());
+    }
+
     // The remaining tests do not use constexpr.
 
+    #[test]
+    fn test_try_new_errors() {
+        let check = |e: FieldError, field, value, range| {
+            assert_eq!((field, value, range), (e.field(), e.value(), e.range()));
+        };
+
+        check(
+            CivilSecond::try_new(2015, 0, 1, 0, 0, 0).unwrap_err(),
+            Field::Month,
+            0,
+            1..=12,
+        );
+        check(
+            CivilSecond::try_new(2015, 4, 31, 0, 0, 0).unwrap_err(),
+            Field::Day,
+            31,
+            1..=30,
+        );
+        check(
+            CivilSecond::try_new(2015, 4, 0, 0, 0, 0).unwrap_err(),
+            Field::Day,
+            0,
+            1..=30,
+        );
+        check(
+            CivilSecond::try_new(2015, 4, 3, 24, 0, 0).unwrap_err(),
+            Field::Hour,
+            24,
+            0..=23,
+        );
+        check(
+            CivilDay::from_ordinal_strict(2016, 367).unwrap_err(),
+            Field::Day,
+            367,
+            1..=366,
+        );
+        check(
+            CivilDay::from_ordinal_strict(YearType::MAX, 366).unwrap_err(),
+            Field::Day,
+            366,
+            1..=365,
+        );
+        check(
+            CivilSecond::try_new(2015, 4, 3, 0, -1, 0).unwrap_err(),
+            Field::Minute,
+            -1,
+            0..=59,
+        );
+        check(
+            CivilSecond::try_new(2015, 4, 3, 0, 0, 60).unwrap_err(),
+            Field::Second,
+            60,
+            0..=59,
+        );
+        check(
+            CivilMinute::try_new(2015, 4, 3, 0, 60).unwrap_err(),
+            Field::Minute,
+            60,
+            0..=59,
+        );
+        check(
+            CivilHour::try_new(2100, 2, 29, 0).unwrap_err(),
+            Field::Day,
+            29,
+            1..=28,
+        );
+        check(
+            CivilDay::try_new(2015, DiffType::MAX, 1).unwrap_err(),
+            Field::Month,
+            DiffType::MAX,
+            1..=12,
+        );
+        check(
+            CivilMonth::try_new(2015, 13).unwrap_err(),
+            Field::Month,
+            13,
+            1..=12,
+        );
+    }
+
+    #[test]
+    fn test_try_new_limits() {
+        assert_eq!(
+            Ok(CivilSecond::MAX),
+            CivilSecond::try_new(YearType::MAX, 12, 31, 23, 59, 59)
+        );
+        assert_eq!(
+            Ok(CivilSecond::MIN),
+            CivilSecond::try_new(YearType::MIN, 1, 1, 0, 0, 0)
+        );
+        assert_eq!(Ok(CivilYear::MAX), CivilYear::try_new(YearType::MAX));
+        assert_eq!(
+            Ok(CivilDay::new(2000, 2, 29)),
+            CivilDay::try_new(2000, 2, 29)
+        );
+    }
+
+    #[test]
+    fn test_builder_try_build() {
+        let b = Builder::new().year(2015).month(2).day(3).hour(4);
+        assert_eq!(Ok(CivilHour::new(2015, 2, 3, 4)), b.try_build());
+        assert_eq!(Ok(CivilDay::new(2015, 2, 3)), b.try_build());
+
+        // Fields finer than the alignment are checked as well.
+        let e = b.minute(60).try_build::<CivilDay>().unwrap_err();
+        assert_eq!(Field::Minute, e.field());
+        let e = b.day(29).try_build::<CivilSecond>().unwrap_err();
+        assert_eq!((Field::Day, 29, 1..=28), (e.field(), e.value(), e.range()));
+    }
+
     #[test]
     fn test_default() {
         let ss = CivilSecond::default();
@@ -1531,6 +2192,109 @@ pub mod tests {
         expect_eq("0", year);
     }
 
+    #[test]
+    fn test_checked_arithmetic_const() {
+        const _MAX: Option<CivilSecond> = CivilSecond::MAX.checked_add(1);
+        sa::const_assert!(_MAX.is_none());
+        const _MIN: CivilSecond = CivilSecond::MIN.saturating_sub(1);
+        sa::const_assert_eq!(DiffType::MIN, _MIN.year());
+        const _WRAP: (CivilDay, bool) = CivilDay::MIN.overflowing_sub(1);
+        sa::const_assert!(_WRAP.1);
+        sa::const_assert_eq!(DiffType::MAX, _WRAP.0.year());
+        const _DIFF: Option<DiffType> = CivilYear::MAX.checked_difference(CivilYear::MIN);
+        sa::const_assert!(_DIFF.is_none());
+    }
+
+    macro_rules! check_arithmetic_limits {
+        ($Type: ident) => {
+            let (max, min) = ($Type::MAX, $Type::MIN);
+
+            assert_eq!(Some(max), (max - 1).checked_add(1));
+            assert_eq!(None, max.checked_add(1));
+            assert_eq!(None, max.checked_add(DiffType::MAX));
+            assert_eq!(None, max.checked_sub(-1));
+            assert_eq!(Some(min), (min + 1).checked_sub(1));
+            assert_eq!(None, min.checked_sub(1));
+            assert_eq!(None, max.checked_sub(DiffType::MIN));
+            assert_eq!(None, min.checked_add(-1));
+            assert_eq!(None, min.checked_add(DiffType::MIN));
+            assert_eq!(Some(max), max.checked_add(0));
+            assert_eq!(Some(min), min.checked_sub(0));
+
+            assert_eq!(max, max.saturating_add(1));
+            assert_eq!(max, (max - 1).saturating_add(DiffType::MAX));
+            assert_eq!(max, max.saturating_sub(DiffType::MIN));
+            assert_eq!(min, min.saturating_sub(1));
+            assert_eq!(min, (min + 1).saturating_add(DiffType::MIN));
+            assert_eq!(max - 1, max.saturating_sub(1));
+            assert_eq!(min + 1, min.saturating_add(1));
+
+            assert_eq!((max, false), (max - 1).overflowing_add(1));
+            assert_eq!((min, true), max.overflowing_add(1));
+            assert_eq!((max, true), min.overflowing_sub(1));
+            assert_eq!((max, true), min.overflowing_add(-1));
+            assert_eq!((min + 1, false), (min + 1).overflowing_sub(0));
+
+            assert_eq!(Some(1), max.checked_difference(max - 1));
+            assert_eq!(Some(-1), min.checked_difference(min + 1));
+            assert_eq!(Some(0), max.checked_difference(max));
+        };
+    }
+
+    #[test]
+    fn test_checked_arithmetic_limits() {
+        check_arithmetic_limits!(CivilSecond);
+        check_arithmetic_limits!(CivilMinute);
+        check_arithmetic_limits!(CivilHour);
+        check_arithmetic_limits!(CivilDay);
+        check_arithmetic_limits!(CivilMonth);
+        check_arithmetic_limits!(CivilYear);
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let second = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+        assert_eq!(Some(second + 100), second.checked_add(100));
+        assert_eq!(Some(second - 100), second.checked_sub(100));
+        assert_eq!(Some(second - 100), second.checked_add(-100));
+        assert_eq!((second + 100, false), second.overflowing_add(100));
+        assert_eq!(second + 100, second.saturating_add(100));
+
+        // Large steps within range are not overflows.
+        let day = CivilDay::new(1970, 1, 1);
+        assert_eq!(Some(day + DiffType::MAX), day.checked_add(DiffType::MAX));
+        assert_eq!(Some(day - DiffType::MIN), day.checked_sub(DiffType::MIN));
+        assert_eq!(
+            Some(CivilSecond::MIN + DiffType::MAX + 1),
+            CivilSecond::MIN.checked_sub(DiffType::MIN)
+        );
+        let year = CivilYear::new(0);
+        assert_eq!(Some(CivilYear::MAX), year.checked_add(DiffType::MAX));
+        assert_eq!(None, year.checked_sub(DiffType::MIN));
+        assert_eq!(
+            Some(CivilYear::new(-1)),
+            CivilYear::MAX.checked_add(DiffType::MIN)
+        );
+
+        // The year wraps around on overflow like the primitive integers.
+        let (wrapped, overflow) = CivilMonth::new(YearType::MAX, 12).overflowing_add(14);
+        assert!(overflow);
+        expect_eq(&format!("{}-02", YearType::MIN + 1), wrapped);
+
+        assert_eq!(
+            None,
+            CivilSecond::MAX.checked_difference(CivilSecond::new(0, 1, 1, 0, 0, 0))
+        );
+        assert_eq!(
+            Some(i64::from(i32::MAX)),
+            CivilDay::new(5881580, 7, 11).checked_difference(CivilDay::new(1970, 1, 1))
+        );
+        assert_eq!(
+            Some(DiffType::MIN),
+            CivilYear::new(-1).checked_difference(CivilYear::MAX)
+        );
+    }
+
     #[test]
     fn test_arithmetic_difference() {
         let second = CivilSecond::new(2015, 1, 2, 3, 4, 5);
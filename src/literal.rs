@@ -0,0 +1,233 @@
+//! Compile-time civil time literals.
+
+use crate::core::Field;
+use crate::parse::{parse_debug, ParseError, ParseErrorKind};
+use crate::{CivilDay, CivilHour, CivilMinute, CivilMonth, CivilSecond, CivilYear};
+
+/// Returns the message to panic with on an invalid literal.
+const fn error_message(e: ParseError) -> &'static str {
+    match (e.kind(), e.field()) {
+        (ParseErrorKind::TooShort, _) => "premature end of civil time literal",
+        (ParseErrorKind::TooLong, _) => "trailing input in civil time literal",
+        (ParseErrorKind::OutOfRange, Some(Field::Year)) => {
+            "year out of range in civil time literal"
+        }
+        (ParseErrorKind::OutOfRange, Some(Field::Month)) => {
+            "month out of range in civil time literal"
+        }
+        (ParseErrorKind::OutOfRange, Some(Field::Day)) => "day out of range in civil time literal",
+        (ParseErrorKind::OutOfRange, Some(Field::Hour)) => {
+            "hour out of range in civil time literal"
+        }
+        (ParseErrorKind::OutOfRange, Some(Field::Minute)) => {
+            "minute out of range in civil time literal"
+        }
+        (ParseErrorKind::OutOfRange, Some(Field::Second)) => {
+            "second out of range in civil time literal"
+        }
+        (ParseErrorKind::Invalid, Some(Field::Year)) => "invalid year in civil time literal",
+        (ParseErrorKind::Invalid, Some(Field::Month)) => "invalid month in civil time literal",
+        (ParseErrorKind::Invalid, Some(Field::Day)) => "invalid day in civil time literal",
+        (ParseErrorKind::Invalid, Some(Field::Hour)) => "invalid hour in civil time literal",
+        (ParseErrorKind::Invalid, Some(Field::Minute)) => "invalid minute in civil time literal",
+        (ParseErrorKind::Invalid, Some(Field::Second)) => "invalid second in civil time literal",
+        _ => "invalid civil time literal",
+    }
+}
+
+macro_rules! impl_literal {
+    ($Type: ident, $n: expr) => {
+        impl $Type {
+            /// Parses the literal `s` in the layout printed by the `Debug`
+            /// impl, panics if it is invalid.
+            #[doc(hidden)]
+            pub const fn __from_literal(s: &str) -> Self {
+                match parse_debug(s.as_bytes(), $n) {
+                    Ok(fields) => Self::from_fields(fields),
+                    Err(e) => panic!("{}", error_message(e)),
+                }
+            }
+        }
+    };
+}
+
+impl_literal!(CivilSecond, 6);
+impl_literal!(CivilMinute, 5);
+impl_literal!(CivilHour, 4);
+impl_literal!(CivilDay, 3);
+impl_literal!(CivilMonth, 2);
+impl_literal!(CivilYear, 1);
+
+/// Creates a constant [CivilSecond](crate::CivilSecond) from a literal in the
+/// layout printed by the `Debug` impl. Malformed or out-of-range literals are
+/// compile errors, instead of being normalized.
+///
+/// ```rust
+/// use civil_time::{civil_second, CivilSecond};
+///
+/// const SS: CivilSecond = civil_second!("2015-02-03T04:05:06");
+/// assert_eq!(CivilSecond::new(2015, 2, 3, 4, 5, 6), SS);
+/// ```
+///
+/// ```compile_fail
+/// use civil_time::{civil_second, CivilSecond};
+///
+/// // Fails to compile: hour out of range.
+/// const SS: CivilSecond = civil_second!("2015-02-03T24:05:06");
+/// ```
+#[macro_export]
+macro_rules! civil_second {
+    ($s: expr) => {{
+        const VALUE: $crate::CivilSecond = $crate::CivilSecond::__from_literal($s);
+        VALUE
+    }};
+}
+
+/// Creates a constant [CivilMinute](crate::CivilMinute) from a literal, e.g.
+/// `civil_minute!("2015-02-03T04:05")`. See [`civil_second!`](crate::civil_second).
+///
+/// ```rust
+/// use civil_time::{civil_minute, CivilMinute};
+///
+/// const MM: CivilMinute = civil_minute!("2015-02-03T04:05");
+/// assert_eq!(CivilMinute::new(2015, 2, 3, 4, 5), MM);
+/// ```
+#[macro_export]
+macro_rules! civil_minute {
+    ($s: expr) => {{
+        const VALUE: $crate::CivilMinute = $crate::CivilMinute::__from_literal($s);
+        VALUE
+    }};
+}
+
+/// Creates a constant [CivilHour](crate::CivilHour) from a literal, e.g.
+/// `civil_hour!("2015-02-03T04")`. See [`civil_second!`](crate::civil_second).
+///
+/// ```rust
+/// use civil_time::{civil_hour, CivilHour};
+///
+/// const HH: CivilHour = civil_hour!("2015-02-03T04");
+/// assert_eq!(CivilHour::new(2015, 2, 3, 4), HH);
+/// ```
+#[macro_export]
+macro_rules! civil_hour {
+    ($s: expr) => {{
+        const VALUE: $crate::CivilHour = $crate::CivilHour::__from_literal($s);
+        VALUE
+    }};
+}
+
+/// Creates a constant [CivilDay](crate::CivilDay) from a literal, e.g.
+/// `civil_day!("2015-02-03")`. See [`civil_second!`](crate::civil_second).
+///
+/// ```rust
+/// use civil_time::{civil_day, CivilDay};
+///
+/// const D: CivilDay = civil_day!("2015-02-03");
+/// assert_eq!(CivilDay::new(2015, 2, 3), D);
+/// ```
+///
+/// ```compile_fail
+/// use civil_time::{civil_day, CivilDay};
+///
+/// // Fails to compile: month out of range.
+/// const D: CivilDay = civil_day!("2015-13-03");
+/// ```
+///
+/// ```compile_fail
+/// use civil_time::{civil_day, CivilDay};
+///
+/// // Fails to compile: 2015 is not a leap year.
+/// const D: CivilDay = civil_day!("2015-02-29");
+/// ```
+#[macro_export]
+macro_rules! civil_day {
+    ($s: expr) => {{
+        const VALUE: $crate::CivilDay = $crate::CivilDay::__from_literal($s);
+        VALUE
+    }};
+}
+
+/// Creates a constant [CivilMonth](crate::CivilMonth) from a literal, e.g.
+/// `civil_month!("2015-02")`. See [`civil_second!`](crate::civil_second).
+///
+/// ```rust
+/// use civil_time::{civil_month, CivilMonth};
+///
+/// const M: CivilMonth = civil_month!("2015-02");
+/// assert_eq!(CivilMonth::new(2015, 2), M);
+/// ```
+#[macro_export]
+macro_rules! civil_month {
+    ($s: expr) => {{
+        const VALUE: $crate::CivilMonth = $crate::CivilMonth::__from_literal($s);
+        VALUE
+    }};
+}
+
+/// Creates a constant [CivilYear](crate::CivilYear) from a literal, e.g.
+/// `civil_year!("2015")`. See [`civil_second!`](crate::civil_second).
+///
+/// ```rust
+/// use civil_time::{civil_year, CivilYear};
+///
+/// const Y: CivilYear = civil_year!("-2015");
+/// assert_eq!(CivilYear::new(-2015), Y);
+/// ```
+#[macro_export]
+macro_rules! civil_year {
+    ($s: expr) => {{
+        const VALUE: $crate::CivilYear = $crate::CivilYear::__from_literal($s);
+        VALUE
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::expect_eq;
+    use crate::YearType;
+    use static_assertions as sa;
+
+    #[test]
+    fn test_literals() {
+        const _SS: CivilSecond = civil_second!("2016-02-29T23:59:59");
+        sa::const_assert_eq!(29, _SS.day());
+        sa::const_assert_eq!(59, _SS.second());
+        const _Y: CivilYear = civil_year!("-9223372036854775808");
+        sa::const_assert_eq!(YearType::MIN, _Y.year());
+
+        expect_eq("2015-02-03T04:05:06", civil_second!("2015-02-03T04:05:06"));
+        expect_eq("2015-02-03T04:05", civil_minute!("2015-02-03T04:05"));
+        expect_eq("2015-02-03T04", civil_hour!("2015-02-03T04"));
+        expect_eq("2015-02-03", civil_day!("2015-02-03"));
+        expect_eq("2015-02", civil_month!("2015-02"));
+        expect_eq("2015", civil_year!("2015"));
+    }
+
+    #[test]
+    fn test_error_message() {
+        let message = |s: &str| error_message(s.parse::<CivilDay>().unwrap_err());
+        assert_eq!(
+            "month out of range in civil time literal",
+            message("2015-13-03")
+        );
+        assert_eq!(
+            "day out of range in civil time literal",
+            message("2015-02-29")
+        );
+        assert_eq!("invalid year in civil time literal", message("x-02-03"));
+        assert_eq!("invalid day in civil time literal", message("2015-02-3x"));
+        assert_eq!("premature end of civil time literal", message("2015-02"));
+        assert_eq!(
+            "trailing input in civil time literal",
+            message("2015-02-03T04")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "day out of range in civil time literal")]
+    fn test_from_literal_panic() {
+        CivilDay::__from_literal("2015-02-29");
+    }
+}
@@ -0,0 +1,1199 @@
+//! Localized names and CLDR-style patterns of civil time types.
+
+use crate::core::{DiffType, Field, Fields};
+use crate::format::{write_spec, DelayedFormat, Spec};
+use crate::parse::{set, Expected, ParseError, ParseMode, Parsed, Scanner, TwoDigitYear};
+use crate::weekday::WEEKDAYS;
+use crate::{CivilDay, CivilHour, CivilMinute, CivilMonth, CivilSecond, CivilYear, Weekday};
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+
+/// A locale with bundled names of months and weekdays and date patterns.
+///
+/// ```rust
+/// use civil_time::{Locale, NameContext, NameWidth, Weekday};
+///
+/// let de = Locale::from_tag("de-DE").unwrap();
+/// assert_eq!(Locale::De, de);
+/// assert_eq!("März", de.month_name(3, NameWidth::Wide, NameContext::Format));
+/// assert_eq!("Mär", de.month_name(3, NameWidth::Abbreviated, NameContext::StandAlone));
+/// assert_eq!("Di.", de.weekday_name(Weekday::Tue, NameWidth::Abbreviated, NameContext::Format));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// English.
+    En,
+    /// German.
+    De,
+    /// French.
+    Fr,
+    /// Spanish.
+    Es,
+    /// Chinese (Simplified).
+    Zh,
+    /// Japanese.
+    Ja,
+}
+
+/// Width of month and weekday names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NameWidth {
+    /// Full names, e.g. "February" and "Tuesday".
+    Wide,
+    /// Abbreviated names, e.g. "Feb" and "Tue".
+    Abbreviated,
+    /// Narrow names, e.g. "F" and "T", which are not unique in most locales.
+    Narrow,
+}
+
+/// Grammatical context of month and weekday names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NameContext {
+    /// Names used within a date, e.g. "3. Feb. 2015" in German.
+    Format,
+    /// Names used on their own, e.g. in a calendar header.
+    StandAlone,
+}
+
+/// Names indexed by [NameWidth].
+type Names<const N: usize> = [[&'static str; N]; 3];
+
+/// Bundled data of a locale.
+struct LocaleData {
+    months: Names<12>,
+    standalone_months: Names<12>,
+    /// Weekdays starting from Monday.
+    weekdays: Names<7>,
+    standalone_weekdays: Names<7>,
+    /// Patterns of the supported skeletons.
+    skeletons: &'static [(&'static str, &'static str)],
+    /// Separator between the date and the time of a skeleton with both.
+    date_time: &'static str,
+}
+
+impl LocaleData {
+    fn months(&self, width: NameWidth, context: NameContext) -> &[&'static str; 12] {
+        match context {
+            NameContext::Format => &self.months[width as usize],
+            NameContext::StandAlone => &self.standalone_months[width as usize],
+        }
+    }
+
+    fn weekdays(&self, width: NameWidth, context: NameContext) -> &[&'static str; 7] {
+        match context {
+            NameContext::Format => &self.weekdays[width as usize],
+            NameContext::StandAlone => &self.standalone_weekdays[width as usize],
+        }
+    }
+}
+
+const EN_MONTHS: Names<12> = [
+    [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ],
+    ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+];
+
+const EN_WEEKDAYS: Names<7> = [
+    [
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+        "Sunday",
+    ],
+    ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+    ["M", "T", "W", "T", "F", "S", "S"],
+];
+
+const EN: LocaleData = LocaleData {
+    months: EN_MONTHS,
+    standalone_months: EN_MONTHS,
+    weekdays: EN_WEEKDAYS,
+    standalone_weekdays: EN_WEEKDAYS,
+    skeletons: &[
+        ("y", "y"),
+        ("yM", "M/y"),
+        ("yMd", "M/d/y"),
+        ("yMMM", "MMM y"),
+        ("yMMMd", "MMM d, y"),
+        ("yMMMEd", "EEE, MMM d, y"),
+        ("yMMMM", "MMMM y"),
+        ("yMMMMd", "MMMM d, y"),
+        ("yMMMMEEEEd", "EEEE, MMMM d, y"),
+        ("Md", "M/d"),
+        ("MMMd", "MMM d"),
+        ("MMMMd", "MMMM d"),
+        ("H", "HH"),
+        ("Hm", "HH:mm"),
+        ("Hms", "HH:mm:ss"),
+    ],
+    date_time: ", ",
+};
+
+const DE_MONTHS: Names<12> = [
+    [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ],
+    [
+        "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+        "Dez.",
+    ],
+    ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+];
+
+const DE_STANDALONE_MONTHS: Names<12> = [
+    DE_MONTHS[0],
+    [
+        "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+    ],
+    DE_MONTHS[2],
+];
+
+const DE_WEEKDAYS: Names<7> = [
+    [
+        "Montag",
+        "Dienstag",
+        "Mittwoch",
+        "Donnerstag",
+        "Freitag",
+        "Samstag",
+        "Sonntag",
+    ],
+    ["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."],
+    ["M", "D", "M", "D", "F", "S", "S"],
+];
+
+const DE_STANDALONE_WEEKDAYS: Names<7> = [
+    DE_WEEKDAYS[0],
+    ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+    DE_WEEKDAYS[2],
+];
+
+const DE: LocaleData = LocaleData {
+    months: DE_MONTHS,
+    standalone_months: DE_STANDALONE_MONTHS,
+    weekdays: DE_WEEKDAYS,
+    standalone_weekdays: DE_STANDALONE_WEEKDAYS,
+    skeletons: &[
+        ("y", "y"),
+        ("yM", "M/y"),
+        ("yMd", "d.M.y"),
+        ("yMMM", "MMM y"),
+        ("yMMMd", "d. MMM y"),
+        ("yMMMEd", "E, d. MMM y"),
+        ("yMMMM", "MMMM y"),
+        ("yMMMMd", "d. MMMM y"),
+        ("yMMMMEEEEd", "EEEE, d. MMMM y"),
+        ("Md", "d.M."),
+        ("MMMd", "d. MMM"),
+        ("MMMMd", "d. MMMM"),
+        ("H", "HH 'Uhr'"),
+        ("Hm", "HH:mm"),
+        ("Hms", "HH:mm:ss"),
+    ],
+    date_time: ", ",
+};
+
+const FR_MONTHS: Names<12> = [
+    [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    [
+        "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+        "déc.",
+    ],
+    ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+];
+
+const FR_WEEKDAYS: Names<7> = [
+    [
+        "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+    ],
+    ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+    ["L", "M", "M", "J", "V", "S", "D"],
+];
+
+const FR: LocaleData = LocaleData {
+    months: FR_MONTHS,
+    standalone_months: FR_MONTHS,
+    weekdays: FR_WEEKDAYS,
+    standalone_weekdays: FR_WEEKDAYS,
+    skeletons: &[
+        ("y", "y"),
+        ("yM", "MM/y"),
+        ("yMd", "dd/MM/y"),
+        ("yMMM", "MMM y"),
+        ("yMMMd", "d MMM y"),
+        ("yMMMEd", "E d MMM y"),
+        ("yMMMM", "MMMM y"),
+        ("yMMMMd", "d MMMM y"),
+        ("yMMMMEEEEd", "EEEE d MMMM y"),
+        ("Md", "dd/MM"),
+        ("MMMd", "d MMM"),
+        ("MMMMd", "d MMMM"),
+        ("H", "HH 'h'"),
+        ("Hm", "HH:mm"),
+        ("Hms", "HH:mm:ss"),
+    ],
+    date_time: " ",
+};
+
+const ES_MONTHS: Names<12> = [
+    [
+        "enero",
+        "febrero",
+        "marzo",
+        "abril",
+        "mayo",
+        "junio",
+        "julio",
+        "agosto",
+        "septiembre",
+        "octubre",
+        "noviembre",
+        "diciembre",
+    ],
+    [
+        "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+    ],
+    ["E", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+];
+
+const ES_WEEKDAYS: Names<7> = [
+    [
+        "lunes",
+        "martes",
+        "miércoles",
+        "jueves",
+        "viernes",
+        "sábado",
+        "domingo",
+    ],
+    ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+    ["L", "M", "X", "J", "V", "S", "D"],
+];
+
+const ES: LocaleData = LocaleData {
+    months: ES_MONTHS,
+    standalone_months: ES_MONTHS,
+    weekdays: ES_WEEKDAYS,
+    standalone_weekdays: ES_WEEKDAYS,
+    skeletons: &[
+        ("y", "y"),
+        ("yM", "M/y"),
+        ("yMd", "d/M/y"),
+        ("yMMM", "MMM y"),
+        ("yMMMd", "d MMM y"),
+        ("yMMMEd", "EEE, d MMM y"),
+        ("yMMMM", "MMMM 'de' y"),
+        ("yMMMMd", "d 'de' MMMM 'de' y"),
+        ("yMMMMEEEEd", "EEEE, d 'de' MMMM 'de' y"),
+        ("Md", "d/M"),
+        ("MMMd", "d MMM"),
+        ("MMMMd", "d 'de' MMMM"),
+        ("H", "H"),
+        ("Hm", "H:mm"),
+        ("Hms", "H:mm:ss"),
+    ],
+    date_time: ", ",
+};
+
+const ZH_MONTHS: Names<12> = [
+    [
+        "一月",
+        "二月",
+        "三月",
+        "四月",
+        "五月",
+        "六月",
+        "七月",
+        "八月",
+        "九月",
+        "十月",
+        "十一月",
+        "十二月",
+    ],
+    [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    [
+        "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12",
+    ],
+];
+
+const ZH_WEEKDAYS: Names<7> = [
+    [
+        "星期一",
+        "星期二",
+        "星期三",
+        "星期四",
+        "星期五",
+        "星期六",
+        "星期日",
+    ],
+    ["周一", "周二", "周三", "周四", "周五", "周六", "周日"],
+    ["一", "二", "三", "四", "五", "六", "日"],
+];
+
+const ZH: LocaleData = LocaleData {
+    months: ZH_MONTHS,
+    standalone_months: ZH_MONTHS,
+    weekdays: ZH_WEEKDAYS,
+    standalone_weekdays: ZH_WEEKDAYS,
+    skeletons: &[
+        ("y", "y年"),
+        ("yM", "y/M"),
+        ("yMd", "y/M/d"),
+        ("yMMM", "y年M月"),
+        ("yMMMd", "y年M月d日"),
+        ("yMMMEd", "y年M月d日E"),
+        ("yMMMM", "y年M月"),
+        ("yMMMMd", "y年M月d日"),
+        ("yMMMMEEEEd", "y年M月d日EEEE"),
+        ("Md", "M/d"),
+        ("MMMd", "M月d日"),
+        ("MMMMd", "M月d日"),
+        ("H", "H时"),
+        ("Hm", "HH:mm"),
+        ("Hms", "HH:mm:ss"),
+    ],
+    date_time: " ",
+};
+
+const JA_MONTHS: Names<12> = [ZH_MONTHS[1], ZH_MONTHS[1], ZH_MONTHS[2]];
+
+const JA_WEEKDAYS: Names<7> = [
+    [
+        "月曜日",
+        "火曜日",
+        "水曜日",
+        "木曜日",
+        "金曜日",
+        "土曜日",
+        "日曜日",
+    ],
+    ["月", "火", "水", "木", "金", "土", "日"],
+    ["月", "火", "水", "木", "金", "土", "日"],
+];
+
+const JA: LocaleData = LocaleData {
+    months: JA_MONTHS,
+    standalone_months: JA_MONTHS,
+    weekdays: JA_WEEKDAYS,
+    standalone_weekdays: JA_WEEKDAYS,
+    skeletons: &[
+        ("y", "y年"),
+        ("yM", "y/M"),
+        ("yMd", "y/M/d"),
+        ("yMMM", "y年M月"),
+        ("yMMMd", "y年M月d日"),
+        ("yMMMEd", "y年M月d日(E)"),
+        ("yMMMM", "y年M月"),
+        ("yMMMMd", "y年M月d日"),
+        ("yMMMMEEEEd", "y年M月d日EEEE"),
+        ("Md", "M/d"),
+        ("MMMd", "M月d日"),
+        ("MMMMd", "M月d日"),
+        ("H", "H時"),
+        ("Hm", "H:mm"),
+        ("Hms", "H:mm:ss"),
+    ],
+    date_time: " ",
+};
+
+impl Locale {
+    /// Returns the locale of the BCP 47 language tag `tag`, e.g. "fr" and
+    /// "zh-Hans-CN". Only the language subtag is significant and it is matched
+    /// ignoring case, other subtags may also be separated by '_'.
+    ///
+    /// ```rust
+    /// use civil_time::Locale;
+    ///
+    /// assert_eq!(Some(Locale::Zh), Locale::from_tag("zh-Hans-CN"));
+    /// assert_eq!(Some(Locale::En), Locale::from_tag("EN_us"));
+    /// assert_eq!(None, Locale::from_tag("ru"));
+    /// ```
+    pub fn from_tag(tag: &str) -> Option<Locale> {
+        let language = tag.split(['-', '_']).next().unwrap_or("");
+        [
+            Locale::En,
+            Locale::De,
+            Locale::Fr,
+            Locale::Es,
+            Locale::Zh,
+            Locale::Ja,
+        ]
+        .iter()
+        .copied()
+        .find(|locale| locale.tag().eq_ignore_ascii_case(language))
+    }
+
+    /// Returns the language tag of the locale, e.g. "fr".
+    pub const fn tag(&self) -> &'static str {
+        match *self {
+            Locale::En => "en",
+            Locale::De => "de",
+            Locale::Fr => "fr",
+            Locale::Es => "es",
+            Locale::Zh => "zh",
+            Locale::Ja => "ja",
+        }
+    }
+
+    fn data(&self) -> &'static LocaleData {
+        match *self {
+            Locale::En => &EN,
+            Locale::De => &DE,
+            Locale::Fr => &FR,
+            Locale::Es => &ES,
+            Locale::Zh => &ZH,
+            Locale::Ja => &JA,
+        }
+    }
+
+    /// Returns the name of the `month` (from 1 to 12), out-of-range months
+    /// are normalized like the fields of [`CivilMonth::new()`].
+    pub fn month_name(&self, month: i32, width: NameWidth, context: NameContext) -> &'static str {
+        self.data().months(width, context)[(month - 1).rem_euclid(12) as usize]
+    }
+
+    /// Returns the name of the weekday `wd`.
+    pub fn weekday_name(
+        &self,
+        wd: Weekday,
+        width: NameWidth,
+        context: NameContext,
+    ) -> &'static str {
+        self.data().weekdays(width, context)[wd as usize]
+    }
+}
+
+/// A token of a localized pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    /// Literal text.
+    Literal(String),
+    /// A directive formatted as the strftime-like directive.
+    Spec(Spec),
+    /// A numeric directive formatted without padding.
+    Unpadded(Spec),
+    /// Name of the month.
+    Month(NameWidth, NameContext),
+    /// Name of the weekday.
+    Weekday(NameWidth, NameContext),
+}
+
+impl Token {
+    /// Returns the token of the pattern field of `count` letters `c`.
+    fn field(c: char, count: usize) -> Option<Token> {
+        let width = |count| match count {
+            1..=3 => Some(NameWidth::Abbreviated),
+            4 => Some(NameWidth::Wide),
+            5 => Some(NameWidth::Narrow),
+            _ => None,
+        };
+        let numeric = |spec, count| match count {
+            1 => Some(Token::Unpadded(spec)),
+            2 => Some(Token::Spec(spec)),
+            _ => None,
+        };
+        match (c, count) {
+            ('y', 1) => Some(Token::Spec(Spec::Year)),
+            ('y', 2) => Some(Token::Spec(Spec::YearOfCentury)),
+            ('y', 4) => Some(Token::Spec(Spec::YearPadded)),
+            ('M', 1..=2) => numeric(Spec::Month, count),
+            ('L', 1..=2) => numeric(Spec::Month, count),
+            ('M', 3..=5) => Some(Token::Month(width(count)?, NameContext::Format)),
+            ('L', 3..=5) => Some(Token::Month(width(count)?, NameContext::StandAlone)),
+            ('d', _) => numeric(Spec::Day, count),
+            ('D', 1) => Some(Token::Unpadded(Spec::YearDay)),
+            ('D', 3) => Some(Token::Spec(Spec::YearDay)),
+            ('E', _) => Some(Token::Weekday(width(count)?, NameContext::Format)),
+            ('c', 3..=5) => Some(Token::Weekday(width(count)?, NameContext::StandAlone)),
+            ('H', _) => numeric(Spec::Hour, count),
+            ('m', _) => numeric(Spec::Minute, count),
+            ('s', _) => numeric(Spec::Second, count),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of digits of a fixed-width numeric token.
+    fn width(&self) -> Option<usize> {
+        match self {
+            Token::Spec(spec) | Token::Unpadded(spec) => spec.width(),
+            _ => None,
+        }
+    }
+}
+
+/// An error in a CLDR-style pattern or skeleton of [LocalePattern].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocalePatternError {
+    /// The field at the byte `offset` is unknown, e.g. "Q" or "yyy".
+    UnknownField {
+        /// Byte offset of the first letter of the field.
+        offset: usize,
+        /// The letter of the field.
+        letter: char,
+    },
+    /// The quoted literal text that starts at the byte `offset` is not
+    /// closed.
+    UnterminatedLiteral {
+        /// Byte offset of the opening `'`.
+        offset: usize,
+    },
+    /// The skeleton, or its time part that starts at the byte `offset`, is
+    /// not supported by the locale.
+    UnsupportedSkeleton {
+        /// Byte offset of the unsupported part of the skeleton.
+        offset: usize,
+    },
+}
+
+impl fmt::Display for LocalePatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            LocalePatternError::UnknownField { offset, letter } => {
+                write!(f, "unknown field '{}' at offset {}", letter, offset)
+            }
+            LocalePatternError::UnterminatedLiteral { offset } => {
+                write!(f, "unterminated literal at offset {}", offset)
+            }
+            LocalePatternError::UnsupportedSkeleton { offset } => {
+                write!(f, "unsupported skeleton at offset {}", offset)
+            }
+        }
+    }
+}
+
+impl Error for LocalePatternError {}
+
+/// A CLDR-style date pattern of a [Locale], which formats and parses civil
+/// times with localized names, e.g. "EEEE, d. MMMM y".
+///
+/// A pattern consists of fields, which are runs of the same ASCII letter,
+/// and literal text. ASCII letters must be quoted by `'` to be literal, and
+/// `''` is a literal `'`. The following fields are supported:
+///
+/// | Field | Meaning | Example |
+/// |-------|---------|---------|
+/// | `y` | Year | `2015` |
+/// | `yy` | Year of century, from `00` to `99` | `15` |
+/// | `yyyy` | Year padded to at least 4 digits | `0015` |
+/// | `M`, `MM` | Month, not padded or padded to 2 digits | `2`, `02` |
+/// | `MMM`, `MMMM`, `MMMMM` | Abbreviated, wide and narrow month name | `Feb`, `February`, `F` |
+/// | `L` to `LLLLL` | Same as `M` to `MMMMM`, but stand-alone names | `Feb` |
+/// | `d`, `dd` | Day of month | `3`, `03` |
+/// | `D`, `DDD` | Day of year | `34`, `034` |
+/// | `E` to `EEE`, `EEEE`, `EEEEE` | Abbreviated, wide and narrow weekday name | `Tue`, `Tuesday`, `T` |
+/// | `ccc`, `cccc`, `ccccc` | Same as `EEE` to `EEEEE`, but stand-alone names | `Tue` |
+/// | `H`, `HH` | Hour, from `0` to `23` | `4`, `04` |
+/// | `m`, `mm` | Minute | `5`, `05` |
+/// | `s`, `ss` | Second | `6`, `06` |
+///
+/// Parsing matches names ignoring ASCII case and accepts unpadded numbers
+/// for padded fields, in the same way as
+/// [`parse_from_str()`](CivilSecond::parse_from_str). Narrow names shared by
+/// several months or weekdays cannot be parsed.
+///
+/// ```rust
+/// use civil_time::{CivilDay, Locale, LocalePattern};
+///
+/// let pattern = LocalePattern::compile(Locale::Fr, "EEEE d MMMM y").unwrap();
+/// let d = CivilDay::new(2015, 2, 3);
+/// assert_eq!("mardi 3 février 2015", d.format_locale(&pattern).to_string());
+/// assert_eq!(d, CivilDay::parse_locale("mardi 3 février 2015", &pattern).unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalePattern {
+    locale: Locale,
+    tokens: Vec<Token>,
+    mode: ParseMode,
+    two_digit_year: TwoDigitYear,
+}
+
+impl LocalePattern {
+    /// Compiles the CLDR-style `pattern` of the `locale`.
+    ///
+    /// ```rust
+    /// use civil_time::{Locale, LocalePattern, LocalePatternError};
+    ///
+    /// assert!(LocalePattern::compile(Locale::En, "MMM d, y 'at' HH:mm").is_ok());
+    /// assert_eq!(
+    ///     LocalePatternError::UnknownField { offset: 2, letter: 'Q' },
+    ///     LocalePattern::compile(Locale::En, "y QQQ").unwrap_err()
+    /// );
+    /// ```
+    pub fn compile(locale: Locale, pattern: &str) -> Result<Self, LocalePatternError> {
+        let mut tokens = Vec::new();
+        let mut literal = String::new();
+        let mut chars = pattern.char_indices().peekable();
+        while let Some((offset, c)) = chars.next() {
+            if c == '\'' {
+                if let Some((_, '\'')) = chars.peek() {
+                    chars.next();
+                    literal.push('\'');
+                    continue;
+                }
+                loop {
+                    match chars.next() {
+                        Some((_, '\'')) => match chars.peek() {
+                            Some((_, '\'')) => {
+                                chars.next();
+                                literal.push('\'');
+                            }
+                            _ => break,
+                        },
+                        Some((_, c)) => literal.push(c),
+                        None => return Err(LocalePatternError::UnterminatedLiteral { offset }),
+                    }
+                }
+            } else if c.is_ascii_alphabetic() {
+                let mut count = 1;
+                while let Some(&(_, next)) = chars.peek() {
+                    if next != c {
+                        break;
+                    }
+                    chars.next();
+                    count += 1;
+                }
+                let token = Token::field(c, count)
+                    .ok_or(LocalePatternError::UnknownField { offset, letter: c })?;
+                if !literal.is_empty() {
+                    tokens.push(Token::Literal(std::mem::take(&mut literal)));
+                }
+                tokens.push(token);
+            } else {
+                literal.push(c);
+            }
+        }
+        if !literal.is_empty() {
+            tokens.push(Token::Literal(literal));
+        }
+
+        Ok(LocalePattern {
+            locale,
+            tokens,
+            mode: ParseMode::Strict,
+            two_digit_year: TwoDigitYear::default(),
+        })
+    }
+
+    /// Returns the pattern of the `locale` for the CLDR-style `skeleton`,
+    /// which lists the fields to show regardless of their order and
+    /// separators, e.g. "yMMMd" is "MMM d, y" in English and "y年M月d日" in
+    /// Chinese.
+    ///
+    /// The supported date skeletons are "y", "yM", "yMd", "yMMM", "yMMMd",
+    /// "yMMMEd", "yMMMM", "yMMMMd", "yMMMMEEEEd", "Md", "MMMd" and "MMMMd",
+    /// and the supported time skeletons are "H", "Hm" and "Hms". A date
+    /// skeleton can be followed by a time skeleton, e.g. "yMMMdHm".
+    ///
+    /// ```rust
+    /// use civil_time::{CivilSecond, Locale, LocalePattern};
+    ///
+    /// let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+    /// let en = LocalePattern::from_skeleton(Locale::En, "yMMMdHm").unwrap();
+    /// assert_eq!("Feb 3, 2015, 04:05", ss.format_locale(&en).to_string());
+    /// let ja = LocalePattern::from_skeleton(Locale::Ja, "yMMMEd").unwrap();
+    /// assert_eq!("2015年2月3日(火)", ss.format_locale(&ja).to_string());
+    /// ```
+    pub fn from_skeleton(locale: Locale, skeleton: &str) -> Result<Self, LocalePatternError> {
+        let data = locale.data();
+        let lookup = |skeleton: &str, offset| {
+            data.skeletons
+                .iter()
+                .find(|(s, _)| *s == skeleton)
+                .map(|(_, pattern)| *pattern)
+                .ok_or(LocalePatternError::UnsupportedSkeleton { offset })
+        };
+        let split = skeleton.find(['H', 'm', 's']).unwrap_or(skeleton.len());
+        let (date, time) = skeleton.split_at(split);
+        let pattern = if date.is_empty() || time.is_empty() {
+            Cow::Borrowed(lookup(skeleton, 0)?)
+        } else {
+            Cow::Owned(format!(
+                "{}{}{}",
+                lookup(date, 0)?,
+                data.date_time,
+                lookup(time, split)?
+            ))
+        };
+        LocalePattern::compile(locale, &pattern)
+    }
+
+    /// Sets how parsing handles fields out of their ranges, which is
+    /// [ParseMode::Strict] by default.
+    pub fn parse_mode(self, mode: ParseMode) -> Self {
+        LocalePattern { mode, ..self }
+    }
+
+    /// Sets how parsing resolves the two-digit years of `yy`, which is
+    /// [`TwoDigitYear::Pivot(1969)`](TwoDigitYear::Pivot) by default.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilDay, Locale, LocalePattern, TwoDigitYear};
+    ///
+    /// let pattern = LocalePattern::compile(Locale::En, "M/d/yy").unwrap();
+    /// let d = CivilDay::parse_locale("2/3/69", &pattern).unwrap();
+    /// assert_eq!(CivilDay::new(1969, 2, 3), d);
+    ///
+    /// let pattern = pattern.two_digit_year(TwoDigitYear::Pivot(1970));
+    /// let d = CivilDay::parse_locale("2/3/69", &pattern).unwrap();
+    /// assert_eq!(CivilDay::new(2069, 2, 3), d);
+    /// ```
+    pub fn two_digit_year(self, two_digit_year: TwoDigitYear) -> Self {
+        LocalePattern {
+            two_digit_year,
+            ..self
+        }
+    }
+
+    /// Returns the locale of the pattern.
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Writes the civil time `cs` by the pattern.
+    pub(crate) fn write<W: fmt::Write>(&self, w: &mut W, cs: CivilSecond) -> fmt::Result {
+        let data = self.locale.data();
+        for token in &self.tokens {
+            match *token {
+                Token::Literal(ref s) => w.write_str(s)?,
+                Token::Spec(spec) => write_spec(w, cs, spec)?,
+                Token::Unpadded(spec) => {
+                    let v = match spec {
+                        Spec::Month => cs.month(),
+                        Spec::Day => cs.day(),
+                        Spec::YearDay => cs.yearday(),
+                        Spec::Hour => cs.hour(),
+                        Spec::Minute => cs.minute(),
+                        _ => cs.second(),
+                    };
+                    write!(w, "{}", v)?
+                }
+                Token::Month(width, context) => {
+                    w.write_str(data.months(width, context)[cs.month() as usize - 1])?
+                }
+                Token::Weekday(width, context) => {
+                    w.write_str(data.weekdays(width, context)[cs.weekday() as usize])?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses the `input` by the pattern.
+    fn parse_fields(&self, input: &str) -> Result<Fields, ParseError> {
+        let data = self.locale.data();
+        let mut sc = Scanner::new(input);
+        let mut parsed = Parsed::new(self.mode);
+        parsed.two_digit_year = self.two_digit_year;
+        for (i, token) in self.tokens.iter().enumerate() {
+            match *token {
+                Token::Literal(ref lit) => sc.literal(lit)?,
+                Token::Spec(spec) | Token::Unpadded(spec) => {
+                    // Widths of the fixed-width fields that immediately follow.
+                    let mut following = self.tokens[i + 1..].iter().map_while(Token::width);
+                    let (min_width, reserve) = match following.next() {
+                        Some(width) => {
+                            (spec.width().unwrap_or(1), width + following.sum::<usize>())
+                        }
+                        None => (1, 0),
+                    };
+                    parsed.parse_spec(&mut sc, spec, min_width, reserve)?;
+                }
+                Token::Month(width, context) => {
+                    let start = sc.pos();
+                    let names = data.months(width, context).iter().copied();
+                    let i = sc.longest_name(names, Field::Month, Expected::MonthName)?;
+                    set(&mut parsed.month, i as DiffType + 1, Field::Month)
+                        .map_err(|e| e.at(start, sc.pos()))?;
+                    parsed.set_span(Field::Month, start, sc.pos());
+                }
+                Token::Weekday(width, context) => {
+                    let start = sc.pos();
+                    let names = data.weekdays(width, context).iter().copied();
+                    let i = sc.longest_name(names, Field::Day, Expected::WeekdayName)?;
+                    set(&mut parsed.weekday, WEEKDAYS[i], Field::Day)
+                        .map_err(|e| e.at(start, sc.pos()))?;
+                    parsed.set_span(Field::Day, start, sc.pos());
+                }
+            }
+        }
+        if !sc.at_end() {
+            return Err(sc.trailing());
+        }
+
+        parsed.resolve()
+    }
+}
+
+macro_rules! impl_locale {
+    ($Type: ident) => {
+        impl $Type {
+            /// Formats the civil time by the localized `pattern`.
+            ///
+            /// ```rust
+            /// use civil_time::{CivilSecond, Locale, LocalePattern};
+            ///
+            /// let pattern = LocalePattern::from_skeleton(Locale::De, "yMMMMEEEEd").unwrap();
+            /// let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+            /// assert_eq!("Dienstag, 3. Februar 2015", ss.format_locale(&pattern).to_string());
+            /// ```
+            pub fn format_locale<'a>(&self, pattern: &'a LocalePattern) -> DelayedFormat<'a> {
+                DelayedFormat::from_locale(CivilSecond::from_fields(self.0), pattern)
+            }
+
+            /// Parses a civil time from `input` by the localized `pattern`.
+            ///
+            /// Fields not mentioned by the pattern take their default values
+            /// (1970-01-01 00:00:00), and fields finer than the alignment are
+            /// dropped.
+            ///
+            /// ```rust
+            /// use civil_time::{CivilSecond, Locale, LocalePattern};
+            ///
+            /// let pattern = LocalePattern::from_skeleton(Locale::Es, "yMMMMd").unwrap();
+            /// let ss = CivilSecond::parse_locale("3 de febrero de 2015", &pattern).unwrap();
+            /// assert_eq!(CivilSecond::new(2015, 2, 3, 0, 0, 0), ss);
+            /// ```
+            pub fn parse_locale(input: &str, pattern: &LocalePattern) -> Result<Self, ParseError> {
+                pattern
+                    .parse_fields(input)
+                    .map(Self::from_fields)
+                    .map_err(|e| e.at(0, input.len()))
+            }
+        }
+    };
+}
+
+impl_locale!(CivilSecond);
+impl_locale!(CivilMinute);
+impl_locale!(CivilHour);
+impl_locale!(CivilDay);
+impl_locale!(CivilMonth);
+impl_locale!(CivilYear);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParseErrorKind;
+
+    const LOCALES: [Locale; 6] = [
+        Locale::En,
+        Locale::De,
+        Locale::Fr,
+        Locale::Es,
+        Locale::Zh,
+        Locale::Ja,
+    ];
+
+    const DATE_SKELETONS: [&str; 6] =
+        ["yMd", "yMMMd", "yMMMEd", "yMMMMd", "yMMMMEEEEd", "yMMMMdHm"];
+
+    fn pattern(locale: Locale, pattern: &str) -> LocalePattern {
+        LocalePattern::compile(locale, pattern).unwrap()
+    }
+
+    #[test]
+    fn test_from_tag() {
+        for locale in &LOCALES {
+            assert_eq!(Some(*locale), Locale::from_tag(locale.tag()));
+        }
+        assert_eq!(Some(Locale::De), Locale::from_tag("DE-at"));
+        assert_eq!(Some(Locale::Ja), Locale::from_tag("ja_JP"));
+        assert_eq!(None, Locale::from_tag(""));
+        assert_eq!(None, Locale::from_tag("english"));
+    }
+
+    #[test]
+    fn test_names() {
+        use NameContext::*;
+        use NameWidth::*;
+
+        assert_eq!("February", Locale::En.month_name(2, Wide, Format));
+        assert_eq!("Dec", Locale::En.month_name(0, Abbreviated, Format));
+        assert_eq!("J", Locale::En.month_name(13, Narrow, StandAlone));
+        assert_eq!("Sept.", Locale::De.month_name(9, Abbreviated, Format));
+        assert_eq!("Sep", Locale::De.month_name(9, Abbreviated, StandAlone));
+        assert_eq!("févr.", Locale::Fr.month_name(2, Abbreviated, Format));
+        assert_eq!("sept", Locale::Es.month_name(9, Abbreviated, Format));
+        assert_eq!("十一月", Locale::Zh.month_name(11, Wide, Format));
+        assert_eq!("11月", Locale::Ja.month_name(11, Wide, Format));
+
+        assert_eq!(
+            "Sun",
+            Locale::En.weekday_name(Weekday::Sun, Abbreviated, Format)
+        );
+        assert_eq!(
+            "So.",
+            Locale::De.weekday_name(Weekday::Sun, Abbreviated, Format)
+        );
+        assert_eq!(
+            "So",
+            Locale::De.weekday_name(Weekday::Sun, Abbreviated, StandAlone)
+        );
+        assert_eq!("X", Locale::Es.weekday_name(Weekday::Wed, Narrow, Format));
+        assert_eq!(
+            "星期日",
+            Locale::Zh.weekday_name(Weekday::Sun, Wide, Format)
+        );
+        assert_eq!(
+            "日曜日",
+            Locale::Ja.weekday_name(Weekday::Sun, Wide, StandAlone)
+        );
+    }
+
+    #[test]
+    fn test_format_locale() {
+        let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+        let expects = [
+            (Locale::En, "Tuesday, February 3, 2015, 04:05:06"),
+            (Locale::De, "Dienstag, 3. Februar 2015, 04:05:06"),
+            (Locale::Fr, "mardi 3 février 2015 04:05:06"),
+            (Locale::Es, "martes, 3 de febrero de 2015, 4:05:06"),
+            (Locale::Zh, "2015年2月3日星期二 04:05:06"),
+            (Locale::Ja, "2015年2月3日火曜日 4:05:06"),
+        ];
+        for (locale, expect) in expects.iter() {
+            let pattern = LocalePattern::from_skeleton(*locale, "yMMMMEEEEdHms").unwrap();
+            assert_eq!(*expect, ss.format_locale(&pattern).to_string());
+        }
+
+        let expects = [
+            (Locale::En, "Feb 3, 2015"),
+            (Locale::De, "3. Feb. 2015"),
+            (Locale::Fr, "3 févr. 2015"),
+            (Locale::Es, "3 feb 2015"),
+            (Locale::Zh, "2015年2月3日"),
+            (Locale::Ja, "2015年2月3日"),
+        ];
+        for (locale, expect) in expects.iter() {
+            let pattern = LocalePattern::from_skeleton(*locale, "yMMMd").unwrap();
+            assert_eq!(*expect, ss.format_locale(&pattern).to_string());
+        }
+    }
+
+    #[test]
+    fn test_format_fields() {
+        let ss = CivilSecond::new(5, 9, 8, 7, 6, 5);
+        let p = pattern(Locale::En, "y yy yyyy M MM d dd D DDD H HH m mm s ss");
+        assert_eq!(
+            "5 05 0005 9 09 8 08 251 251 7 07 6 06 5 05",
+            ss.format_locale(&p).to_string()
+        );
+        let p = pattern(Locale::De, "MMM LLL MMMMM E EEEE EEEEE ccc");
+        assert_eq!(
+            "Sept. Sep S Do. Donnerstag D Do",
+            ss.format_locale(&p).to_string()
+        );
+
+        // Fields finer than the alignment are formatted as their minimum value.
+        let p = pattern(Locale::En, "MMM d, y HH:mm");
+        assert_eq!(
+            "Sep 1, 5 00:00",
+            CivilMonth::from(ss).format_locale(&p).to_string()
+        );
+    }
+
+    #[test]
+    fn test_compile_literal() {
+        let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+        let p = pattern(Locale::En, "'h' 'o''clock' ''H");
+        assert_eq!("h o'clock '4", ss.format_locale(&p).to_string());
+        let p = pattern(Locale::Fr, "H 'h' mm");
+        assert_eq!("4 h 05", ss.format_locale(&p).to_string());
+        let p = pattern(Locale::En, "'h o''clock' ''H");
+        let hh = CivilHour::parse_locale("h o'clock '4", &p).unwrap();
+        assert_eq!(CivilHour::new(1970, 1, 1, 4), hh);
+    }
+
+    #[test]
+    fn test_compile_error() {
+        let expect_err = |p: &str, e: LocalePatternError| {
+            assert_eq!(
+                e,
+                LocalePattern::compile(Locale::En, p).unwrap_err(),
+                "{}",
+                p
+            );
+        };
+        let unknown = |offset, letter| LocalePatternError::UnknownField { offset, letter };
+        expect_err("y-MM-dd hh", unknown(8, 'h'));
+        expect_err("yyy", unknown(0, 'y'));
+        expect_err("MMMMMM", unknown(0, 'M'));
+        expect_err("ddd", unknown(0, 'd'));
+        expect_err("c", unknown(0, 'c'));
+        expect_err(
+            "y 'at",
+            LocalePatternError::UnterminatedLiteral { offset: 2 },
+        );
+        assert_eq!(
+            "unknown field 'Q' at offset 2",
+            LocalePattern::compile(Locale::En, "y QQQ")
+                .unwrap_err()
+                .to_string()
+        );
+
+        let table = [
+            ("", 0),
+            ("dMy", 0),
+            ("yMMMMM", 0),
+            ("Hmy", 0),
+            ("ms", 0),
+            ("yMMMMMHm", 0),
+            ("yMMMdHs", 5),
+        ];
+        for (skeleton, offset) in table.iter() {
+            assert_eq!(
+                LocalePatternError::UnsupportedSkeleton { offset: *offset },
+                LocalePattern::from_skeleton(Locale::En, skeleton).unwrap_err(),
+                "{}",
+                skeleton
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_locale() {
+        let p = pattern(Locale::En, "EEEE, MMMM d, y");
+        let d = CivilDay::parse_locale("tuesday, FEBRUARY 3, 2015", &p).unwrap();
+        assert_eq!(CivilDay::new(2015, 2, 3), d);
+
+        let p = pattern(Locale::De, "LLL y");
+        let m = CivilMonth::parse_locale("Mär 2015", &p).unwrap();
+        assert_eq!(CivilMonth::new(2015, 3), m);
+        assert!(CivilMonth::parse_locale("März 2015", &p).is_err());
+
+        // The longest name matches.
+        let p = pattern(Locale::Fr, "MMM y");
+        assert_eq!(
+            CivilMonth::new(2015, 7),
+            CivilMonth::parse_locale("juil. 2015", &p).unwrap()
+        );
+        let p = pattern(Locale::Zh, "MMMM");
+        assert_eq!(
+            CivilMonth::new(1970, 12),
+            CivilMonth::parse_locale("十二月", &p).unwrap()
+        );
+
+        let p = pattern(Locale::En, "yyyyMMdd");
+        assert_eq!(
+            CivilDay::new(2015, 2, 3),
+            CivilDay::parse_locale("20150203", &p).unwrap()
+        );
+        let p = pattern(Locale::En, "d/M/yy");
+        assert_eq!(
+            CivilDay::new(1999, 12, 31),
+            CivilDay::parse_locale("31/12/99", &p).unwrap()
+        );
+
+        // Unique narrow names can be parsed.
+        let p = pattern(Locale::En, "MMMMM y");
+        assert_eq!(
+            CivilMonth::new(2015, 2),
+            CivilMonth::parse_locale("F 2015", &p).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_locale_error() {
+        let expect_err = |input: &str, p: &LocalePattern, kind, field| {
+            let e = CivilDay::parse_locale(input, p).unwrap_err();
+            assert_eq!(kind, e.kind(), "input: {}", input);
+            assert_eq!(field, e.field(), "input: {}", input);
+        };
+        use ParseErrorKind::*;
+
+        let p = pattern(Locale::En, "EEE, MMM d, y");
+        expect_err("Mon, Feb 3, 2015", &p, Inconsistent, Some(Field::Day));
+        expect_err("Tue, Feb 30, 2015", &p, OutOfRange, Some(Field::Day));
+        expect_err("Tue, Fev 3, 2015", &p, Invalid, Some(Field::Month));
+        expect_err("Tue, Feb 3, 2015!", &p, TooLong, None);
+        expect_err("Tue, Feb 3", &p, TooShort, None);
+
+        // Ambiguous narrow names.
+        let p = pattern(Locale::En, "MMMMM d, y");
+        expect_err("J 3, 2015", &p, Invalid, Some(Field::Month));
+
+        let p = pattern(Locale::En, "MMM d, y").parse_mode(ParseMode::Normalize);
+        assert_eq!(
+            CivilDay::new(2015, 3, 2),
+            CivilDay::parse_locale("Feb 30, 2015", &p).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        macro_rules! check_round_trip {
+            ($Type: ident, $v: expr, $pattern: expr) => {{
+                let v = $v;
+                let s = v.format_locale($pattern).to_string();
+                assert_eq!(v, $Type::parse_locale(&s, $pattern).unwrap(), "{}", s);
+            }};
+        }
+
+        let values = [
+            CivilSecond::new(2015, 2, 3, 4, 5, 6),
+            CivilSecond::new(2016, 2, 29, 23, 59, 59),
+            CivilSecond::new(-1, 12, 31, 0, 0, 0),
+        ];
+        for locale in &LOCALES {
+            for v in &values {
+                for skeleton in &DATE_SKELETONS {
+                    let p = LocalePattern::from_skeleton(*locale, skeleton).unwrap();
+                    check_round_trip!(CivilDay, CivilDay::from(*v), &p);
+                }
+                let p = LocalePattern::from_skeleton(*locale, "yMMMMdHms").unwrap();
+                check_round_trip!(CivilSecond, *v, &p);
+                check_round_trip!(CivilMinute, CivilMinute::from(*v), &p);
+                check_round_trip!(CivilHour, CivilHour::from(*v), &p);
+                let p = LocalePattern::from_skeleton(*locale, "yMMMM").unwrap();
+                check_round_trip!(CivilMonth, CivilMonth::from(*v), &p);
+                let p = LocalePattern::from_skeleton(*locale, "y").unwrap();
+                check_round_trip!(CivilYear, CivilYear::from(*v), &p);
+            }
+        }
+    }
+}
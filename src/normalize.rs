@@ -0,0 +1,263 @@
+//! Reports of the normalization done by the constructors.
+
+use std::fmt;
+
+use crate::core::{self, DiffType, Field, Fields, MonthType, YearType};
+
+/// Fields from the coarsest to the finest.
+const FIELDS: [Field; 6] = [
+    Field::Year,
+    Field::Month,
+    Field::Day,
+    Field::Hour,
+    Field::Minute,
+    Field::Second,
+];
+
+/// A record of the normalization done by `new_with_report()`, e.g.
+/// [`CivilDay::new_with_report()`](crate::CivilDay::new_with_report): which
+/// input fields were out of their natural ranges, and how many units carried
+/// into each coarser field.
+///
+/// ```rust
+/// use civil_time::{CivilDay, Field};
+///
+/// let (d, report) = CivilDay::new_with_report(2015, 4, 32);
+/// assert_eq!(CivilDay::new(2015, 5, 2), d);
+/// assert!(report.out_of_range(Field::Day));
+/// assert_eq!(1, report.carry(Field::Month));
+/// assert_eq!("day 32 carried 1 into month", report.to_string());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Normalization {
+    values: [DiffType; 6],
+    out_of_range: [bool; 6],
+    carries: [DiffType; 6],
+}
+
+impl Normalization {
+    /// Records the normalization of the given fields into `fields`.
+    pub(crate) const fn new(
+        y: YearType,
+        m: DiffType,
+        d: DiffType,
+        hh: DiffType,
+        mm: DiffType,
+        ss: DiffType,
+        fields: Fields,
+    ) -> Self {
+        // Carries are split into quotients and remainders so that they do not
+        // overflow, like Fields::n_sec().
+        let c_min = ss.div_euclid(60);
+        let c_hour = carry(mm, c_min, 60);
+        let c_day = carry(hh, c_hour, 24);
+
+        // The day is checked against the month it falls in once the input
+        // month is normalized.
+        let (y1, m1) = if m.rem_euclid(12) == 0 {
+            (y.wrapping_add(m.div_euclid(12) - 1), 12)
+        } else {
+            (y.wrapping_add(m.div_euclid(12)), m.rem_euclid(12))
+        };
+        // The day carries into the month, which then carries into the year.
+        // The true differences fit even if the years themselves wrapped
+        // around.
+        let c_year = fields.y.wrapping_sub(y);
+        let c_month = c_year
+            .wrapping_mul(12)
+            .wrapping_add(fields.m as DiffType)
+            .wrapping_sub(m);
+
+        let days = core::days_per_month(y1, m1 as MonthType);
+        Normalization {
+            values: [y, m, d, hh, mm, ss],
+            out_of_range: [
+                false,
+                m < 1 || m > 12,
+                d < 1 || d > days,
+                hh < 0 || hh > 23,
+                mm < 0 || mm > 59,
+                ss < 0 || ss > 59,
+            ],
+            carries: [c_year, c_month, c_day, c_hour, c_min, 0],
+        }
+    }
+
+    /// Returns true if any input field was out of its natural range.
+    ///
+    /// The day is checked against the month it falls in once the month is
+    /// normalized.
+    pub const fn was_normalized(&self) -> bool {
+        let mut i = 0;
+        while i < self.out_of_range.len() {
+            if self.out_of_range[i] {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Returns true if the input `field` was out of its natural range.
+    pub const fn out_of_range(&self, field: Field) -> bool {
+        self.out_of_range[field as usize]
+    }
+
+    /// Returns the number of units carried into `field` from the next finer
+    /// field, negative if borrowed. The carry into [Field::Second] is always
+    /// 0.
+    ///
+    /// The carries are applied from the finest field, so a carry may come
+    /// from a field in range, e.g. a minute of 59 carries 1 into the hour
+    /// once the seconds carry 1 into it.
+    pub const fn carry(&self, field: Field) -> DiffType {
+        self.carries[field as usize]
+    }
+}
+
+/// Returns the carry of `v + c` for the unit of `n`.
+const fn carry(v: DiffType, c: DiffType, n: DiffType) -> DiffType {
+    v.div_euclid(n) + c.div_euclid(n) + (v.rem_euclid(n) + c.rem_euclid(n)) / n
+}
+
+impl fmt::Display for Normalization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for i in 1..FIELDS.len() {
+            let carry = self.carries[i - 1];
+            if !self.out_of_range[i] && carry == 0 {
+                continue;
+            }
+            if !first {
+                f.write_str(", ")?;
+            }
+            first = false;
+            write!(
+                f,
+                "{} {} carried {} into {}",
+                FIELDS[i],
+                self.values[i],
+                carry,
+                FIELDS[i - 1]
+            )?;
+        }
+        if first {
+            f.write_str("no field normalized")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::expect_eq;
+    use crate::{CivilDay, CivilMinute, CivilMonth, CivilSecond, CivilYear};
+
+    #[test]
+    fn test_new_with_report() {
+        let (ss, report) = CivilSecond::new_with_report(2015, 2, 3, 4, 5, 6);
+        assert_eq!(CivilSecond::new(2015, 2, 3, 4, 5, 6), ss);
+        assert!(!report.was_normalized());
+        for field in FIELDS.iter() {
+            assert!(!report.out_of_range(*field));
+            assert_eq!(0, report.carry(*field));
+        }
+        assert_eq!("no field normalized", report.to_string());
+
+        let (ss, report) = CivilSecond::new_with_report(2015, 12, 31, 23, 59, 60);
+        expect_eq("2016-01-01T00:00:00", ss);
+        assert!(report.was_normalized());
+        assert!(report.out_of_range(Field::Second));
+        assert!(!report.out_of_range(Field::Minute));
+        assert!(!report.out_of_range(Field::Day));
+        assert_eq!([1, 1, 1, 1, 1, 0], report.carries);
+        assert_eq!(
+            "month 12 carried 1 into year, day 31 carried 1 into month, hour 23 carried 1 into day, \
+             minute 59 carried 1 into hour, second 60 carried 1 into minute",
+            report.to_string()
+        );
+
+        // The hour carries through the day and the month into the year.
+        let (ss, report) = CivilSecond::new_with_report(2016, 12, 31, 24, 0, 0);
+        expect_eq("2017-01-01T00:00:00", ss);
+        assert_eq!([1, 1, 1, 0, 0, 0], report.carries);
+        assert_eq!(
+            "month 12 carried 1 into year, day 31 carried 1 into month, hour 24 carried 1 into day",
+            report.to_string()
+        );
+    }
+
+    #[test]
+    fn test_new_with_report_borrows() {
+        let (ss, report) = CivilSecond::new_with_report(2016, 3, 0, -1, 0, 0);
+        expect_eq("2016-02-28T23:00:00", ss);
+        assert!(report.out_of_range(Field::Day));
+        assert!(report.out_of_range(Field::Hour));
+        assert_eq!([0, -1, -1, 0, 0, 0], report.carries);
+
+        let (d, report) = CivilDay::new_with_report(2016, 0, 1);
+        expect_eq("2015-12-01", d);
+        assert!(report.out_of_range(Field::Month));
+        assert_eq!(-1, report.carry(Field::Year));
+        assert_eq!("month 0 carried -1 into year", report.to_string());
+
+        let (m, report) = CivilMonth::new_with_report(2016, -13);
+        expect_eq("2014-11", m);
+        assert_eq!(-2, report.carry(Field::Year));
+    }
+
+    #[test]
+    fn test_new_with_report_days() {
+        // The day is checked against the normalized month.
+        let (d, report) = CivilDay::new_with_report(2016, 14, 29);
+        expect_eq("2017-02-28", d - 1);
+        assert!(report.out_of_range(Field::Day));
+        assert_eq!(1, report.carry(Field::Year));
+        assert_eq!(1, report.carry(Field::Month));
+
+        let (d, report) = CivilDay::new_with_report(2016, 1, 366);
+        expect_eq("2016-12-31", d);
+        assert_eq!(11, report.carry(Field::Month));
+        assert_eq!(0, report.carry(Field::Year));
+
+        let (d, report) = CivilDay::new_with_report(2016, 1, 367);
+        expect_eq("2017-01-01", d);
+        assert_eq!(12, report.carry(Field::Month));
+        assert_eq!(1, report.carry(Field::Year));
+        assert_eq!(
+            "month 1 carried 1 into year, day 367 carried 12 into month",
+            report.to_string()
+        );
+
+        // A month out of range and the day carry both reach the year.
+        let (d, report) = CivilDay::new_with_report(2016, 13, -31);
+        expect_eq("2016-11-30", d);
+        assert_eq!(-2, report.carry(Field::Month));
+        assert_eq!(0, report.carry(Field::Year));
+        let (d, report) = CivilDay::new_with_report(2016, 24, 32);
+        expect_eq("2018-01-01", d);
+        assert_eq!(1, report.carry(Field::Month));
+        assert_eq!(2, report.carry(Field::Year));
+
+        // A minute in range may still carry.
+        let (mm, report) = CivilMinute::new_with_report(2015, 1, 1, 47, 0);
+        expect_eq("2015-01-02T23:00", mm);
+        assert!(!report.out_of_range(Field::Minute));
+        assert!(report.out_of_range(Field::Hour));
+        assert_eq!(1, report.carry(Field::Day));
+    }
+
+    #[test]
+    fn test_new_with_report_limits() {
+        let max = DiffType::MAX;
+        let (ss, report) = CivilSecond::new_with_report(1970, 1, 1, 0, max, max);
+        assert_eq!(CivilSecond::new(1970, 1, 1, 0, max, max), ss);
+        assert_eq!(156284915068928145, report.carry(Field::Hour));
+        assert_eq!(max / 60, report.carry(Field::Minute));
+
+        let (y, report) = CivilYear::new_with_report(YearType::MIN);
+        assert_eq!(CivilYear::MIN, y);
+        assert!(!report.was_normalized());
+    }
+}
@@ -0,0 +1,99 @@
+//! UTC offsets carried by timestamps.
+
+use std::fmt;
+
+use crate::core::{DiffType, Field};
+use crate::parse::{Expected, ParseError, ParseErrorKind, Scanner};
+
+/// A fixed offset from UTC in minutes, e.g. "+0800" and "-05:00", as carried
+/// by RFC 2822 and RFC 3339 timestamps.
+///
+/// The offset does not change the civil time it comes with, which is the
+/// local time at the offset.
+///
+/// ```rust
+/// use civil_time::UtcOffset;
+///
+/// let offset = UtcOffset::from_minutes(-300);
+/// assert_eq!(-300, offset.minutes());
+/// assert_eq!(UtcOffset::UTC, UtcOffset::default());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct UtcOffset {
+    minutes: i32,
+}
+
+impl UtcOffset {
+    /// The offset of UTC itself.
+    pub const UTC: UtcOffset = UtcOffset { minutes: 0 };
+
+    /// Creates an offset of `minutes` east of UTC.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the offset is not less than 24 hours in either direction.
+    pub const fn from_minutes(minutes: i32) -> Self {
+        assert!(-1440 < minutes && minutes < 1440, "offset out of range");
+        UtcOffset { minutes }
+    }
+
+    /// Returns the number of minutes east of UTC.
+    pub const fn minutes(&self) -> i32 {
+        self.minutes
+    }
+
+    /// Writes the offset as a sign, hours and minutes, separated by ':' if
+    /// `colon`, e.g. "+0800" and "-05:00".
+    pub(crate) fn write<W: fmt::Write>(&self, w: &mut W, colon: bool) -> fmt::Result {
+        let sign = if self.minutes < 0 { '-' } else { '+' };
+        let minutes = self.minutes.unsigned_abs();
+        let separator = if colon { ":" } else { "" };
+        write!(
+            w,
+            "{}{:02}{}{:02}",
+            sign,
+            minutes / 60,
+            separator,
+            minutes % 60
+        )
+    }
+
+    /// Parses the offset as a sign, hours and minutes, separated by ':' if
+    /// `colon`, e.g. "+0800" and "-05:00".
+    pub(crate) fn parse(sc: &mut Scanner<'_>, colon: bool) -> Result<Self, ParseError> {
+        let negative = match sc.peek() {
+            Some(b'+') => false,
+            Some(b'-') => true,
+            _ => return Err(sc.unexpected(None, Expected::Zone)),
+        };
+        sc.literal(if negative { "-" } else { "+" })?;
+        let hh = Self::parse_part(sc, Field::Hour, 23)?;
+        if colon {
+            sc.literal(":")?;
+        }
+        let mm = Self::parse_part(sc, Field::Minute, 59)?;
+        let minutes = (hh * 60 + mm) as i32;
+        Ok(UtcOffset::from_minutes(if negative {
+            -minutes
+        } else {
+            minutes
+        }))
+    }
+
+    /// Parses the hours or minutes of the offset, which are of 2 digits up to
+    /// `max`. Errors are not of the fields of the civil time.
+    fn parse_part(
+        sc: &mut Scanner<'_>,
+        field: Field,
+        max: DiffType,
+    ) -> Result<DiffType, ParseError> {
+        let start = sc.pos();
+        let v = sc.number(2, 2, field).map_err(|e| e.with_field(None))?;
+        if v > max {
+            return Err(ParseError::new(ParseErrorKind::OutOfRange, None)
+                .at(start, sc.pos())
+                .expecting(Expected::Range { min: 0, max }));
+        }
+        Ok(v)
+    }
+}
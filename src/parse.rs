@@ -0,0 +1,2008 @@
+//! Parsing civil time types from strings.
+
+use crate::core::{self, DiffType, Field, Fields, MonthType, YearType};
+use crate::format::{Item, Items, Spec, MONTH_NAMES};
+use crate::weekday::{iso_week, week_of_year, WEEKDAYS};
+use crate::{CivilDay, CivilHour, CivilMinute, CivilMonth, CivilSecond, CivilYear, Weekday};
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+/// The kind of an error occurred while parsing a civil time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input ends before the civil time is complete.
+    TooShort,
+    /// There are remaining characters after the civil time.
+    TooLong,
+    /// A field or a separator is malformed.
+    Invalid,
+    /// A field is out of its valid range.
+    OutOfRange,
+    /// A field contradicts other fields, e.g. the weekday does not match the
+    /// date.
+    Inconsistent,
+    /// A field can not be determined without guessing, e.g. the century of a
+    /// two-digit year under [TwoDigitYear::Strict].
+    Ambiguous,
+}
+
+/// What the parser expected where an error occurred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expected {
+    /// A digit of a number.
+    Digit,
+    /// A character of a literal or a separator.
+    Char(char),
+    /// A sign of a number, '+' or '-'.
+    Sign,
+    /// A month name.
+    MonthName,
+    /// A weekday name.
+    WeekdayName,
+    /// A UTC offset or a zone name.
+    Zone,
+    /// The keyword of an expression, e.g. "of".
+    Keyword(&'static str),
+    /// A word of a relative expression, e.g. "next" or "days".
+    Word,
+    /// A value from `min` to `max` of the field.
+    Range {
+        /// The minimum value.
+        min: i64,
+        /// The maximum value.
+        max: i64,
+    },
+    /// The end of input.
+    End,
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Expected::Digit => f.write_str("a digit"),
+            Expected::Char(c) => write!(f, "{:?}", c),
+            Expected::Sign => f.write_str("'+' or '-'"),
+            Expected::MonthName => f.write_str("a month name"),
+            Expected::WeekdayName => f.write_str("a weekday name"),
+            Expected::Zone => f.write_str("a UTC offset or zone name"),
+            Expected::Keyword(word) => write!(f, "\"{}\"", word),
+            Expected::Word => f.write_str("a word of relative time"),
+            Expected::Range { min, max } => write!(f, "a value from {} to {}", min, max),
+            Expected::End => f.write_str("end of input"),
+        }
+    }
+}
+
+/// An error returned when parsing a civil time from a string.
+///
+/// Besides the kind of the error and the field involved, the error carries
+/// the byte span of the offending input and what was expected there, which
+/// can be rendered as a caret diagnostic by [`diagnostic()`](Self::diagnostic).
+///
+/// ```rust
+/// use civil_time::{CivilDay, Expected, Field, ParseErrorKind};
+///
+/// let e = CivilDay::parse_from_str("2015-Fob-03", "%Y-%b-%d").unwrap_err();
+/// assert_eq!(ParseErrorKind::Invalid, e.kind());
+/// assert_eq!(Some(Field::Month), e.field());
+/// assert_eq!(5..8, e.span());
+/// assert_eq!(Some(Expected::MonthName), e.expected());
+///
+/// let e = CivilDay::parse_from_str("2015-02-30", "%Y-%m-%d").unwrap_err();
+/// assert_eq!(8..10, e.span());
+/// assert_eq!(Some(Expected::Range { min: 1, max: 28 }), e.expected());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    field: Option<Field>,
+    span: Option<(usize, usize)>,
+    expected: Option<Expected>,
+}
+
+impl ParseError {
+    pub(crate) const fn new(kind: ParseErrorKind, field: Option<Field>) -> Self {
+        Self {
+            kind,
+            field,
+            span: None,
+            expected: None,
+        }
+    }
+
+    /// Locates the error at the byte span [`start`, `end`), unless it is
+    /// already located.
+    pub(crate) const fn at(mut self, start: usize, end: usize) -> Self {
+        if self.span.is_none() {
+            self.span = Some((start, end));
+        }
+        self
+    }
+
+    /// Moves the span of the error by `offset` bytes, for errors of a part
+    /// of the input.
+    pub(crate) const fn shift(mut self, offset: usize) -> Self {
+        if let Some((start, end)) = self.span {
+            self.span = Some((start + offset, end + offset));
+        }
+        self
+    }
+
+    /// Sets what was expected, unless it is already set.
+    pub(crate) const fn expecting(mut self, expected: Expected) -> Self {
+        if self.expected.is_none() {
+            self.expected = Some(expected);
+        }
+        self
+    }
+
+    /// Replaces the field of the error.
+    pub(crate) const fn with_field(mut self, field: Option<Field>) -> Self {
+        self.field = field;
+        self
+    }
+
+    /// Returns the kind of the error.
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Returns the field that failed to parse, or `None` if the error is not
+    /// caused by a specific field.
+    pub const fn field(&self) -> Option<Field> {
+        self.field
+    }
+
+    /// Returns the byte span of the offending input, which is empty at the
+    /// end of input if the input ends prematurely, and is the whole input if
+    /// the error is not caused by a specific part of it. The span is `0..0`
+    /// if the error is not located in any input.
+    pub fn span(&self) -> Range<usize> {
+        let (start, end) = self.span.unwrap_or((0, 0));
+        start..end
+    }
+
+    /// Returns what was expected at the span, or `None` if the error is not
+    /// about a specific token, e.g. fields that are inconsistent.
+    pub const fn expected(&self) -> Option<Expected> {
+        self.expected
+    }
+
+    /// Returns a diagnostic that shows the error under the line of `input`
+    /// it occurred in, `input` must be the input that failed to parse.
+    ///
+    /// ```rust
+    /// use civil_time::CivilDay;
+    ///
+    /// let input = "2015-Fob-03";
+    /// let e = CivilDay::parse_from_str(input, "%Y-%b-%d").unwrap_err();
+    /// let expect = "\
+    /// invalid month at byte 5, expected a month name
+    /// 2015-Fob-03
+    ///      ^^^";
+    /// assert_eq!(expect, e.diagnostic(input).to_string());
+    /// ```
+    pub fn diagnostic<'a>(&self, input: &'a str) -> Diagnostic<'a> {
+        Diagnostic {
+            error: *self,
+            input,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.kind, self.field) {
+            (ParseErrorKind::TooShort, Some(field)) => {
+                write!(f, "premature end of input, expecting {}", field)
+            }
+            (ParseErrorKind::TooShort, None) => f.write_str("premature end of input"),
+            (ParseErrorKind::TooLong, _) => f.write_str("trailing input"),
+            (ParseErrorKind::Invalid, Some(field)) => write!(f, "invalid {}", field),
+            (ParseErrorKind::Invalid, None) => f.write_str("invalid input"),
+            (ParseErrorKind::OutOfRange, Some(field)) => write!(f, "{} out of range", field),
+            (ParseErrorKind::OutOfRange, None) => f.write_str("value out of range"),
+            (ParseErrorKind::Inconsistent, Some(field)) => {
+                write!(f, "{} is inconsistent with other fields", field)
+            }
+            (ParseErrorKind::Inconsistent, None) => f.write_str("inconsistent fields"),
+            (ParseErrorKind::Ambiguous, Some(field)) => write!(f, "ambiguous {}", field),
+            (ParseErrorKind::Ambiguous, None) => f.write_str("ambiguous input"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// A caret diagnostic of a [ParseError], returned by
+/// [`ParseError::diagnostic()`].
+///
+/// The first line is the error with its byte offset and what was expected,
+/// followed by the line of the input the error occurred in, and carets under
+/// the offending characters.
+#[derive(Debug, Clone, Copy)]
+pub struct Diagnostic<'a> {
+    error: ParseError,
+    input: &'a str,
+}
+
+/// Returns the largest char boundary of `s` not greater than `i`.
+fn floor_char_boundary(s: &str, i: usize) -> usize {
+    let mut i = i.min(s.len());
+    while !s.is_char_boundary(i) {
+        i -= 1;
+    }
+    i
+}
+
+impl fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let input = self.input;
+        let span = self.error.span();
+        let start = floor_char_boundary(input, span.start);
+        let end = floor_char_boundary(input, span.end).max(start);
+
+        write!(f, "{} at byte {}", self.error, start)?;
+        if let Some(expected) = self.error.expected {
+            write!(f, ", expected {}", expected)?;
+        }
+
+        let line_start = input[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[start..].find('\n').map_or(input.len(), |i| start + i);
+        let line = input[line_start..line_end].trim_end_matches('\r');
+        write!(f, "\n{}\n", line)?;
+        // Tabs are kept so that the carets line up with the line above.
+        for c in input[line_start..start].chars() {
+            f.write_str(if c == '\t' { "\t" } else { " " })?;
+        }
+        let width = input[start..end.min(line_end)].chars().count().max(1);
+        for _ in 0..width {
+            f.write_str("^")?;
+        }
+        Ok(())
+    }
+}
+
+/// How fields out of their natural ranges are handled by parsing.
+///
+/// ```rust
+/// use civil_time::{CivilDay, FormatDescription, ParseErrorKind, ParseMode};
+///
+/// let desc = FormatDescription::compile("%Y-%m-%d").unwrap();
+/// let e = desc.parse::<CivilDay>("2016-10-32").unwrap_err();
+/// assert_eq!(ParseErrorKind::OutOfRange, e.kind());
+///
+/// let desc = desc.parse_mode(ParseMode::Normalize);
+/// let d: CivilDay = desc.parse("2016-10-32").unwrap();
+/// assert_eq!(CivilDay::new(2016, 11, 1), d);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ParseMode {
+    /// Rejects any field out of its natural range, e.g. October 32, and
+    /// reports the field.
+    #[default]
+    Strict,
+    /// Normalizes fields out of their natural ranges in the same way as the
+    /// constructors, e.g. October 32 is November 1.
+    ///
+    /// Only month, day, day of year, hour, minute and second are normalized.
+    /// Weekdays and weeks of year must still be in their ranges, and are
+    /// checked against the normalized date. A year that overflows by
+    /// normalization is an [OutOfRange](ParseErrorKind::OutOfRange) error of
+    /// [Field::Year].
+    ///
+    /// ```rust
+    /// use civil_time::{CivilDay, Field, FormatDescription, ParseErrorKind, ParseMode};
+    ///
+    /// let desc = FormatDescription::compile("%Y-%m-%d")
+    ///     .unwrap()
+    ///     .parse_mode(ParseMode::Normalize);
+    /// let e = desc.parse::<CivilDay>("9223372036854775807-12-32").unwrap_err();
+    /// assert_eq!(ParseErrorKind::OutOfRange, e.kind());
+    /// assert_eq!(Some(Field::Year), e.field());
+    /// ```
+    Normalize,
+}
+
+/// How two-digit years, e.g. `%y` without `%C`, are resolved by parsing.
+///
+/// ```rust
+/// use civil_time::{CivilDay, CivilYear, FormatDescription, ParseErrorKind, TwoDigitYear};
+///
+/// let desc = FormatDescription::compile("%y%m%d").unwrap();
+/// let d: CivilDay = desc.parse("690203").unwrap();
+/// assert_eq!(CivilDay::new(1969, 2, 3), d);
+///
+/// let pivot = desc.clone().two_digit_year(TwoDigitYear::Pivot(1970));
+/// let d: CivilDay = pivot.parse("690203").unwrap();
+/// assert_eq!(CivilDay::new(2069, 2, 3), d);
+///
+/// let window = TwoDigitYear::sliding(CivilYear::new(2015), 20).unwrap();
+/// let sliding = desc.clone().two_digit_year(window);
+/// assert_eq!(CivilDay::new(2035, 2, 3), sliding.parse::<CivilDay>("350203").unwrap());
+/// assert_eq!(CivilDay::new(1936, 2, 3), sliding.parse::<CivilDay>("360203").unwrap());
+///
+/// let strict = desc.two_digit_year(TwoDigitYear::Strict);
+/// let e = strict.parse::<CivilDay>("690203").unwrap_err();
+/// assert_eq!(ParseErrorKind::Ambiguous, e.kind());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwoDigitYear {
+    /// Two-digit years refer to the 100 years starting at the pivot year,
+    /// e.g. 69 to 99 refer to 1969 to 1999 and 00 to 68 refer to 2000 to 2068
+    /// if the pivot is 1969, which is the default as in POSIX.
+    ///
+    /// A window sliding with the current year is a pivot created by
+    /// [`TwoDigitYear::sliding()`].
+    Pivot(YearType),
+    /// Two-digit years are errors of [ParseErrorKind::Ambiguous], unless the
+    /// century is also parsed.
+    Strict,
+}
+
+impl Default for TwoDigitYear {
+    fn default() -> Self {
+        TwoDigitYear::POSIX
+    }
+}
+
+impl TwoDigitYear {
+    /// The default policy, usable in const contexts.
+    pub(crate) const POSIX: TwoDigitYear = TwoDigitYear::Pivot(1969);
+
+    /// Returns the policy of the 100 years ending `future` years after the
+    /// `reference` year, usually the current year, e.g. 2014 to 2113 if the
+    /// reference is 2015 and `future` is 98.
+    ///
+    /// Returns `None` if `future` is greater than 99, or if the window starts
+    /// before the minimum year.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilYear, TwoDigitYear};
+    ///
+    /// let window = TwoDigitYear::sliding(CivilYear::new(2015), 98);
+    /// assert_eq!(Some(TwoDigitYear::Pivot(2014)), window);
+    /// assert_eq!(None, TwoDigitYear::sliding(CivilYear::new(2015), 100));
+    /// assert_eq!(None, TwoDigitYear::sliding(CivilYear::MIN, 0));
+    /// ```
+    pub const fn sliding(reference: CivilYear, future: u8) -> Option<Self> {
+        if future > 99 {
+            return None;
+        }
+        match reference.year().checked_add(future as YearType - 99) {
+            Some(pivot) => Some(TwoDigitYear::Pivot(pivot)),
+            None => None,
+        }
+    }
+
+    /// Resolves the two-digit year `yy` into a full year.
+    pub(crate) fn resolve(self, yy: DiffType) -> Result<YearType, ParseError> {
+        let out_of_range = || ParseError::new(ParseErrorKind::OutOfRange, Some(Field::Year));
+        let pivot = match self {
+            TwoDigitYear::Pivot(pivot) => pivot,
+            TwoDigitYear::Strict => {
+                return Err(ParseError::new(
+                    ParseErrorKind::Ambiguous,
+                    Some(Field::Year),
+                ))
+            }
+        };
+        // The year of the same century as the pivot, or of the next century
+        // if it is before the pivot.
+        let y = pivot
+            .checked_sub(pivot.rem_euclid(100))
+            .and_then(|y| y.checked_add(yy))
+            .ok_or_else(out_of_range)?;
+        if y < pivot {
+            y.checked_add(100).ok_or_else(out_of_range)
+        } else {
+            Ok(y)
+        }
+    }
+}
+
+/// Fields in the order they are printed.
+const FIELDS: [Field; 6] = [
+    Field::Year,
+    Field::Month,
+    Field::Day,
+    Field::Hour,
+    Field::Minute,
+    Field::Second,
+];
+
+/// Separators printed before each field by the `Debug` impls.
+const SEPARATORS: [u8; 6] = [0, b'-', b'-', b'T', b':', b':'];
+
+/// Returns the end of the char starting at `pos`, which must be in `s`.
+const fn char_end(s: &[u8], pos: usize) -> usize {
+    let len = match s[pos] {
+        0..=0x7f => 1,
+        0x80..=0xdf => 2,
+        0xe0..=0xef => 3,
+        _ => 4,
+    };
+    if pos + len < s.len() {
+        pos + len
+    } else {
+        s.len()
+    }
+}
+
+/// Returns the error of an unexpected char at `pos`, or of the premature end
+/// of input if `pos` is at the end.
+const fn unexpected_at(s: &[u8], pos: usize, field: Field, expected: Expected) -> ParseError {
+    let e = if pos >= s.len() {
+        ParseError::new(ParseErrorKind::TooShort, Some(field)).at(s.len(), s.len())
+    } else {
+        ParseError::new(ParseErrorKind::Invalid, Some(field)).at(pos, char_end(s, pos))
+    };
+    e.expecting(expected)
+}
+
+/// Parses an optionally negative year starting at `pos`, returns the year and
+/// the position after it.
+pub(crate) const fn parse_year(s: &[u8], mut pos: usize) -> Result<(YearType, usize), ParseError> {
+    let sign = pos;
+    let negative = pos < s.len() && s[pos] == b'-';
+    if negative {
+        pos += 1;
+    }
+    let start = pos;
+    let mut y: YearType = 0;
+    while pos < s.len() && s[pos].is_ascii_digit() {
+        let digit = (s[pos] - b'0') as YearType;
+        let next = match y.checked_mul(10) {
+            Some(v) if negative => v.checked_sub(digit),
+            Some(v) => v.checked_add(digit),
+            None => None,
+        };
+        y = match next {
+            Some(v) => v,
+            None => {
+                return Err(
+                    ParseError::new(ParseErrorKind::OutOfRange, Some(Field::Year))
+                        .at(sign, pos + 1)
+                        .expecting(Expected::Range {
+                            min: YearType::MIN,
+                            max: YearType::MAX,
+                        }),
+                )
+            }
+        };
+        pos += 1;
+    }
+    if pos == start {
+        return Err(unexpected_at(s, pos, Field::Year, Expected::Digit));
+    }
+
+    Ok((y, pos))
+}
+
+/// Returns the natural range of the `i`-th field.
+const fn range(i: usize, values: &[DiffType; 6]) -> (DiffType, DiffType) {
+    match i {
+        1 => (1, 12),
+        2 => (1, core::days_per_month(values[0], values[1] as MonthType)),
+        3 => (0, 23),
+        _ => (0, 59),
+    }
+}
+
+/// Parses the first `n` fields (from year to second) of a civil time in the
+/// layout printed by the `Debug` impls, e.g. "2015-02-03T04:05:06".
+///
+/// Fields are not normalized, any field out of its range is an error.
+pub(crate) const fn parse_debug(s: &[u8], n: usize) -> Result<Fields, ParseError> {
+    let (y, mut pos) = match parse_year(s, 0) {
+        Ok(v) => v,
+        Err(e) => return Err(e),
+    };
+    let mut values: [DiffType; 6] = [y, 1, 1, 0, 0, 0];
+    let mut i = 1;
+    while i < n {
+        let field = FIELDS[i];
+        if pos >= s.len() || s[pos] != SEPARATORS[i] {
+            return Err(unexpected_at(
+                s,
+                pos,
+                field,
+                Expected::Char(SEPARATORS[i] as char),
+            ));
+        }
+        pos += 1;
+
+        let start = pos;
+        let mut v = 0;
+        while pos < start + 2 {
+            if pos >= s.len() || !s[pos].is_ascii_digit() {
+                return Err(unexpected_at(s, pos, field, Expected::Digit));
+            }
+            v = v * 10 + (s[pos] - b'0') as DiffType;
+            pos += 1;
+        }
+        values[i] = v;
+        let (min, max) = range(i, &values);
+        if v < min || v > max {
+            return Err(ParseError::new(ParseErrorKind::OutOfRange, Some(field))
+                .at(start, pos)
+                .expecting(Expected::Range { min, max }));
+        }
+        i += 1;
+    }
+    if pos < s.len() {
+        return Err(ParseError::new(ParseErrorKind::TooLong, None)
+            .at(pos, s.len())
+            .expecting(Expected::End));
+    }
+
+    Ok(Fields::n_sec(
+        values[0], values[1], values[2], values[3], values[4], values[5],
+    ))
+}
+
+/// Cursor over the input of strftime-like parsing.
+pub(crate) struct Scanner<'a> {
+    input: &'a str,
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub(crate) fn new(s: &'a str) -> Self {
+        Scanner {
+            input: s,
+            s: s.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    /// Returns the error of an unexpected character at current position,
+    /// where `expected` is expected.
+    pub(crate) fn unexpected(&self, field: Option<Field>, expected: Expected) -> ParseError {
+        let (kind, end) = match self.rest().chars().next() {
+            Some(c) => (ParseErrorKind::Invalid, self.pos + c.len_utf8()),
+            None => (ParseErrorKind::TooShort, self.pos),
+        };
+        ParseError::new(kind, field)
+            .at(self.pos, end)
+            .expecting(expected)
+    }
+
+    /// Returns the error of an unexpected name at current position, which
+    /// spans the letters there.
+    fn unexpected_name(&self, field: Field, expected: Expected) -> ParseError {
+        let len: usize = self
+            .rest()
+            .chars()
+            .take_while(|c| c.is_alphabetic())
+            .map(char::len_utf8)
+            .sum();
+        if len == 0 {
+            return self.unexpected(Some(field), expected);
+        }
+        ParseError::new(ParseErrorKind::Invalid, Some(field))
+            .at(self.pos, self.pos + len)
+            .expecting(expected)
+    }
+
+    /// Returns the error of trailing input after the civil time.
+    pub(crate) fn trailing(&self) -> ParseError {
+        ParseError::new(ParseErrorKind::TooLong, None)
+            .at(self.pos, self.s.len())
+            .expecting(Expected::End)
+    }
+
+    /// Returns the input after current position.
+    fn rest(&self) -> &'a str {
+        // The position is always at a char boundary.
+        self.input.get(self.pos..).unwrap_or("")
+    }
+
+    /// Returns whether the whole input is consumed.
+    pub(crate) fn at_end(&self) -> bool {
+        self.pos >= self.s.len()
+    }
+
+    /// Returns the current byte position.
+    pub(crate) fn pos(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the byte at current position.
+    pub(crate) fn peek(&self) -> Option<u8> {
+        self.s.get(self.pos).copied()
+    }
+
+    /// Skips spaces and tabs, returns the number of bytes skipped.
+    pub(crate) fn spaces(&mut self) -> usize {
+        let start = self.pos;
+        while matches!(self.peek(), Some(b' ') | Some(b'\t')) {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    /// Skips a parenthesized comment of RFC 2822 at current position, which
+    /// may contain nested comments and backslash-escaped characters. Returns
+    /// whether a comment was skipped.
+    pub(crate) fn comment(&mut self) -> Result<bool, ParseError> {
+        if self.peek() != Some(b'(') {
+            return Ok(false);
+        }
+        let mut depth = 0usize;
+        let mut chars = self.rest().chars();
+        while let Some(c) = chars.next() {
+            self.pos += c.len_utf8();
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                '\\' => match chars.next() {
+                    Some(c) => self.pos += c.len_utf8(),
+                    None => break,
+                },
+                _ => {}
+            }
+            if depth == 0 {
+                return Ok(true);
+            }
+        }
+        Err(self.unexpected(None, Expected::Char(')')))
+    }
+
+    /// Skips ASCII digits, returns the number of digits skipped.
+    pub(crate) fn digits(&mut self) -> usize {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    /// Skips ASCII letters, returns the letters skipped.
+    pub(crate) fn letters(&mut self) -> &'a [u8] {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_ascii_alphabetic()) {
+            self.pos += 1;
+        }
+        &self.s[start..self.pos]
+    }
+
+    pub(crate) fn literal(&mut self, lit: &str) -> Result<(), ParseError> {
+        for c in lit.chars() {
+            if !self.rest().starts_with(c) {
+                return Err(self.unexpected(None, Expected::Char(c)));
+            }
+            self.pos += c.len_utf8();
+        }
+        Ok(())
+    }
+
+    /// Parses an unsigned number of `min` to `max` digits.
+    pub(crate) fn number(
+        &mut self,
+        min: usize,
+        max: usize,
+        field: Field,
+    ) -> Result<DiffType, ParseError> {
+        let start = self.pos;
+        let mut v = 0;
+        while self.pos - start < max {
+            match self.s.get(self.pos) {
+                Some(c) if c.is_ascii_digit() => v = v * 10 + (c - b'0') as DiffType,
+                _ => break,
+            }
+            self.pos += 1;
+        }
+        if self.pos - start < min {
+            return Err(self.unexpected(Some(field), Expected::Digit));
+        }
+        Ok(v)
+    }
+
+    /// Parses an optionally signed number of at least `min` digits, but leaves
+    /// `reserve` trailing digits to the directives that follow.
+    fn signed(&mut self, min: usize, reserve: usize, field: Field) -> Result<i64, ParseError> {
+        let negative = match self.s.get(self.pos) {
+            Some(b'-') => true,
+            Some(b'+') => false,
+            _ => return self.unsigned(min, reserve, false, field),
+        };
+        self.pos += 1;
+        self.unsigned(min, reserve, negative, field)
+    }
+
+    /// Parses the sign of a number that must be signed, returns if it is
+    /// negative.
+    fn sign(&mut self, field: Field) -> Result<bool, ParseError> {
+        let negative = match self.s.get(self.pos) {
+            Some(b'-') => true,
+            Some(b'+') => false,
+            _ => return Err(self.unexpected(Some(field), Expected::Sign)),
+        };
+        self.pos += 1;
+        Ok(negative)
+    }
+
+    fn unsigned(
+        &mut self,
+        min: usize,
+        reserve: usize,
+        negative: bool,
+        field: Field,
+    ) -> Result<i64, ParseError> {
+        let run = self.s[self.pos..]
+            .iter()
+            .take_while(|c| c.is_ascii_digit())
+            .count();
+        if run < reserve + min.max(1) {
+            self.pos += run;
+            return Err(self.unexpected(Some(field), Expected::Digit));
+        }
+
+        let end = self.pos + run - reserve;
+        let mut v: i64 = 0;
+        for &c in &self.s[self.pos..end] {
+            let digit = (c - b'0') as i64;
+            v = v
+                .checked_mul(10)
+                .and_then(|v| {
+                    if negative {
+                        v.checked_sub(digit)
+                    } else {
+                        v.checked_add(digit)
+                    }
+                })
+                .ok_or_else(|| {
+                    ParseError::new(ParseErrorKind::OutOfRange, Some(field))
+                        .at(self.pos, end)
+                        .expecting(Expected::Range {
+                            min: i64::MIN,
+                            max: i64::MAX,
+                        })
+                })?;
+        }
+        self.pos = end;
+        Ok(v)
+    }
+
+    /// Parses one of the given (full, abbreviated) name pairs ignoring case,
+    /// returns the index of the pair.
+    fn name<'n, I>(
+        &mut self,
+        names: I,
+        field: Field,
+        expected: Expected,
+    ) -> Result<usize, ParseError>
+    where
+        I: Iterator<Item = (&'n str, &'n str)>,
+    {
+        let rest = &self.s[self.pos..];
+        for (i, (full, abbr)) in names.enumerate() {
+            for name in &[full, abbr] {
+                if rest.len() >= name.len()
+                    && rest[..name.len()].eq_ignore_ascii_case(name.as_bytes())
+                {
+                    self.pos += name.len();
+                    return Ok(i);
+                }
+            }
+        }
+        Err(self.unexpected_name(field, expected))
+    }
+
+    /// Parses the longest of the given `names` ignoring case, returns the
+    /// index of the name. Names of the same length that match different
+    /// indices are ambiguous and rejected.
+    pub(crate) fn longest_name<'n, I>(
+        &mut self,
+        names: I,
+        field: Field,
+        expected: Expected,
+    ) -> Result<usize, ParseError>
+    where
+        I: Iterator<Item = &'n str>,
+    {
+        let rest = &self.s[self.pos..];
+        let mut best: Option<(usize, usize)> = None;
+        let mut ambiguous = false;
+        for (i, name) in names.enumerate() {
+            if name.is_empty()
+                || rest.len() < name.len()
+                || !rest[..name.len()].eq_ignore_ascii_case(name.as_bytes())
+            {
+                continue;
+            }
+            match best {
+                Some((_, len)) if len > name.len() => {}
+                Some((j, len)) if len == name.len() => ambiguous |= i != j,
+                _ => {
+                    best = Some((i, name.len()));
+                    ambiguous = false;
+                }
+            }
+        }
+        match best {
+            Some((i, len)) if !ambiguous => {
+                self.pos += len;
+                Ok(i)
+            }
+            _ => Err(self.unexpected_name(field, expected)),
+        }
+    }
+}
+
+/// Sets the parsed `value` to `slot`, a field parsed more than once must have
+/// the same value.
+pub(crate) fn set<T: PartialEq>(
+    slot: &mut Option<T>,
+    value: T,
+    field: Field,
+) -> Result<(), ParseError> {
+    match slot {
+        Some(v) if *v != value => Err(ParseError::new(ParseErrorKind::Inconsistent, Some(field))),
+        _ => {
+            *slot = Some(value);
+            Ok(())
+        }
+    }
+}
+
+/// Checks that `v` is in the range [`min`, `max`].
+pub(crate) fn check_range(
+    v: DiffType,
+    min: DiffType,
+    max: DiffType,
+    field: Field,
+) -> Result<DiffType, ParseError> {
+    if min <= v && v <= max {
+        Ok(v)
+    } else {
+        Err(ParseError::new(ParseErrorKind::OutOfRange, Some(field))
+            .expecting(Expected::Range { min, max }))
+    }
+}
+
+/// Normalizes the fields, the year overflowing is out of range.
+fn normalize(
+    y: YearType,
+    m: DiffType,
+    d: DiffType,
+    hh: DiffType,
+    mm: DiffType,
+    ss: DiffType,
+) -> Result<Fields, ParseError> {
+    Fields::checked_n_sec(y, m, d, hh, mm, ss)
+        .ok_or_else(|| ParseError::new(ParseErrorKind::OutOfRange, Some(Field::Year)))
+}
+
+/// Values parsed by the directives of a strftime-like pattern.
+#[derive(Debug, Default)]
+pub(crate) struct Parsed {
+    pub(crate) year: Option<YearType>,
+    century: Option<YearType>,
+    year_of_century: Option<DiffType>,
+    pub(crate) month: Option<DiffType>,
+    day: Option<DiffType>,
+    yearday: Option<DiffType>,
+    hour: Option<DiffType>,
+    minute: Option<DiffType>,
+    second: Option<DiffType>,
+    pub(crate) weekday: Option<Weekday>,
+    week_from_sunday: Option<DiffType>,
+    week_from_monday: Option<DiffType>,
+    iso_year: Option<YearType>,
+    iso_week: Option<DiffType>,
+    mode: ParseMode,
+    pub(crate) two_digit_year: TwoDigitYear,
+    /// Byte spans of the input each field is last parsed from.
+    spans: [Option<(usize, usize)>; 6],
+}
+
+impl Parsed {
+    pub(crate) fn new(mode: ParseMode) -> Self {
+        Parsed {
+            mode,
+            ..Parsed::default()
+        }
+    }
+
+    /// Checks the range of a field value that is normalized in
+    /// [ParseMode::Normalize].
+    fn check_field(
+        &self,
+        v: DiffType,
+        min: DiffType,
+        max: DiffType,
+        field: Field,
+    ) -> Result<DiffType, ParseError> {
+        match self.mode {
+            ParseMode::Strict => check_range(v, min, max, field),
+            ParseMode::Normalize => Ok(v),
+        }
+    }
+
+    /// Records that the `field` is parsed from the byte span [`start`, `end`),
+    /// which locates the errors of the field found by resolving.
+    pub(crate) fn set_span(&mut self, field: Field, start: usize, end: usize) {
+        self.spans[field as usize] = Some((start, end));
+    }
+
+    /// Parses the directive `spec`, which takes at least `min_width` digits,
+    /// and leaves `reserve` digits to following directives if it is not
+    /// fixed-width.
+    pub(crate) fn parse_spec(
+        &mut self,
+        sc: &mut Scanner<'_>,
+        spec: Spec,
+        min_width: usize,
+        reserve: usize,
+    ) -> Result<(), ParseError> {
+        let start = sc.pos();
+        self.parse_value(sc, spec, min_width, reserve)
+            .map_err(|e| e.at(start, sc.pos()))?;
+        self.set_span(spec.field(), start, sc.pos());
+        Ok(())
+    }
+
+    fn parse_value(
+        &mut self,
+        sc: &mut Scanner<'_>,
+        spec: Spec,
+        min_width: usize,
+        reserve: usize,
+    ) -> Result<(), ParseError> {
+        let field = spec.field();
+        let max_width = spec.width().unwrap_or(0);
+        match spec {
+            Spec::Year | Spec::YearPadded => {
+                let v = sc.signed(min_width, reserve, field)?;
+                set(&mut self.year, v, field)
+            }
+            Spec::IsoYear | Spec::IsoYearPadded => {
+                let v = sc.signed(min_width, reserve, field)?;
+                set(&mut self.iso_year, v, field)
+            }
+            Spec::YearExpanded => {
+                let negative = sc.sign(field)?;
+                let v = sc.unsigned(min_width, reserve, negative, field)?;
+                set(&mut self.year, v, field)
+            }
+            Spec::IsoYearExpanded => {
+                let negative = sc.sign(field)?;
+                let v = sc.unsigned(min_width, reserve, negative, field)?;
+                set(&mut self.iso_year, v, field)
+            }
+            Spec::Century => {
+                let v = sc.signed(min_width, reserve, field)?;
+                set(&mut self.century, v, field)
+            }
+            Spec::YearOfCentury => {
+                let v = sc.number(min_width, max_width, field)?;
+                set(&mut self.year_of_century, v, field)
+            }
+            Spec::Month => {
+                let v = self.check_field(sc.number(min_width, max_width, field)?, 1, 12, field)?;
+                set(&mut self.month, v, field)
+            }
+            Spec::MonthShort | Spec::MonthLong => {
+                let names = MONTH_NAMES.iter().map(|name| (*name, &name[..3]));
+                let i = sc.name(names, field, Expected::MonthName)?;
+                set(&mut self.month, i as DiffType + 1, field)
+            }
+            Spec::Day | Spec::DaySpacePadded => {
+                let max_width = if spec == Spec::DaySpacePadded && sc.s.get(sc.pos) == Some(&b' ') {
+                    sc.pos += 1;
+                    1
+                } else {
+                    max_width
+                };
+                let min_width = min_width.min(max_width);
+                let v = self.check_field(sc.number(min_width, max_width, field)?, 1, 31, field)?;
+                set(&mut self.day, v, field)
+            }
+            Spec::YearDay => {
+                let v = self.check_field(sc.number(min_width, max_width, field)?, 1, 366, field)?;
+                set(&mut self.yearday, v, field)
+            }
+            Spec::Hour => {
+                let v = self.check_field(sc.number(min_width, max_width, field)?, 0, 23, field)?;
+                set(&mut self.hour, v, field)
+            }
+            Spec::Minute => {
+                let v = self.check_field(sc.number(min_width, max_width, field)?, 0, 59, field)?;
+                set(&mut self.minute, v, field)
+            }
+            Spec::Second => {
+                let v = self.check_field(sc.number(min_width, max_width, field)?, 0, 59, field)?;
+                set(&mut self.second, v, field)
+            }
+            Spec::WeekdayShort | Spec::WeekdayLong => {
+                let names = WEEKDAYS.iter().map(|wd| (wd.long_name(), wd.short_name()));
+                let i = sc.name(names, field, Expected::WeekdayName)?;
+                set(&mut self.weekday, WEEKDAYS[i], field)
+            }
+            Spec::WeekdayFromMonday => {
+                let v = check_range(sc.number(min_width, max_width, field)?, 1, 7, field)?;
+                set(&mut self.weekday, WEEKDAYS[v as usize - 1], field)
+            }
+            Spec::WeekdayFromSunday => {
+                let v = check_range(sc.number(min_width, max_width, field)?, 0, 6, field)?;
+                set(&mut self.weekday, WEEKDAYS[(v as usize + 6) % 7], field)
+            }
+            Spec::WeekFromSunday => {
+                let v = check_range(sc.number(min_width, max_width, field)?, 0, 53, field)?;
+                set(&mut self.week_from_sunday, v, field)
+            }
+            Spec::WeekFromMonday => {
+                let v = check_range(sc.number(min_width, max_width, field)?, 0, 53, field)?;
+                set(&mut self.week_from_monday, v, field)
+            }
+            Spec::IsoWeek => {
+                // Week 0 is only valid in the minimum year, which is checked
+                // once the week-numbering year is resolved.
+                let v = check_range(sc.number(min_width, max_width, field)?, 0, 53, field)?;
+                set(&mut self.iso_week, v, field)
+            }
+        }
+    }
+
+    fn resolve_year(&self) -> Result<YearType, ParseError> {
+        let y = match (self.century, self.year_of_century) {
+            (Some(c), yy) => Some(
+                c.checked_mul(100)
+                    .and_then(|y| y.checked_add(yy.unwrap_or(0)))
+                    .ok_or_else(|| {
+                        ParseError::new(ParseErrorKind::OutOfRange, Some(Field::Year))
+                    })?,
+            ),
+            (None, Some(yy)) => Some(self.two_digit_year.resolve(yy)?),
+            (None, None) => None,
+        };
+        match (self.year, y) {
+            (Some(a), Some(b)) if a != b => Err(ParseError::new(
+                ParseErrorKind::Inconsistent,
+                Some(Field::Year),
+            )),
+            (Some(y), _) | (None, Some(y)) => Ok(y),
+            (None, None) => Ok(1970),
+        }
+    }
+
+    /// Resolves the parsed values into normalized fields, missing fields take
+    /// their default values.
+    ///
+    /// Errors are located at the span of the field involved if it is parsed.
+    pub(crate) fn resolve(&self) -> Result<Fields, ParseError> {
+        self.resolve_fields()
+            .map_err(|e| match e.field().and_then(|f| self.spans[f as usize]) {
+                Some((start, end)) => e.at(start, end),
+                None => e,
+            })
+    }
+
+    fn resolve_fields(&self) -> Result<Fields, ParseError> {
+        let week_date = self.resolve_week_date()?;
+        let (y, m, d) = match week_date {
+            Some(cd) => (cd.year(), cd.month() as DiffType, cd.day() as DiffType),
+            None => self.resolve_date()?,
+        };
+
+        let fields = normalize(
+            y,
+            m,
+            d,
+            self.hour.unwrap_or(0),
+            self.minute.unwrap_or(0),
+            self.second.unwrap_or(0),
+        )?;
+        // The ISO 8601 week date matches the date resolved from it, unless
+        // it is normalized.
+        self.check_weeks(CivilSecond::from_fields(fields), week_date.is_none())?;
+
+        Ok(fields)
+    }
+
+    /// Resolves the date from the ISO 8601 week date if neither month, day
+    /// nor day of year is parsed.
+    fn resolve_week_date(&self) -> Result<Option<CivilDay>, ParseError> {
+        let week = match self.iso_week {
+            Some(week) if self.month.is_none() && self.day.is_none() && self.yearday.is_none() => {
+                week
+            }
+            _ => return Ok(None),
+        };
+        let iso_year = match self.iso_year {
+            Some(iso_year) => iso_year,
+            None => self.resolve_year()?,
+        };
+        let weekday = self.weekday.unwrap_or(Weekday::Mon);
+        if week == 0 && iso_year != YearType::MIN {
+            return Err(
+                ParseError::new(ParseErrorKind::OutOfRange, Some(Field::Day))
+                    .expecting(Expected::Range { min: 1, max: 53 }),
+            );
+        }
+
+        let cd = CivilDay::checked_from_iso_week_date(iso_year, week, weekday)
+            .ok_or_else(|| ParseError::new(ParseErrorKind::OutOfRange, Some(Field::Year)))?;
+        if self.mode == ParseMode::Strict && iso_week(cd) != (iso_year, week as i32) {
+            // The year has no week 53.
+            return Err(
+                ParseError::new(ParseErrorKind::OutOfRange, Some(Field::Day))
+                    .expecting(Expected::Range { min: 1, max: 52 }),
+            );
+        }
+        let has_year =
+            self.year.is_some() || self.century.is_some() || self.year_of_century.is_some();
+        if self.iso_year.is_some() && has_year && self.resolve_year()? != cd.year() {
+            return Err(ParseError::new(
+                ParseErrorKind::Inconsistent,
+                Some(Field::Year),
+            ));
+        }
+
+        Ok(Some(cd))
+    }
+
+    /// Resolves the calendar date.
+    fn resolve_date(&self) -> Result<(YearType, DiffType, DiffType), ParseError> {
+        let y = self.resolve_year()?;
+        let strict = self.mode == ParseMode::Strict;
+        let (y, m, d) = match self.yearday {
+            Some(yd) => {
+                // The range is checked first, as a day beyond the maximum
+                // year does not fit.
+                let max = if core::is_leap_year(y) { 366 } else { 365 };
+                if strict && (yd < 1 || yd > max) {
+                    return Err(
+                        ParseError::new(ParseErrorKind::OutOfRange, Some(Field::Day))
+                            .expecting(Expected::Range { min: 1, max }),
+                    );
+                }
+                let f = normalize(y, 1, yd, 0, 0, 0)?;
+                if matches!(self.month, Some(m) if m != f.m as DiffType) {
+                    return Err(ParseError::new(
+                        ParseErrorKind::Inconsistent,
+                        Some(Field::Month),
+                    ));
+                }
+                if matches!(self.day, Some(d) if d != f.d as DiffType) {
+                    return Err(ParseError::new(
+                        ParseErrorKind::Inconsistent,
+                        Some(Field::Day),
+                    ));
+                }
+                (f.y, f.m as DiffType, f.d as DiffType)
+            }
+            None => (y, self.month.unwrap_or(1), self.day.unwrap_or(1)),
+        };
+        if strict {
+            let max = core::days_per_month(y, m as MonthType);
+            if d > max {
+                return Err(
+                    ParseError::new(ParseErrorKind::OutOfRange, Some(Field::Day))
+                        .expecting(Expected::Range { min: 1, max }),
+                );
+            }
+        }
+
+        Ok((y, m, d))
+    }
+
+    /// Checks that the weekday and weeks of year parsed match the date, the
+    /// ISO 8601 week and week-numbering year are checked only if `check_iso`.
+    fn check_weeks(&self, cs: CivilSecond, check_iso: bool) -> Result<(), ParseError> {
+        let inconsistent = ParseError::new(ParseErrorKind::Inconsistent, Some(Field::Day));
+        if matches!(self.weekday, Some(wd) if wd != cs.weekday()) {
+            return Err(inconsistent);
+        }
+        let week = week_of_year(cs, Weekday::Sun) as DiffType;
+        if matches!(self.week_from_sunday, Some(w) if w != week) {
+            return Err(inconsistent);
+        }
+        let week = week_of_year(cs, Weekday::Mon) as DiffType;
+        if matches!(self.week_from_monday, Some(w) if w != week) {
+            return Err(inconsistent);
+        }
+        if !check_iso || (self.iso_week.is_none() && self.iso_year.is_none()) {
+            return Ok(());
+        }
+        let (iso_year, iso_week) = iso_week(CivilDay::from_second(cs));
+        if matches!(self.iso_week, Some(w) if w != iso_week as DiffType) {
+            return Err(inconsistent);
+        }
+        if matches!(self.iso_year, Some(y) if y != iso_year) {
+            return Err(ParseError::new(
+                ParseErrorKind::Inconsistent,
+                Some(Field::Year),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Options of [`parse_from_str_with()`](crate::CivilSecond::parse_from_str_with):
+/// how fields out of their ranges are handled and how two-digit years are
+/// resolved.
+///
+/// ```rust
+/// use civil_time::{CivilDay, ParseMode, ParseOptions, TwoDigitYear};
+///
+/// let options = ParseOptions::new()
+///     .mode(ParseMode::Normalize)
+///     .two_digit_year(TwoDigitYear::Pivot(1950));
+/// let d = CivilDay::parse_from_str_with("49-10-32", "%y-%m-%d", options).unwrap();
+/// assert_eq!(CivilDay::new(2049, 11, 1), d);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ParseOptions {
+    /// Whether numeric directives must be padded to the widths they are
+    /// formatted in.
+    pub(crate) exact_width: bool,
+    /// How fields out of their ranges are handled.
+    pub(crate) mode: ParseMode,
+    /// How two-digit years are resolved.
+    pub(crate) two_digit_year: TwoDigitYear,
+}
+
+impl ParseOptions {
+    /// Creates the default options, [ParseMode::Strict] and
+    /// [`TwoDigitYear::Pivot(1969)`](TwoDigitYear::Pivot).
+    pub const fn new() -> Self {
+        ParseOptions {
+            exact_width: false,
+            mode: ParseMode::Strict,
+            two_digit_year: TwoDigitYear::POSIX,
+        }
+    }
+
+    /// Sets how fields out of their ranges are handled.
+    pub const fn mode(self, mode: ParseMode) -> Self {
+        ParseOptions { mode, ..self }
+    }
+
+    /// Sets how two-digit years are resolved.
+    pub const fn two_digit_year(self, two_digit_year: TwoDigitYear) -> Self {
+        ParseOptions {
+            two_digit_year,
+            ..self
+        }
+    }
+}
+
+/// Parses the `input` by the items of a strftime-like pattern.
+///
+/// Fixed-width numeric directives take as many digits as their width, but
+/// accept fewer digits unless they are followed by another numeric directive
+/// or `options` requires exact widths. Variable-width directives (such as
+/// `%Y`) leave enough digits to the fixed-width directives that immediately
+/// follow them, so "%Y%m%d" parses "20150203".
+pub(crate) fn parse_items(
+    input: &str,
+    items: &[Item<'_>],
+    options: ParseOptions,
+) -> Result<Fields, ParseError> {
+    scan_items(input, items, options)
+        .and_then(|parsed| parsed.resolve())
+        .map_err(|e| e.at(0, input.len()))
+}
+
+/// Same as [parse_items()], but returns the parsed values without resolving
+/// them.
+pub(crate) fn scan_items(
+    input: &str,
+    items: &[Item<'_>],
+    options: ParseOptions,
+) -> Result<Parsed, ParseError> {
+    let mut sc = Scanner::new(input);
+    let mut parsed = Parsed::new(options.mode);
+    parsed.two_digit_year = options.two_digit_year;
+    for (i, item) in items.iter().enumerate() {
+        match *item {
+            Item::Literal(lit) => sc.literal(lit)?,
+            Item::Spec(spec) => {
+                // Widths of the fixed-width directives that immediately follow.
+                let mut following = items[i + 1..].iter().map_while(|item| match item {
+                    Item::Spec(spec) => spec.width(),
+                    Item::Literal(_) => None,
+                });
+                let (min_width, reserve) = match following.next() {
+                    Some(width) => (spec.width().unwrap_or(1), width + following.sum::<usize>()),
+                    None => (1, 0),
+                };
+                let min_width = if options.exact_width {
+                    spec.padded_width()
+                } else {
+                    min_width
+                };
+                parsed.parse_spec(&mut sc, spec, min_width, reserve)?;
+            }
+        }
+    }
+    if !sc.at_end() {
+        return Err(sc.trailing());
+    }
+
+    Ok(parsed)
+}
+
+macro_rules! impl_parse {
+    ($Type: ident, $n: expr) => {
+        impl $Type {
+            /// Parses a civil time from `input` by the strftime-like `pattern`,
+            /// which supports the same directives as [`format()`](Self::format).
+            ///
+            /// Fields not mentioned by the pattern take their default values
+            /// (1970-01-01 00:00:00), and fields finer than the alignment are
+            /// dropped. Names are matched ignoring case, and a field out of its
+            /// range or contradicting other fields is an error. See
+            /// [`parse_from_str_with()`](Self::parse_from_str_with) to
+            /// normalize fields out of their ranges instead.
+            ///
+            /// ```rust
+            /// use civil_time::CivilDay;
+            ///
+            /// let d = CivilDay::parse_from_str("03 Feb 2015", "%d %b %Y").unwrap();
+            /// assert_eq!(CivilDay::new(2015, 2, 3), d);
+            /// ```
+            pub fn parse_from_str(input: &str, pattern: &str) -> Result<Self, ParseError> {
+                Self::parse_from_str_with(input, pattern, ParseOptions::new())
+            }
+
+            /// Same as [`parse_from_str()`](Self::parse_from_str), but
+            /// handles fields out of their ranges and resolves two-digit
+            /// years by `options`.
+            ///
+            /// ```rust
+            /// use civil_time::{CivilDay, ParseMode, ParseOptions, TwoDigitYear};
+            ///
+            /// let options = ParseOptions::new().two_digit_year(TwoDigitYear::Pivot(1950));
+            /// let d = CivilDay::parse_from_str_with("03/02/49", "%d/%m/%y", options).unwrap();
+            /// assert_eq!(CivilDay::new(2049, 2, 3), d);
+            ///
+            /// let options = ParseOptions::new().mode(ParseMode::Normalize);
+            /// let d = CivilDay::parse_from_str_with("2016-10-32", "%Y-%m-%d", options).unwrap();
+            /// assert_eq!(CivilDay::new(2016, 11, 1), d);
+            /// ```
+            pub fn parse_from_str_with(
+                input: &str,
+                pattern: &str,
+                options: ParseOptions,
+            ) -> Result<Self, ParseError> {
+                let items: Vec<_> = Items::new(pattern).collect();
+                parse_items(input, &items, options).map(Self::from_fields)
+            }
+        }
+
+        impl FromStr for $Type {
+            type Err = ParseError;
+
+            /// Parses a string in the layout printed by the `Debug` impl, in
+            /// [ParseMode::Strict].
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                parse_debug(s.as_bytes(), $n).map(Self::from_fields)
+            }
+        }
+    };
+}
+
+impl_parse!(CivilSecond, 6);
+impl_parse!(CivilMinute, 5);
+impl_parse!(CivilHour, 4);
+impl_parse!(CivilDay, 3);
+impl_parse!(CivilMonth, 2);
+impl_parse!(CivilYear, 1);
+
+/// Dates of two-digit years, months and days, e.g. "150203".
+const YYMMDD: [Item<'static>; 3] = [
+    Item::Spec(Spec::YearOfCentury),
+    Item::Spec(Spec::Month),
+    Item::Spec(Spec::Day),
+];
+
+impl CivilDay {
+    /// Parses a date of 6 digits "YYMMDD", resolving the two-digit year by
+    /// `two_digit_year`.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilDay, CivilYear, ParseErrorKind, TwoDigitYear};
+    ///
+    /// let d = CivilDay::from_yymmdd("150203", TwoDigitYear::default()).unwrap();
+    /// assert_eq!(CivilDay::new(2015, 2, 3), d);
+    ///
+    /// let policy = TwoDigitYear::sliding(CivilYear::new(2015), 0).unwrap();
+    /// let d = CivilDay::from_yymmdd("160203", policy).unwrap();
+    /// assert_eq!(CivilDay::new(1916, 2, 3), d);
+    ///
+    /// let e = CivilDay::from_yymmdd("150203", TwoDigitYear::Strict).unwrap_err();
+    /// assert_eq!(ParseErrorKind::Ambiguous, e.kind());
+    /// ```
+    pub fn from_yymmdd(input: &str, two_digit_year: TwoDigitYear) -> Result<Self, ParseError> {
+        let options = ParseOptions {
+            exact_width: true,
+            two_digit_year,
+            ..ParseOptions::default()
+        };
+        parse_items(input, &YYMMDD, options).map(Self::from_fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::expect_eq;
+    use crate::FormatDescription;
+
+    fn expect_err<T: FromStr<Err = ParseError> + fmt::Debug>(
+        s: &str,
+        kind: ParseErrorKind,
+        field: Option<Field>,
+    ) {
+        let e = s.parse::<T>().unwrap_err();
+        assert_eq!(kind, e.kind(), "input: {}", s);
+        assert_eq!(field, e.field(), "input: {}", s);
+    }
+
+    #[test]
+    fn test_from_str() {
+        expect_eq(
+            "2015-02-03T04:05:06",
+            "2015-02-03T04:05:06".parse::<CivilSecond>().unwrap(),
+        );
+        expect_eq(
+            "2015-02-03T04:05",
+            "2015-02-03T04:05".parse::<CivilMinute>().unwrap(),
+        );
+        expect_eq(
+            "2015-02-03T04",
+            "2015-02-03T04".parse::<CivilHour>().unwrap(),
+        );
+        expect_eq("2015-02-03", "2015-02-03".parse::<CivilDay>().unwrap());
+        expect_eq("2015-02", "2015-02".parse::<CivilMonth>().unwrap());
+        expect_eq("2015", "2015".parse::<CivilYear>().unwrap());
+
+        expect_eq("-1-12-31", "-1-12-31".parse::<CivilDay>().unwrap());
+        expect_eq("2016-02-29", "2016-02-29".parse::<CivilDay>().unwrap());
+        expect_eq("2015", "0002015".parse::<CivilYear>().unwrap());
+    }
+
+    #[test]
+    fn test_from_str_round_trip() {
+        macro_rules! check_round_trip {
+            ($Type: ident, $v: expr) => {{
+                let v = $v;
+                assert_eq!(v, format!("{:?}", v).parse::<$Type>().unwrap());
+            }};
+        }
+
+        macro_rules! check_all {
+            ($v: expr) => {{
+                let v = $v;
+                check_round_trip!(CivilSecond, v);
+                check_round_trip!(CivilMinute, CivilMinute::from(v));
+                check_round_trip!(CivilHour, CivilHour::from(v));
+                check_round_trip!(CivilDay, CivilDay::from(v));
+                check_round_trip!(CivilMonth, CivilMonth::from(v));
+                check_round_trip!(CivilYear, CivilYear::from(v));
+            }};
+        }
+
+        check_all!(CivilSecond::default());
+        check_all!(CivilSecond::new(2015, 2, 3, 4, 5, 6));
+        check_all!(CivilSecond::new(2016, 2, 29, 23, 59, 59));
+        check_all!(CivilSecond::new(-1, 1, 1, 0, 0, 0));
+        check_all!(CivilSecond::new(2016, -42, 122, 99, -147, 4949));
+        check_all!(CivilSecond::MAX);
+        check_all!(CivilSecond::MIN);
+    }
+
+    #[test]
+    fn test_from_str_error() {
+        use ParseErrorKind::*;
+
+        expect_err::<CivilYear>("", TooShort, Some(Field::Year));
+        expect_err::<CivilYear>("-", TooShort, Some(Field::Year));
+        expect_err::<CivilYear>("+2015", Invalid, Some(Field::Year));
+        expect_err::<CivilYear>("9223372036854775808", OutOfRange, Some(Field::Year));
+        expect_err::<CivilYear>("-9223372036854775809", OutOfRange, Some(Field::Year));
+        expect_err::<CivilYear>("2015-01", TooLong, None);
+
+        expect_err::<CivilMonth>("2015", TooShort, Some(Field::Month));
+        expect_err::<CivilMonth>("2015/02", Invalid, Some(Field::Month));
+        expect_err::<CivilMonth>("2015-2", TooShort, Some(Field::Month));
+        expect_err::<CivilMonth>("2015-13", OutOfRange, Some(Field::Month));
+        expect_err::<CivilMonth>("2015-00", OutOfRange, Some(Field::Month));
+
+        expect_err::<CivilDay>("2015-02-29", OutOfRange, Some(Field::Day));
+        expect_err::<CivilDay>("2015-02-3x", Invalid, Some(Field::Day));
+        expect_err::<CivilDay>("2015-02-03T04", TooLong, None);
+
+        expect_err::<CivilHour>("2015-02-03 04", Invalid, Some(Field::Hour));
+        expect_err::<CivilHour>("2015-02-03T24", OutOfRange, Some(Field::Hour));
+        expect_err::<CivilMinute>("2015-02-03T04:60", OutOfRange, Some(Field::Minute));
+        expect_err::<CivilSecond>("2015-02-03T04:05:60", OutOfRange, Some(Field::Second));
+        expect_err::<CivilSecond>("2015-02-03T04:05", TooShort, Some(Field::Second));
+    }
+
+    #[test]
+    fn test_error_display() {
+        let e = "2015-13".parse::<CivilMonth>().unwrap_err();
+        assert_eq!("month out of range", e.to_string());
+        let e = "2015-02-03T04:05".parse::<CivilSecond>().unwrap_err();
+        assert_eq!("premature end of input, expecting second", e.to_string());
+        let e = "2015-02-03 04".parse::<CivilHour>().unwrap_err();
+        assert_eq!("invalid hour", e.to_string());
+        let e = "2015!".parse::<CivilYear>().unwrap_err();
+        assert_eq!("trailing input", e.to_string());
+    }
+
+    #[test]
+    fn test_parse_from_str() {
+        let ss = CivilSecond::parse_from_str("2015-02-03 04:05:06", "%Y-%m-%d %H:%M:%S").unwrap();
+        expect_eq("2015-02-03T04:05:06", ss);
+        let d = CivilDay::parse_from_str("03 Feb 2015", "%d %b %Y").unwrap();
+        expect_eq("2015-02-03", d);
+        let d = CivilDay::parse_from_str("2015/034", "%Y/%j").unwrap();
+        expect_eq("2015-02-03", d);
+        let d = CivilDay::parse_from_str("tuesday, FEBRUARY  3, 15", "%A, %B %e, %y").unwrap();
+        expect_eq("2015-02-03", d);
+        let d = CivilDay::parse_from_str("2015-2-3", "%Y-%m-%d").unwrap();
+        expect_eq("2015-02-03", d);
+        let d = CivilDay::parse_from_str("-1-12-31", "%Y-%m-%d").unwrap();
+        expect_eq("-1-12-31", d);
+        let d = CivilDay::parse_from_str("100% 2015-02-03", "100%% %Y-%m-%d").unwrap();
+        expect_eq("2015-02-03", d);
+        let d = CivilDay::parse_from_str("%F-02-03", "%F-%m-%d").unwrap();
+        expect_eq("1970-02-03", d);
+    }
+
+    #[test]
+    fn test_parse_from_str_mode() {
+        let normalize = ParseOptions::new().mode(ParseMode::Normalize);
+        let d = CivilDay::parse_from_str_with("2016-10-32", "%Y-%m-%d", normalize).unwrap();
+        expect_eq("2016-11-01", d);
+        let ss =
+            CivilSecond::parse_from_str_with("2016-12-31 24:00:60", "%Y-%m-%d %H:%M:%S", normalize)
+                .unwrap();
+        expect_eq("2017-01-01T00:01:00", ss);
+        let e = CivilDay::parse_from_str_with("9223372036854775807-12-32", "%Y-%m-%d", normalize)
+            .unwrap_err();
+        assert_eq!(ParseErrorKind::OutOfRange, e.kind());
+        assert_eq!(Some(Field::Year), e.field());
+
+        // Strict is the default, as in parse_from_str() and FromStr.
+        assert_eq!(ParseOptions::default(), ParseOptions::new());
+        let e = CivilDay::parse_from_str_with("2016-10-32", "%Y-%m-%d", ParseOptions::new())
+            .unwrap_err();
+        assert_eq!(ParseErrorKind::OutOfRange, e.kind());
+        assert_eq!(Some(Field::Day), e.field());
+        expect_err::<CivilDay>("2016-10-32", ParseErrorKind::OutOfRange, Some(Field::Day));
+    }
+
+    #[test]
+    fn test_parse_from_str_defaults() {
+        // Missing fields default to 1970-01-01 00:00:00.
+        let ss = CivilSecond::parse_from_str("04:05", "%H:%M").unwrap();
+        expect_eq("1970-01-01T04:05:00", ss);
+        let ss = CivilSecond::parse_from_str("2015", "%Y").unwrap();
+        expect_eq("2015-01-01T00:00:00", ss);
+        let ss = CivilSecond::parse_from_str("", "").unwrap();
+        expect_eq("1970-01-01T00:00:00", ss);
+    }
+
+    #[test]
+    fn test_parse_from_str_alignment() {
+        let input = "2015-02-03 04:05:06";
+        let pattern = "%Y-%m-%d %H:%M:%S";
+        expect_eq(
+            "2015-02-03T04:05",
+            CivilMinute::parse_from_str(input, pattern).unwrap(),
+        );
+        expect_eq(
+            "2015-02-03T04",
+            CivilHour::parse_from_str(input, pattern).unwrap(),
+        );
+        expect_eq(
+            "2015-02-03",
+            CivilDay::parse_from_str(input, pattern).unwrap(),
+        );
+        expect_eq(
+            "2015-02",
+            CivilMonth::parse_from_str(input, pattern).unwrap(),
+        );
+        expect_eq("2015", CivilYear::parse_from_str(input, pattern).unwrap());
+    }
+
+    #[test]
+    fn test_parse_from_str_adjacent_numbers() {
+        let ss = CivilSecond::parse_from_str("20150203T040506", "%Y%m%dT%H%M%S").unwrap();
+        expect_eq("2015-02-03T04:05:06", ss);
+        let d = CivilDay::parse_from_str("123450203", "%Y%m%d").unwrap();
+        expect_eq("12345-02-03", d);
+        let d = CivilDay::parse_from_str("-10203", "%Y%m%d").unwrap();
+        expect_eq("-1-02-03", d);
+        let d = CivilDay::parse_from_str("2015034", "%Y%j").unwrap();
+        expect_eq("2015-02-03", d);
+
+        let e = CivilDay::parse_from_str("0203", "%Y%m%d").unwrap_err();
+        assert_eq!(ParseErrorKind::TooShort, e.kind());
+        assert_eq!(Some(Field::Year), e.field());
+        let e = CivilDay::parse_from_str("2015023", "%Y%m%d").unwrap_err();
+        assert_eq!(ParseErrorKind::OutOfRange, e.kind());
+        assert_eq!(Some(Field::Month), e.field());
+    }
+
+    #[test]
+    fn test_parse_from_str_week_date() {
+        let pattern = "%G-W%V-%u";
+        expect_eq(
+            "2014-12-29",
+            CivilDay::parse_from_str("2015-W01-1", pattern).unwrap(),
+        );
+        expect_eq(
+            "2016-01-03",
+            CivilDay::parse_from_str("2015-W53-7", pattern).unwrap(),
+        );
+        expect_eq(
+            "2015-02-03T04:05",
+            CivilMinute::parse_from_str("2015-W06-2 04:05", "%G-W%V-%u %H:%M").unwrap(),
+        );
+
+        // The weekday defaults to Monday, and %Y is used without %G.
+        expect_eq(
+            "2014-12-29",
+            CivilDay::parse_from_str("2015 01", "%G %V").unwrap(),
+        );
+        expect_eq(
+            "2015-02-05",
+            CivilDay::parse_from_str("2015 06 Thu", "%Y %V %a").unwrap(),
+        );
+
+        // The calendar year must match the date.
+        expect_eq(
+            "2014-12-29",
+            CivilDay::parse_from_str("2014 2015-W01-1", "%Y %G-W%V-%u").unwrap(),
+        );
+        let e = CivilDay::parse_from_str("2015 2015-W01-1", "%Y %G-W%V-%u").unwrap_err();
+        assert_eq!(ParseErrorKind::Inconsistent, e.kind());
+        assert_eq!(Some(Field::Year), e.field());
+
+        let e = CivilDay::parse_from_str("2014-W53-1", pattern).unwrap_err();
+        assert_eq!(ParseErrorKind::OutOfRange, e.kind());
+        assert_eq!(Some(Field::Day), e.field());
+
+        // Month and day take precedence and are checked against the week.
+        expect_eq(
+            "2015-02-03",
+            CivilDay::parse_from_str("2015-02-03 W06", "%Y-%m-%d W%V").unwrap(),
+        );
+        let e = CivilDay::parse_from_str("2015-02-03 W07", "%Y-%m-%d W%V").unwrap_err();
+        assert_eq!(ParseErrorKind::Inconsistent, e.kind());
+    }
+
+    #[test]
+    fn test_parse_from_str_years() {
+        let pattern = "%y-%m-%d";
+        expect_eq(
+            "2000-01-01",
+            CivilDay::parse_from_str("00-01-01", pattern).unwrap(),
+        );
+        expect_eq(
+            "2068-01-01",
+            CivilDay::parse_from_str("68-01-01", pattern).unwrap(),
+        );
+        expect_eq(
+            "1969-01-01",
+            CivilDay::parse_from_str("69-01-01", pattern).unwrap(),
+        );
+        expect_eq(
+            "1999-01-01",
+            CivilDay::parse_from_str("99-01-01", pattern).unwrap(),
+        );
+
+        let y = CivilYear::parse_from_str("1915", "%C%y").unwrap();
+        expect_eq("1915", y);
+        let y = CivilYear::parse_from_str("-1 99", "%C %y").unwrap();
+        expect_eq("-1", y);
+        let y = CivilYear::parse_from_str("2015 20 15", "%Y %C %y").unwrap();
+        expect_eq("2015", y);
+        let e = CivilYear::parse_from_str("2015 19 15", "%Y %C %y").unwrap_err();
+        assert_eq!(ParseErrorKind::Inconsistent, e.kind());
+        assert_eq!(Some(Field::Year), e.field());
+
+        let y = CivilYear::parse_from_str("+9223372036854775807", "%Y").unwrap();
+        assert_eq!(CivilYear::MAX, y);
+        let y = CivilYear::parse_from_str("-9223372036854775808", "%Y").unwrap();
+        assert_eq!(CivilYear::MIN, y);
+        let e = CivilYear::parse_from_str("9223372036854775808", "%Y").unwrap_err();
+        assert_eq!(ParseErrorKind::OutOfRange, e.kind());
+        let d = CivilDay::parse_from_str("9223372036854775807/365", "%Y/%j").unwrap();
+        assert_eq!(CivilDay::MAX, d);
+    }
+
+    #[test]
+    fn test_two_digit_year() {
+        let sliding = |reference, future| TwoDigitYear::sliding(CivilYear::new(reference), future);
+        let table = [
+            (TwoDigitYear::default(), 68, 2068),
+            (TwoDigitYear::default(), 69, 1969),
+            (TwoDigitYear::Pivot(2000), 0, 2000),
+            (TwoDigitYear::Pivot(2000), 99, 2099),
+            (TwoDigitYear::Pivot(1950), 49, 2049),
+            (TwoDigitYear::Pivot(1950), 50, 1950),
+            (TwoDigitYear::Pivot(-150), 49, -51),
+            (TwoDigitYear::Pivot(-150), 50, -150),
+            (sliding(2015, 20).unwrap(), 35, 2035),
+            (sliding(2015, 20).unwrap(), 36, 1936),
+            (sliding(2015, 0).unwrap(), 15, 2015),
+            (sliding(2015, 0).unwrap(), 16, 1916),
+            (sliding(2015, 99).unwrap(), 14, 2114),
+            (sliding(2015, 99).unwrap(), 15, 2015),
+        ];
+        for (policy, yy, expect) in table.iter() {
+            assert_eq!(Ok(*expect), policy.resolve(*yy), "{:?} {}", policy, yy);
+        }
+
+        let e = TwoDigitYear::Strict.resolve(15).unwrap_err();
+        assert_eq!(ParseErrorKind::Ambiguous, e.kind());
+        assert_eq!(Some(Field::Year), e.field());
+        assert_eq!("ambiguous year", e.to_string());
+        let e = TwoDigitYear::Pivot(YearType::MAX).resolve(99).unwrap_err();
+        assert_eq!(ParseErrorKind::OutOfRange, e.kind());
+        let e = TwoDigitYear::Pivot(YearType::MIN).resolve(0).unwrap_err();
+        assert_eq!(ParseErrorKind::OutOfRange, e.kind());
+        // Bad windows are rejected on construction rather than clamped.
+        assert_eq!(None, sliding(2015, 100));
+        assert_eq!(None, sliding(2015, u8::MAX));
+        assert_eq!(None, sliding(YearType::MIN, 0));
+        assert_eq!(None, sliding(YearType::MIN + 98, 0));
+        assert_eq!(
+            Some(TwoDigitYear::Pivot(YearType::MIN)),
+            sliding(YearType::MIN + 99, 0)
+        );
+        assert_eq!(
+            Some(TwoDigitYear::Pivot(YearType::MAX)),
+            sliding(YearType::MAX, 99)
+        );
+
+        let options = ParseOptions::new().two_digit_year(TwoDigitYear::Pivot(1950));
+        let d = CivilDay::parse_from_str_with("49-02-03", "%y-%m-%d", options).unwrap();
+        expect_eq("2049-02-03", d);
+        let options = ParseOptions::new().two_digit_year(TwoDigitYear::Strict);
+        let e = CivilDay::parse_from_str_with("49-02-03", "%y-%m-%d", options).unwrap_err();
+        assert_eq!(ParseErrorKind::Ambiguous, e.kind());
+        assert_eq!(0..2, e.span());
+
+        // The century takes precedence over the policy.
+        let desc = FormatDescription::compile("%C%y").unwrap();
+        let desc = desc.two_digit_year(TwoDigitYear::Strict);
+        expect_eq("1915", desc.parse::<CivilYear>("1915").unwrap());
+    }
+
+    #[test]
+    fn test_from_yymmdd() {
+        let policy = TwoDigitYear::Pivot(1950);
+        expect_eq(
+            "2049-12-31",
+            CivilDay::from_yymmdd("491231", policy).unwrap(),
+        );
+        expect_eq(
+            "1950-01-01",
+            CivilDay::from_yymmdd("500101", policy).unwrap(),
+        );
+
+        let expect_err = |input: &str, kind: ParseErrorKind, field: Option<Field>| {
+            let e = CivilDay::from_yymmdd(input, policy).unwrap_err();
+            assert_eq!(kind, e.kind(), "input: {}", input);
+            assert_eq!(field, e.field(), "input: {}", input);
+        };
+        expect_err("", ParseErrorKind::TooShort, Some(Field::Year));
+        expect_err("15023", ParseErrorKind::TooShort, Some(Field::Day));
+        expect_err("1502034", ParseErrorKind::TooLong, None);
+        expect_err("15-2-3", ParseErrorKind::Invalid, Some(Field::Month));
+        expect_err("151303", ParseErrorKind::OutOfRange, Some(Field::Month));
+        expect_err("150229", ParseErrorKind::OutOfRange, Some(Field::Day));
+    }
+
+    #[test]
+    fn test_parse_from_str_error() {
+        use ParseErrorKind::*;
+
+        let check = |input: &str, pattern: &str, kind, field| {
+            let e = CivilSecond::parse_from_str(input, pattern).unwrap_err();
+            assert_eq!(kind, e.kind(), "input: {}, pattern: {}", input, pattern);
+            assert_eq!(field, e.field(), "input: {}, pattern: {}", input, pattern);
+        };
+
+        check("2015-13-01", "%Y-%m-%d", OutOfRange, Some(Field::Month));
+        check("2015-02-29", "%Y-%m-%d", OutOfRange, Some(Field::Day));
+        check("2015-02-00", "%Y-%m-%d", OutOfRange, Some(Field::Day));
+        check("24:00", "%H:%M", OutOfRange, Some(Field::Hour));
+        check("00:60", "%H:%M", OutOfRange, Some(Field::Minute));
+        check("00:00:60", "%H:%M:%S", OutOfRange, Some(Field::Second));
+        check("2015/366", "%Y/%j", OutOfRange, Some(Field::Day));
+        check(
+            "9223372036854775807/366",
+            "%Y/%j",
+            OutOfRange,
+            Some(Field::Day),
+        );
+        check("2015-02-03", "%Y-%m", TooLong, None);
+        check("2015-02", "%Y-%m-%d", TooShort, None);
+        check("2015-02-", "%Y-%m-%d", TooShort, Some(Field::Day));
+        check("2015/02/03", "%Y-%m-%d", Invalid, None);
+        check("2015-Fab-03", "%Y-%b-%d", Invalid, Some(Field::Month));
+        check("2015-02-x3", "%Y-%m-%d", Invalid, Some(Field::Day));
+        // The unknown directive %F is literal text.
+        check("100% 2015-02-03", "100%% %F-%m-%d", Invalid, None);
+
+        // Inconsistent fields.
+        check(
+            "Wed 2015-02-03",
+            "%a %Y-%m-%d",
+            Inconsistent,
+            Some(Field::Day),
+        );
+        check(
+            "3 2015-02-03",
+            "%u %Y-%m-%d",
+            Inconsistent,
+            Some(Field::Day),
+        );
+        check(
+            "2015-02-03 033",
+            "%Y-%m-%d %j",
+            Inconsistent,
+            Some(Field::Day),
+        );
+        check(
+            "2015-03-03 034",
+            "%Y-%m-%d %j",
+            Inconsistent,
+            Some(Field::Month),
+        );
+        check(
+            "2015-02-03 02",
+            "%Y-%m-%d %d",
+            Inconsistent,
+            Some(Field::Day),
+        );
+        check(
+            "2015-02-03 06",
+            "%Y-%m-%d %U",
+            Inconsistent,
+            Some(Field::Day),
+        );
+        check(
+            "2015-02-03 06",
+            "%Y-%m-%d %W",
+            Inconsistent,
+            Some(Field::Day),
+        );
+        check(
+            "2015-02-03 05",
+            "%Y-%m-%d %V",
+            Inconsistent,
+            Some(Field::Day),
+        );
+        check(
+            "2014-12-29 2014",
+            "%Y-%m-%d %G",
+            Inconsistent,
+            Some(Field::Year),
+        );
+    }
+
+    #[test]
+    fn test_parse_error_span() {
+        let check = |input: &str, pattern: &str, span: Range<usize>, expected| {
+            let e = CivilSecond::parse_from_str(input, pattern).unwrap_err();
+            assert_eq!(span, e.span(), "input: {}, pattern: {}", input, pattern);
+            assert_eq!(
+                expected,
+                e.expected(),
+                "input: {}, pattern: {}",
+                input,
+                pattern
+            );
+        };
+
+        check(
+            "2015-13-01",
+            "%Y-%m-%d",
+            5..7,
+            Some(Expected::Range { min: 1, max: 12 }),
+        );
+        check(
+            "2016-02-30",
+            "%Y-%m-%d",
+            8..10,
+            Some(Expected::Range { min: 1, max: 29 }),
+        );
+        check(
+            "24:00",
+            "%H:%M",
+            0..2,
+            Some(Expected::Range { min: 0, max: 23 }),
+        );
+        check("2015/02/03", "%Y-%m-%d", 4..5, Some(Expected::Char('-')));
+        // Unknown directives are literal text, which the input must match.
+        check(
+            "100% 2015-02-03",
+            "100%% %F-%m-%d",
+            5..6,
+            Some(Expected::Char('%')),
+        );
+        check("2015-02-x3", "%Y-%m-%d", 8..9, Some(Expected::Digit));
+        check("2015-Fab-03", "%Y-%b-%d", 5..8, Some(Expected::MonthName));
+        check("Tus 2015", "%a %Y", 0..3, Some(Expected::WeekdayName));
+        check("2015-02-03xyz", "%Y-%m-%d", 10..13, Some(Expected::End));
+        check("2015-02-", "%Y-%m-%d", 8..8, Some(Expected::Digit));
+        // Inconsistent fields are located at the field of the error.
+        check("Wed 2015-02-03", "%a %Y-%m-%d", 12..14, None);
+        check("2015-03-03 034", "%Y-%m-%d %j", 5..7, None);
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let check = |input: &str, pattern: &str, expected: &str| {
+            let e = CivilSecond::parse_from_str(input, pattern).unwrap_err();
+            assert_eq!(
+                expected,
+                e.diagnostic(input).to_string(),
+                "input: {}",
+                input
+            );
+        };
+
+        check(
+            "2015-13-01",
+            "%Y-%m-%d",
+            "month out of range at byte 5, expected a value from 1 to 12\n\
+             2015-13-01\n     ^^",
+        );
+        check(
+            "2015-02-",
+            "%Y-%m-%d",
+            "premature end of input, expecting day at byte 8, expected a digit\n\
+             2015-02-\n        ^",
+        );
+        check(
+            "2015\t02\t03 04",
+            "%Y\t%m\t%d %H:%M",
+            "premature end of input at byte 13, expected ':'\n\
+             2015\t02\t03 04\n    \t  \t     ^",
+        );
+        check(
+            "2015-02-03\n04:61",
+            "%Y-%m-%d\n%H:%M",
+            "minute out of range at byte 14, expected a value from 0 to 59\n\
+             04:61\n   ^^",
+        );
+        check(
+            "2015-02-03\u{e9}",
+            "%Y-%m-%d",
+            "trailing input at byte 10, expected end of input\n\
+             2015-02-03\u{e9}\n          ^",
+        );
+    }
+
+    #[test]
+    fn test_parse_from_str_round_trip() {
+        let pattern = "%a %A %b %B %C %y %Y %m %d %e %j %H %M %S %u %w %U %W %G %V";
+        let start = CivilDay::new(2013, 12, 1);
+        for i in 0..(365 * 3) {
+            let ss = CivilSecond::from(start + i) + 3723;
+            let s = ss.format(pattern).to_string();
+            assert_eq!(
+                ss,
+                CivilSecond::parse_from_str(&s, pattern).unwrap(),
+                "{}",
+                s
+            );
+        }
+    }
+}
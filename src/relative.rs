@@ -0,0 +1,664 @@
+//! Resolving relative natural-language date expressions.
+
+use crate::core::{self, MonthType};
+use crate::parse::{Expected, ParseError, ParseErrorKind};
+use crate::weekday::WEEKDAYS;
+use crate::{
+    CivilDay, CivilHour, CivilMinute, CivilMonth, CivilSecond, CivilYear, DiffType, Weekday,
+};
+
+/// A civil time of any alignment.
+///
+/// ```rust
+/// use civil_time::{CivilDay, CivilSecond, CivilTime};
+///
+/// let t = CivilTime::Day(CivilDay::new(2015, 2, 3));
+/// assert_eq!(CivilSecond::new(2015, 2, 3, 0, 0, 0), t.to_second());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CivilTime {
+    /// A civil time in second alignment.
+    Second(CivilSecond),
+    /// A civil time in minute alignment.
+    Minute(CivilMinute),
+    /// A civil time in hour alignment.
+    Hour(CivilHour),
+    /// A civil time in day alignment.
+    Day(CivilDay),
+    /// A civil time in month alignment.
+    Month(CivilMonth),
+    /// A civil time in year alignment.
+    Year(CivilYear),
+}
+
+impl CivilTime {
+    /// Returns the civil time as a [CivilSecond].
+    pub const fn to_second(self) -> CivilSecond {
+        match self {
+            CivilTime::Second(v) => v,
+            CivilTime::Minute(v) => CivilSecond::from_minute(v),
+            CivilTime::Hour(v) => CivilSecond::from_hour(v),
+            CivilTime::Day(v) => CivilSecond::from_day(v),
+            CivilTime::Month(v) => CivilSecond::from_month(v),
+            CivilTime::Year(v) => CivilSecond::from_year(v),
+        }
+    }
+}
+
+/// A unit of relative expressions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl Unit {
+    /// Returns the unit named by `word`, either singular or plural.
+    fn from_word(word: &str) -> Option<Unit> {
+        let word = word.strip_suffix('s').unwrap_or(word);
+        Some(match word {
+            "second" | "sec" => Unit::Second,
+            "minute" | "min" => Unit::Minute,
+            "hour" => Unit::Hour,
+            "day" => Unit::Day,
+            "week" => Unit::Week,
+            "month" => Unit::Month,
+            "quarter" => Unit::Quarter,
+            "year" => Unit::Year,
+            _ => return None,
+        })
+    }
+
+    /// Whether the unit spans whole days.
+    fn is_calendar(self) -> bool {
+        matches!(self, Unit::Week | Unit::Month | Unit::Quarter | Unit::Year)
+    }
+}
+
+/// Returns the weekday named by `word`, either in full or abbreviated.
+fn weekday_from_word(word: &str) -> Option<Weekday> {
+    WEEKDAYS.iter().copied().find(|wd| {
+        word == wd.short_name().to_ascii_lowercase() || word == wd.long_name().to_ascii_lowercase()
+    })
+}
+
+/// Returns the error of a civil time beyond the representable range.
+fn out_of_range() -> ParseError {
+    ParseError::new(ParseErrorKind::OutOfRange, None)
+}
+
+/// Returns the number of days from the weekday `from` to the next `to`, from
+/// 0 to 6.
+fn days_between(from: Weekday, to: Weekday) -> DiffType {
+    (to as DiffType - from as DiffType).rem_euclid(7)
+}
+
+/// Returns `n` units after `base` in the alignment of the unit, weeks are
+/// counted in days and quarters in months.
+fn shift(base: CivilSecond, unit: Unit, n: DiffType) -> Result<CivilTime, ParseError> {
+    let scaled = |factor: DiffType| n.checked_mul(factor).ok_or_else(out_of_range);
+    Ok(match unit {
+        Unit::Second => CivilTime::Second(base.checked_add(n).ok_or_else(out_of_range)?),
+        Unit::Minute => CivilTime::Minute(
+            CivilMinute::from(base)
+                .checked_add(n)
+                .ok_or_else(out_of_range)?,
+        ),
+        Unit::Hour => CivilTime::Hour(
+            CivilHour::from(base)
+                .checked_add(n)
+                .ok_or_else(out_of_range)?,
+        ),
+        Unit::Day => CivilTime::Day(
+            CivilDay::from(base)
+                .checked_add(n)
+                .ok_or_else(out_of_range)?,
+        ),
+        Unit::Week => CivilTime::Day(
+            CivilDay::from(base)
+                .checked_add(scaled(7)?)
+                .ok_or_else(out_of_range)?,
+        ),
+        Unit::Month => CivilTime::Month(
+            CivilMonth::from(base)
+                .checked_add(n)
+                .ok_or_else(out_of_range)?,
+        ),
+        Unit::Quarter => CivilTime::Month(
+            CivilMonth::from(base)
+                .checked_add(scaled(3)?)
+                .ok_or_else(out_of_range)?,
+        ),
+        Unit::Year => CivilTime::Year(
+            CivilYear::from(base)
+                .checked_add(n)
+                .ok_or_else(out_of_range)?,
+        ),
+    })
+}
+
+/// Returns the first and the last day of the week, month, quarter or year
+/// that is `offset` units after the one containing `base`. Weeks start on
+/// Monday.
+fn period(
+    base: CivilSecond,
+    unit: Unit,
+    offset: DiffType,
+) -> Result<(CivilDay, CivilDay), ParseError> {
+    // The last day is found within the period, so that it does not overflow
+    // unless the period itself does.
+    let months = |m: CivilMonth, n: DiffType| {
+        let last = m.checked_add(n - 1).ok_or_else(out_of_range)?;
+        let days = core::days_per_month(last.year(), last.month() as MonthType);
+        Ok((CivilDay::from(m), CivilDay::from(last) + (days - 1)))
+    };
+    match unit {
+        Unit::Week => {
+            let today = CivilDay::from(base);
+            let monday = offset
+                .checked_mul(7)
+                .and_then(|n| n.checked_sub(days_between(Weekday::Mon, today.weekday())))
+                .and_then(|n| today.checked_add(n))
+                .ok_or_else(out_of_range)?;
+            Ok((monday, monday.checked_add(6).ok_or_else(out_of_range)?))
+        }
+        Unit::Month => {
+            let m = CivilMonth::from(base).checked_add(offset);
+            months(m.ok_or_else(out_of_range)?, 1)
+        }
+        Unit::Quarter => {
+            let first = (base.month() as DiffType - 1) / 3 * 3 + 1;
+            let m = offset
+                .checked_mul(3)
+                .and_then(|n| CivilMonth::new(base.year(), first).checked_add(n));
+            months(m.ok_or_else(out_of_range)?, 3)
+        }
+        _ => {
+            let y = CivilYear::from(base)
+                .checked_add(offset)
+                .ok_or_else(out_of_range)?;
+            Ok((CivilDay::from(y), CivilDay::new(y.year(), 12, 31)))
+        }
+    }
+}
+
+/// Returns whether the day is from Monday to Friday.
+fn is_business_day(cd: CivilDay) -> bool {
+    !matches!(cd.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// Cursor over the lowercase words of an expression.
+struct Words {
+    /// Words in lower case and their byte spans in the input.
+    words: Vec<(String, usize, usize)>,
+    pos: usize,
+    len: usize,
+}
+
+impl Words {
+    fn new(input: &str) -> Self {
+        let mut words = Vec::new();
+        let mut start = None;
+        for (i, c) in input.char_indices().chain(Some((input.len(), ' '))) {
+            match (start, c.is_whitespace()) {
+                (Some(s), true) => {
+                    words.push((input[s..i].to_ascii_lowercase(), s, i));
+                    start = None;
+                }
+                (None, false) => start = Some(i),
+                _ => {}
+            }
+        }
+        Words {
+            words,
+            pos: 0,
+            len: input.len(),
+        }
+    }
+
+    fn peek(&self) -> Option<&str> {
+        self.peek_at(0)
+    }
+
+    /// Returns the `n`-th word after the next one.
+    fn peek_at(&self, n: usize) -> Option<&str> {
+        self.words.get(self.pos + n).map(|w| w.0.as_str())
+    }
+
+    /// Returns the error of the `i`-th word, or of the end of input if there
+    /// are not so many words.
+    fn error(&self, kind: ParseErrorKind, i: usize, expected: Expected) -> ParseError {
+        let (start, end) = match self.words.get(i) {
+            Some(&(_, start, end)) => (start, end),
+            None => (self.len, self.len),
+        };
+        ParseError::new(kind, None)
+            .at(start, end)
+            .expecting(expected)
+    }
+
+    /// Returns the error of the last word consumed.
+    fn invalid(&self, expected: Expected) -> ParseError {
+        self.error(ParseErrorKind::Invalid, self.pos - 1, expected)
+    }
+
+    /// Returns the next word, the input must not end.
+    fn next(&mut self, expected: Expected) -> Result<&str, ParseError> {
+        if self.pos >= self.words.len() {
+            return Err(self.error(ParseErrorKind::TooShort, self.pos, expected));
+        }
+        self.pos += 1;
+        Ok(&self.words[self.pos - 1].0)
+    }
+
+    /// Consumes the next word if it is `word`.
+    fn eat(&mut self, word: &str) -> bool {
+        if self.peek() == Some(word) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, word: &'static str) -> Result<(), ParseError> {
+        if self.next(Expected::Keyword(word))? == word {
+            Ok(())
+        } else {
+            Err(self.invalid(Expected::Keyword(word)))
+        }
+    }
+
+    fn unit(&mut self) -> Result<Unit, ParseError> {
+        let unit = Unit::from_word(self.next(Expected::Word)?);
+        unit.ok_or_else(|| self.invalid(Expected::Word))
+    }
+
+    /// Parses the last word consumed as a count, either digits or "a", "an"
+    /// and "one".
+    fn count(&self) -> Result<DiffType, ParseError> {
+        let word = self.words[self.pos - 1].0.as_str();
+        match word {
+            "a" | "an" | "one" => Ok(1),
+            _ if word.bytes().all(|c| c.is_ascii_digit()) => word.parse().map_err(|_| {
+                self.error(
+                    ParseErrorKind::OutOfRange,
+                    self.pos - 1,
+                    Expected::Range {
+                        min: 0,
+                        max: DiffType::MAX,
+                    },
+                )
+            }),
+            _ => Err(self.invalid(Expected::Digit)),
+        }
+    }
+
+    /// Parses an optional "this", "next" or "last" followed by a unit,
+    /// returns the unit and the offset.
+    fn relative_unit(&mut self) -> Result<(Unit, DiffType), ParseError> {
+        let offset = if self.eat("next") {
+            1
+        } else if self.eat("last") {
+            -1
+        } else {
+            self.eat("this");
+            0
+        };
+        Ok((self.unit()?, offset))
+    }
+}
+
+/// Which day of a period is selected by a "first ... of" or "last ... of"
+/// expression.
+#[derive(Debug, Clone, Copy)]
+enum DayOf {
+    Day,
+    BusinessDay,
+    Weekday(Weekday),
+}
+
+impl DayOf {
+    /// Parses the words between "first" or "last" and "of", returns `None`
+    /// and consumes nothing if they do not form such an expression.
+    fn parse(words: &mut Words) -> Option<DayOf> {
+        let (day_of, len) = match (words.peek_at(0), words.peek_at(1)) {
+            (Some("day"), _) => (DayOf::Day, 1),
+            (Some("business"), Some("day")) => (DayOf::BusinessDay, 2),
+            (Some(word), _) => (DayOf::Weekday(weekday_from_word(word)?), 1),
+            (None, _) => return None,
+        };
+        if words.peek_at(len) != Some("of") {
+            return None;
+        }
+        words.pos += len + 1;
+        Some(day_of)
+    }
+
+    /// Returns the first or the last matching day of the period, which
+    /// contains every weekday.
+    fn resolve(self, (first, last): (CivilDay, CivilDay), from_first: bool) -> CivilDay {
+        match self {
+            DayOf::Day if from_first => first,
+            DayOf::Day => last,
+            DayOf::BusinessDay => {
+                let (mut d, step) = if from_first { (first, 1) } else { (last, -1) };
+                while !is_business_day(d) {
+                    d += step;
+                }
+                d
+            }
+            DayOf::Weekday(wd) if from_first => first + days_between(first.weekday(), wd),
+            DayOf::Weekday(wd) => last - days_between(wd, last.weekday()),
+        }
+    }
+}
+
+/// Resolves the expression in `words` against `base`.
+fn resolve(base: CivilSecond, words: &mut Words) -> Result<CivilTime, ParseError> {
+    let today = CivilDay::from(base);
+    let word = words.next(Expected::Word)?.to_owned();
+    if word == "first" || word == "last" {
+        if let Some(day_of) = DayOf::parse(words) {
+            let (unit, offset) = words.relative_unit()?;
+            if !unit.is_calendar() {
+                return Err(words.invalid(Expected::Word));
+            }
+            let period = period(base, unit, offset)?;
+            return Ok(CivilTime::Day(day_of.resolve(period, word == "first")));
+        }
+    }
+    match word.as_str() {
+        "now" => Ok(CivilTime::Second(base)),
+        "today" => Ok(CivilTime::Day(today)),
+        "yesterday" => shift(base, Unit::Day, -1),
+        "tomorrow" => shift(base, Unit::Day, 1),
+        "in" => {
+            words.next(Expected::Digit)?;
+            let n = words.count()?;
+            shift(base, words.unit()?, n)
+        }
+        "start" | "end" => {
+            words.expect("of")?;
+            let (unit, offset) = words.relative_unit()?;
+            let start = word == "start";
+            if unit.is_calendar() {
+                let (first, last) = period(base, unit, offset)?;
+                return Ok(CivilTime::Day(if start { first } else { last }));
+            }
+            let first = shift(base, unit, offset)?.to_second();
+            if start {
+                return Ok(CivilTime::Second(first));
+            }
+            // The last second is counted from the first one, so that it does
+            // not overflow at the end of the range.
+            let seconds = match unit {
+                Unit::Minute => 60,
+                Unit::Hour => 60 * 60,
+                Unit::Day => 24 * 60 * 60,
+                _ => 1,
+            };
+            Ok(CivilTime::Second(first + (seconds - 1)))
+        }
+        "next" | "last" | "this" => {
+            if let Some(wd) = words.peek().and_then(weekday_from_word) {
+                words.pos += 1;
+                let n = match word.as_str() {
+                    "next" => 7 - days_between(wd, today.weekday()),
+                    "last" => days_between(today.weekday(), wd) - 7,
+                    _ => days_between(today.weekday(), wd),
+                };
+                return shift(base, Unit::Day, n);
+            }
+            let offset = match word.as_str() {
+                "next" => 1,
+                "last" => -1,
+                _ => 0,
+            };
+            match words.unit()? {
+                Unit::Week => Ok(CivilTime::Day(period(base, Unit::Week, offset)?.0)),
+                unit => shift(base, unit, offset),
+            }
+        }
+        _ => {
+            let n = words.count()?;
+            let unit = words.unit()?;
+            if words.eat("from") {
+                words.expect("now")?;
+                return shift(base, unit, n);
+            }
+            words.expect("ago")?;
+            shift(base, unit, -n)
+        }
+    }
+}
+
+impl CivilSecond {
+    /// Resolves the relative natural-language expression `input` against the
+    /// civil time, returns the civil time in the alignment of the expression.
+    ///
+    /// Words are separated by whitespace and matched ignoring ASCII case. Units
+    /// are `second`, `minute`, `hour`, `day`, `week`, `month`, `quarter` and
+    /// `year`, in singular or plural. Weeks start on Monday, and weekdays are
+    /// named in full or abbreviated, e.g. "friday" and "fri".
+    ///
+    /// | Expression | Result |
+    /// |------------|--------|
+    /// | `now` | The civil time itself |
+    /// | `today`, `yesterday`, `tomorrow` | A [CivilDay] |
+    /// | `3 days ago`, `in 2 hours`, `a month from now` | The civil time shifted in the alignment of the unit, weeks in days and quarters in months |
+    /// | `next friday`, `last friday`, `this friday` | The [CivilDay] of the weekday strictly after, strictly before, or on or after the day |
+    /// | `next month`, `last year`, `this hour` | The civil time shifted by one unit, weeks to their Monday |
+    /// | `start of month`, `end of next quarter` | The first or last [CivilDay] of a week, month, quarter or year, or the first or last [CivilSecond] of a day, hour or minute |
+    /// | `first day of month`, `last business day of quarter`, `last friday of this month` | The [CivilDay] in a week, month, quarter or year |
+    ///
+    /// Business days are Monday to Friday, holidays are not considered.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilDay, CivilHour, CivilMonth, CivilSecond, CivilTime};
+    ///
+    /// // 2015-02-03 is a Tuesday.
+    /// let base = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+    /// let resolve = |s| base.resolve_relative(s).unwrap();
+    /// assert_eq!(CivilTime::Day(CivilDay::new(2015, 2, 2)), resolve("yesterday"));
+    /// assert_eq!(CivilTime::Day(CivilDay::new(2015, 2, 6)), resolve("next friday"));
+    /// assert_eq!(CivilTime::Hour(CivilHour::new(2015, 2, 3, 1)), resolve("3 hours ago"));
+    /// assert_eq!(CivilTime::Month(CivilMonth::new(2015, 3)), resolve("next month"));
+    /// assert_eq!(CivilTime::Day(CivilDay::new(2015, 2, 1)), resolve("start of month"));
+    /// assert_eq!(
+    ///     CivilTime::Day(CivilDay::new(2015, 3, 31)),
+    ///     resolve("last business day of quarter")
+    /// );
+    /// ```
+    pub fn resolve_relative(self, input: &str) -> Result<CivilTime, ParseError> {
+        let mut words = Words::new(input);
+        let t = resolve(self, &mut words).map_err(|e| e.at(0, input.len()))?;
+        if let Some(&(_, start, _)) = words.words.get(words.pos) {
+            return Err(ParseError::new(ParseErrorKind::TooLong, None)
+                .at(start, input.len())
+                .expecting(Expected::End));
+        }
+        Ok(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::expect_eq;
+
+    // 2015-02-03 is a Tuesday.
+    const BASE: CivilSecond = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+
+    fn resolve(input: &str) -> CivilTime {
+        BASE.resolve_relative(input).unwrap()
+    }
+
+    #[test]
+    fn test_days() {
+        assert_eq!(CivilTime::Second(BASE), resolve("now"));
+        expect_eq("Day(2015-02-03)", resolve("today"));
+        expect_eq("Day(2015-02-02)", resolve("Yesterday"));
+        expect_eq("Day(2015-02-04)", resolve("  TOMORROW "));
+    }
+
+    #[test]
+    fn test_shift() {
+        expect_eq("Second(2015-02-03T04:04:36)", resolve("30 seconds ago"));
+        expect_eq("Minute(2015-02-03T04:20)", resolve("in 15 minutes"));
+        expect_eq("Hour(2015-02-03T01)", resolve("3 hours ago"));
+        expect_eq("Day(2015-01-31)", resolve("3 days ago"));
+        expect_eq("Day(2015-02-17)", resolve("in 2 weeks"));
+        expect_eq("Month(2014-12)", resolve("2 months ago"));
+        expect_eq("Month(2015-08)", resolve("2 quarters from now"));
+        expect_eq("Year(2016)", resolve("a year from now"));
+        expect_eq("Day(2015-02-04)", resolve("in one day"));
+    }
+
+    #[test]
+    fn test_weekdays() {
+        expect_eq("Day(2015-02-06)", resolve("next friday"));
+        expect_eq("Day(2015-02-10)", resolve("next tue"));
+        expect_eq("Day(2015-01-30)", resolve("last friday"));
+        expect_eq("Day(2015-01-27)", resolve("last Tuesday"));
+        expect_eq("Day(2015-02-03)", resolve("this tuesday"));
+        expect_eq("Day(2015-02-09)", resolve("this monday"));
+    }
+
+    #[test]
+    fn test_next_last_unit() {
+        expect_eq("Day(2015-02-09)", resolve("next week"));
+        expect_eq("Day(2015-01-26)", resolve("last week"));
+        expect_eq("Day(2015-02-02)", resolve("this week"));
+        expect_eq("Month(2015-03)", resolve("next month"));
+        expect_eq("Month(2014-11)", resolve("last quarter"));
+        expect_eq("Year(2014)", resolve("last year"));
+        expect_eq("Hour(2015-02-03T04)", resolve("this hour"));
+        expect_eq("Day(2015-02-02)", resolve("last day"));
+    }
+
+    #[test]
+    fn test_start_end() {
+        expect_eq("Day(2015-02-01)", resolve("start of month"));
+        expect_eq("Day(2015-02-28)", resolve("end of this month"));
+        expect_eq("Day(2015-02-02)", resolve("start of week"));
+        expect_eq("Day(2015-02-15)", resolve("end of next week"));
+        expect_eq("Day(2015-01-01)", resolve("start of quarter"));
+        expect_eq("Day(2015-06-30)", resolve("end of next quarter"));
+        expect_eq("Day(2014-12-31)", resolve("end of last year"));
+        expect_eq("Second(2015-02-03T00:00:00)", resolve("start of day"));
+        expect_eq("Second(2015-02-02T23:59:59)", resolve("end of last day"));
+        expect_eq("Second(2015-02-03T04:59:59)", resolve("end of hour"));
+        expect_eq(
+            "Second(2015-02-03T04:06:00)",
+            resolve("start of next minute"),
+        );
+    }
+
+    #[test]
+    fn test_day_of() {
+        expect_eq("Day(2015-02-01)", resolve("first day of month"));
+        expect_eq("Day(2015-02-28)", resolve("last day of month"));
+        expect_eq("Day(2015-02-02)", resolve("first business day of month"));
+        expect_eq("Day(2015-03-31)", resolve("last business day of quarter"));
+        expect_eq(
+            "Day(2014-12-31)",
+            resolve("last business day of last quarter"),
+        );
+        expect_eq("Day(2015-02-27)", resolve("last friday of month"));
+        expect_eq("Day(2015-02-06)", resolve("first fri of this month"));
+        expect_eq("Day(2016-01-04)", resolve("first monday of next year"));
+        expect_eq("Day(2015-02-08)", resolve("last day of week"));
+
+        let base = CivilSecond::new(2016, 2, 10, 0, 0, 0);
+        let t = base.resolve_relative("last day of month").unwrap();
+        assert_eq!(CivilTime::Day(CivilDay::new(2016, 2, 29)), t);
+        let base = CivilSecond::new(2015, 5, 10, 0, 0, 0);
+        let t = base.resolve_relative("last business day of month").unwrap();
+        assert_eq!(CivilTime::Day(CivilDay::new(2015, 5, 29)), t);
+    }
+
+    #[test]
+    fn test_resolve_error() {
+        let expect_err = |input: &str, kind: ParseErrorKind| {
+            let e = BASE.resolve_relative(input).unwrap_err();
+            assert_eq!(kind, e.kind(), "input: {}", input);
+            assert_eq!(None, e.field(), "input: {}", input);
+        };
+        use ParseErrorKind::*;
+
+        expect_err("", TooShort);
+        expect_err("in 3", TooShort);
+        expect_err("start of", TooShort);
+        expect_err("3 days", TooShort);
+        expect_err("someday", Invalid);
+        expect_err("3 fortnights ago", Invalid);
+        expect_err("-3 days ago", Invalid);
+        expect_err("first day of hour", Invalid);
+        expect_err("first day", Invalid);
+        expect_err("3 days later", Invalid);
+        expect_err("yesterday morning", TooLong);
+        expect_err("99999999999999999999 days ago", OutOfRange);
+        expect_err("in 9223372036854775807 weeks", OutOfRange);
+    }
+
+    #[test]
+    fn test_resolve_limits() {
+        let out_of_range = |base: CivilSecond, input: &str| {
+            let e = base.resolve_relative(input).unwrap_err();
+            assert_eq!(ParseErrorKind::OutOfRange, e.kind(), "input: {}", input);
+        };
+        out_of_range(BASE, "in 9223372036854775807 years");
+
+        // 9223372036854775807-12-31 is a Thursday.
+        let max = CivilSecond::MAX;
+        let resolve = |input: &str| max.resolve_relative(input).unwrap();
+        assert_eq!(CivilTime::Day(CivilDay::MAX), resolve("today"));
+        assert_eq!(CivilTime::Day(CivilDay::MAX), resolve("end of month"));
+        assert_eq!(CivilTime::Day(CivilDay::MAX), resolve("last day of year"));
+        assert_eq!(CivilTime::Day(CivilDay::MAX), resolve("this thursday"));
+        assert_eq!(CivilTime::Second(max), resolve("end of day"));
+        assert_eq!(CivilTime::Year(CivilYear::MAX), resolve("this year"));
+        assert_eq!(CivilTime::Day(CivilDay::MAX - 7), resolve("last thursday"));
+        out_of_range(max, "tomorrow");
+        out_of_range(max, "next year");
+        out_of_range(max, "next friday");
+        out_of_range(max, "end of week");
+        out_of_range(max, "start of next quarter");
+        out_of_range(max, "first monday of next month");
+        out_of_range(max, "in 1 second");
+
+        // -9223372036854775808-01-01 is a Sunday.
+        let min = CivilSecond::MIN;
+        let resolve = |input: &str| min.resolve_relative(input).unwrap();
+        assert_eq!(CivilTime::Day(CivilDay::MIN), resolve("start of month"));
+        assert_eq!(CivilTime::Day(CivilDay::MIN), resolve("this sunday"));
+        assert_eq!(CivilTime::Day(CivilDay::MIN + 1), resolve("next monday"));
+        out_of_range(min, "yesterday");
+        out_of_range(min, "start of week");
+        out_of_range(min, "last month");
+        out_of_range(min, "1 second ago");
+    }
+
+    #[test]
+    fn test_resolve_error_span() {
+        let expect_span = |input: &str, span: std::ops::Range<usize>, expected| {
+            let e = BASE.resolve_relative(input).unwrap_err();
+            assert_eq!(span, e.span(), "input: {}", input);
+            assert_eq!(expected, e.expected(), "input: {}", input);
+        };
+
+        expect_span("3 fortnights ago", 2..12, Some(Expected::Word));
+        expect_span("3 days later", 7..12, Some(Expected::Keyword("ago")));
+        expect_span("start  month", 7..12, Some(Expected::Keyword("of")));
+        expect_span("yesterday  morning", 11..18, Some(Expected::End));
+        expect_span("in 3", 4..4, Some(Expected::Word));
+        expect_span("-3 days ago", 0..2, Some(Expected::Digit));
+    }
+}
@@ -0,0 +1,211 @@
+//! Replacement of a single field of civil time types.
+
+use crate::core::{DiffType, Field, FieldError, Fields};
+use crate::{CivilDay, CivilHour, CivilMinute, CivilMonth, CivilSecond, CivilYear};
+
+/// How the `with_*()` methods, e.g. [`CivilDay::with_month()`], handle a
+/// replaced field out of its natural range, or a day beyond the end of the
+/// month it ends up in.
+///
+/// ```rust
+/// use civil_time::{CivilDay, Field, ReplacePolicy};
+///
+/// let d = CivilDay::new(2015, 1, 31);
+/// assert_eq!(Ok(CivilDay::new(2015, 2, 28)), d.with_month(2, ReplacePolicy::Clamp));
+/// assert_eq!(Ok(CivilDay::new(2015, 3, 3)), d.with_month(2, ReplacePolicy::Normalize));
+///
+/// let e = d.with_month(2, ReplacePolicy::Strict).unwrap_err();
+/// assert_eq!((Field::Day, 31, 1..=28), (e.field(), e.value(), e.range()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ReplacePolicy {
+    /// Clamps the fields to their natural ranges, e.g. January 31 with the
+    /// month replaced by 2 is February 28, and an hour of 24 is 23.
+    Clamp,
+    /// Normalizes the fields in the same way as the constructors, e.g.
+    /// January 31 with the month replaced by 2 is March 3.
+    Normalize,
+    /// Rejects any field out of its natural range, including a day that is
+    /// beyond the end of the month once the month or the year is replaced.
+    #[default]
+    Strict,
+}
+
+macro_rules! impl_replace {
+    ($($Type: ty)*) => ($(
+        impl $Type {
+            /// Returns the value with `field` replaced by `v`, handled by
+            /// `policy`.
+            const fn replace(
+                self,
+                field: Field,
+                v: DiffType,
+                policy: ReplacePolicy,
+            ) -> Result<Self, FieldError> {
+                let f = self.0;
+                let (mut y, mut m, mut d) = (f.y, f.m as DiffType, f.d as DiffType);
+                let (mut hh, mut mm, mut ss) =
+                    (f.hh as DiffType, f.mm as DiffType, f.ss as DiffType);
+                match field {
+                    Field::Year => y = v,
+                    Field::Month => m = v,
+                    Field::Day => d = v,
+                    Field::Hour => hh = v,
+                    Field::Minute => mm = v,
+                    Field::Second => ss = v,
+                }
+                match policy {
+                    ReplacePolicy::Clamp => {
+                        Ok(Self::from_fields(Fields::clamp(y, m, d, hh, mm, ss)))
+                    }
+                    ReplacePolicy::Normalize => Ok(Self::from_ymd_hms(y, m, d, hh, mm, ss)),
+                    ReplacePolicy::Strict => Self::try_from_ymd_hms(y, m, d, hh, mm, ss),
+                }
+            }
+        }
+    )*)
+}
+
+impl_replace! {
+    CivilSecond CivilMinute CivilHour CivilDay CivilMonth CivilYear
+}
+
+macro_rules! impl_with {
+    ($Type: ty, $func: ident, $field: ident) => {
+        impl $Type {
+            /// Returns the value with the field named by the method replaced
+            /// by `v`.
+            ///
+            /// See [ReplacePolicy] for how `policy` handles fields out of
+            /// range, only [`ReplacePolicy::Strict`] returns an error.
+            pub const fn $func(
+                self,
+                v: DiffType,
+                policy: ReplacePolicy,
+            ) -> Result<Self, FieldError> {
+                self.replace(Field::$field, v, policy)
+            }
+        }
+    };
+}
+
+// Implement replacement for CivilSecond.
+impl_with!(CivilSecond, with_year, Year);
+impl_with!(CivilSecond, with_month, Month);
+impl_with!(CivilSecond, with_day, Day);
+impl_with!(CivilSecond, with_hour, Hour);
+impl_with!(CivilSecond, with_minute, Minute);
+impl_with!(CivilSecond, with_second, Second);
+
+// Implement replacement for CivilMinute.
+impl_with!(CivilMinute, with_year, Year);
+impl_with!(CivilMinute, with_month, Month);
+impl_with!(CivilMinute, with_day, Day);
+impl_with!(CivilMinute, with_hour, Hour);
+impl_with!(CivilMinute, with_minute, Minute);
+
+// Implement replacement for CivilHour.
+impl_with!(CivilHour, with_year, Year);
+impl_with!(CivilHour, with_month, Month);
+impl_with!(CivilHour, with_day, Day);
+impl_with!(CivilHour, with_hour, Hour);
+
+// Implement replacement for CivilDay.
+impl_with!(CivilDay, with_year, Year);
+impl_with!(CivilDay, with_month, Month);
+impl_with!(CivilDay, with_day, Day);
+
+// Implement replacement for CivilMonth.
+impl_with!(CivilMonth, with_year, Year);
+impl_with!(CivilMonth, with_month, Month);
+
+// Implement replacement for CivilYear.
+impl_with!(CivilYear, with_year, Year);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::expect_eq;
+    use ReplacePolicy::*;
+
+    #[test]
+    fn test_with_clamp() {
+        let ss = CivilSecond::new(2016, 1, 31, 4, 5, 6);
+        expect_eq("2016-02-29T04:05:06", ss.with_month(2, Clamp).unwrap());
+        expect_eq("2015-01-31T04:05:06", ss.with_year(2015, Clamp).unwrap());
+        expect_eq("2016-12-31T04:05:06", ss.with_month(13, Clamp).unwrap());
+        expect_eq("2016-01-01T04:05:06", ss.with_day(0, Clamp).unwrap());
+        expect_eq("2016-01-31T23:05:06", ss.with_hour(24, Clamp).unwrap());
+        expect_eq("2016-01-31T04:00:06", ss.with_minute(-1, Clamp).unwrap());
+        expect_eq("2016-01-31T04:05:59", ss.with_second(60, Clamp).unwrap());
+
+        let d = CivilDay::new(2016, 2, 29);
+        expect_eq("2015-02-28", d.with_year(2015, Clamp).unwrap());
+        expect_eq("2016-04-29", d.with_month(4, Clamp).unwrap());
+        expect_eq("2016-02-29", d.with_day(31, Clamp).unwrap());
+    }
+
+    #[test]
+    fn test_with_normalize() {
+        let ss = CivilSecond::new(2016, 1, 31, 4, 5, 6);
+        expect_eq("2016-03-02T04:05:06", ss.with_month(2, Normalize).unwrap());
+        expect_eq("2017-01-31T04:05:06", ss.with_month(13, Normalize).unwrap());
+        expect_eq("2015-12-31T04:05:06", ss.with_day(0, Normalize).unwrap());
+        expect_eq("2016-02-01T00:05:06", ss.with_hour(24, Normalize).unwrap());
+        expect_eq(
+            "2016-01-31T03:59:06",
+            ss.with_minute(-1, Normalize).unwrap(),
+        );
+        expect_eq(
+            "2016-01-31T04:06:00",
+            ss.with_second(60, Normalize).unwrap(),
+        );
+
+        let d = CivilDay::new(2016, 2, 29);
+        expect_eq("2015-03-01", d.with_year(2015, Normalize).unwrap());
+    }
+
+    #[test]
+    fn test_with_strict() {
+        let ss = CivilSecond::new(2016, 1, 31, 4, 5, 6);
+        expect_eq("2016-03-31T04:05:06", ss.with_month(3, Strict).unwrap());
+        expect_eq("2016-01-31T04:05:59", ss.with_second(59, Strict).unwrap());
+
+        let check = |r: Result<CivilSecond, FieldError>, field, value| {
+            let e = r.unwrap_err();
+            assert_eq!((field, value), (e.field(), e.value()));
+        };
+        check(ss.with_month(2, Strict), Field::Day, 31);
+        check(ss.with_month(0, Strict), Field::Month, 0);
+        check(ss.with_day(32, Strict), Field::Day, 32);
+        check(ss.with_hour(24, Strict), Field::Hour, 24);
+        check(ss.with_minute(60, Strict), Field::Minute, 60);
+        check(ss.with_second(-1, Strict), Field::Second, -1);
+
+        let d = CivilDay::new(2016, 2, 29);
+        let e = d.with_year(2015, Strict).unwrap_err();
+        assert_eq!((Field::Day, 29, 1..=28), (e.field(), e.value(), e.range()));
+        assert_eq!(Ok(CivilDay::new(2020, 2, 29)), d.with_year(2020, Strict));
+    }
+
+    #[test]
+    fn test_with_keeps_alignment() {
+        let mm = CivilMinute::new(2015, 2, 3, 4, 5);
+        expect_eq("2015-02-03T06:05", mm.with_hour(6, Strict).unwrap());
+        let hh = CivilHour::new(2015, 2, 3, 4);
+        expect_eq("2015-02-03T23", hh.with_hour(23, Strict).unwrap());
+        let m = CivilMonth::new(2015, 2);
+        expect_eq("2015-12", m.with_month(12, Strict).unwrap());
+        expect_eq("2016-01", m.with_month(13, Normalize).unwrap());
+        expect_eq("2015-12", m.with_month(13, Clamp).unwrap());
+        let y = CivilYear::new(2015);
+        assert_eq!(Ok(CivilYear::MAX), y.with_year(i64::MAX, Strict));
+    }
+
+    #[test]
+    fn test_with_const() {
+        const _D: Result<CivilDay, FieldError> =
+            CivilDay::new(2015, 1, 31).with_month(2, ReplacePolicy::Clamp);
+        static_assertions::const_assert!(_D.is_ok());
+    }
+}
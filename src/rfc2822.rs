@@ -0,0 +1,595 @@
+//! HTTP-date and RFC 2822 date-time of [CivilSecond].
+
+use crate::core::{Field, FieldError, Fields};
+use crate::format::{DelayedFormat, Item, Spec};
+use crate::offset::UtcOffset;
+use crate::parse::{
+    parse_items, set, Expected, ParseError, ParseErrorKind, ParseMode, ParseOptions, Parsed,
+    Scanner, TwoDigitYear,
+};
+use crate::{CivilSecond, CivilYear};
+
+/// The preferred HTTP-date form, e.g. "Tue, 03 Feb 2015 04:05:06 GMT".
+const IMF_FIXDATE: [Item<'static>; 14] = [
+    Item::Spec(Spec::WeekdayShort),
+    Item::Literal(", "),
+    Item::Spec(Spec::Day),
+    Item::Literal(" "),
+    Item::Spec(Spec::MonthShort),
+    Item::Literal(" "),
+    Item::Spec(Spec::YearPadded),
+    Item::Literal(" "),
+    Item::Spec(Spec::Hour),
+    Item::Literal(":"),
+    Item::Spec(Spec::Minute),
+    Item::Literal(":"),
+    Item::Spec(Spec::Second),
+    Item::Literal(" GMT"),
+];
+
+/// The obsolete RFC 850 form, e.g. "Tuesday, 03-Feb-15 04:05:06 GMT".
+const RFC850_DATE: [Item<'static>; 14] = [
+    Item::Spec(Spec::WeekdayLong),
+    Item::Literal(", "),
+    Item::Spec(Spec::Day),
+    Item::Literal("-"),
+    Item::Spec(Spec::MonthShort),
+    Item::Literal("-"),
+    Item::Spec(Spec::YearOfCentury),
+    Item::Literal(" "),
+    Item::Spec(Spec::Hour),
+    Item::Literal(":"),
+    Item::Spec(Spec::Minute),
+    Item::Literal(":"),
+    Item::Spec(Spec::Second),
+    Item::Literal(" GMT"),
+];
+
+/// The obsolete asctime() form, e.g. "Tue Feb  3 04:05:06 2015".
+const ASCTIME_DATE: [Item<'static>; 13] = [
+    Item::Spec(Spec::WeekdayShort),
+    Item::Literal(" "),
+    Item::Spec(Spec::MonthShort),
+    Item::Literal(" "),
+    Item::Spec(Spec::DaySpacePadded),
+    Item::Literal(" "),
+    Item::Spec(Spec::Hour),
+    Item::Literal(":"),
+    Item::Spec(Spec::Minute),
+    Item::Literal(":"),
+    Item::Spec(Spec::Second),
+    Item::Literal(" "),
+    Item::Spec(Spec::YearPadded),
+];
+
+/// RFC 2822 date-time without the zone, e.g. "Tue, 03 Feb 2015 04:05:06 ".
+const RFC2822_DATE: [Item<'static>; 14] = [
+    Item::Spec(Spec::WeekdayShort),
+    Item::Literal(", "),
+    Item::Spec(Spec::Day),
+    Item::Literal(" "),
+    Item::Spec(Spec::MonthShort),
+    Item::Literal(" "),
+    Item::Spec(Spec::YearPadded),
+    Item::Literal(" "),
+    Item::Spec(Spec::Hour),
+    Item::Literal(":"),
+    Item::Spec(Spec::Minute),
+    Item::Literal(":"),
+    Item::Spec(Spec::Second),
+    Item::Literal(" "),
+];
+
+/// Obsolete zone names of RFC 2822 and their offsets in hours.
+const ZONES: [(&str, i32); 10] = [
+    ("UT", 0),
+    ("GMT", 0),
+    ("EST", -5),
+    ("EDT", -4),
+    ("CST", -6),
+    ("CDT", -5),
+    ("MST", -7),
+    ("MDT", -6),
+    ("PST", -8),
+    ("PDT", -7),
+];
+
+/// Skips white space and comments, e.g. " (UTC) ", returns the number of
+/// bytes skipped.
+fn cfws(sc: &mut Scanner<'_>) -> Result<usize, ParseError> {
+    let start = sc.pos();
+    sc.spaces();
+    while sc.comment()? {
+        sc.spaces();
+    }
+    Ok(sc.pos() - start)
+}
+
+/// Skips the white space and comments between two parts, which must not be
+/// empty.
+fn separator(sc: &mut Scanner<'_>, field: Option<Field>) -> Result<(), ParseError> {
+    if cfws(sc)? == 0 {
+        return Err(sc.unexpected(field, Expected::Char(' ')));
+    }
+    Ok(())
+}
+
+/// Parses the zone of RFC 2822, either "+hhmm", "-hhmm" or an obsolete zone
+/// name. Military zones are treated as UTC as recommended by RFC 2822.
+fn parse_zone(sc: &mut Scanner<'_>) -> Result<UtcOffset, ParseError> {
+    match sc.peek() {
+        Some(c) if c.is_ascii_alphabetic() => {
+            let start = sc.pos();
+            let name = sc.letters();
+            if name.len() == 1 && !name.eq_ignore_ascii_case(b"J") {
+                return Ok(UtcOffset::UTC);
+            }
+            ZONES
+                .iter()
+                .find(|(zone, _)| name.eq_ignore_ascii_case(zone.as_bytes()))
+                .map(|(_, hours)| UtcOffset::from_minutes(hours * 60))
+                .ok_or_else(|| {
+                    ParseError::new(ParseErrorKind::Invalid, None)
+                        .at(start, sc.pos())
+                        .expecting(Expected::Zone)
+                })
+        }
+        _ => UtcOffset::parse(sc, false),
+    }
+}
+
+/// Checks that the year of `ss` is from 0 to 9999, the only years with the
+/// four digits of HTTP-date and RFC 2822.
+fn check_year(ss: CivilSecond) -> Result<(), FieldError> {
+    if ss.year() < 0 || ss.year() > 9999 {
+        return Err(FieldError::new(Field::Year, ss.year(), 0, 9999));
+    }
+    Ok(())
+}
+
+/// Parses an RFC 2822 date-time, e.g. "Tue, 3 Feb 2015 04:05:06 +0800".
+fn parse_rfc2822(input: &str) -> Result<(Fields, UtcOffset), ParseError> {
+    let mut sc = Scanner::new(input);
+    let mut parsed = Parsed::new(ParseMode::Strict);
+    cfws(&mut sc)?;
+    if matches!(sc.peek(), Some(c) if c.is_ascii_alphabetic()) {
+        parsed.parse_spec(&mut sc, Spec::WeekdayShort, 1, 0)?;
+        cfws(&mut sc)?;
+        sc.literal(",")?;
+        cfws(&mut sc)?;
+    }
+    parsed.parse_spec(&mut sc, Spec::Day, 1, 0)?;
+    separator(&mut sc, Some(Field::Month))?;
+    parsed.parse_spec(&mut sc, Spec::MonthShort, 1, 0)?;
+    separator(&mut sc, Some(Field::Year))?;
+
+    // Obsolete years of 2 digits are from 1950 to 2049, and of 3 digits are
+    // added to 1900.
+    let start = sc.pos();
+    let y = sc.number(2, 18, Field::Year)?;
+    let y = match sc.pos() - start {
+        2 if y < 50 => 2000 + y,
+        2 | 3 => 1900 + y,
+        _ => y,
+    };
+    set(&mut parsed.year, y, Field::Year)?;
+    parsed.set_span(Field::Year, start, sc.pos());
+
+    separator(&mut sc, Some(Field::Hour))?;
+    parsed.parse_spec(&mut sc, Spec::Hour, 2, 0)?;
+    sc.literal(":")?;
+    parsed.parse_spec(&mut sc, Spec::Minute, 2, 0)?;
+    if sc.peek() == Some(b':') {
+        sc.literal(":")?;
+        parsed.parse_spec(&mut sc, Spec::Second, 2, 0)?;
+    }
+    separator(&mut sc, None)?;
+    let offset = parse_zone(&mut sc)?;
+    cfws(&mut sc)?;
+    if !sc.at_end() {
+        return Err(sc.trailing());
+    }
+
+    Ok((parsed.resolve()?, offset))
+}
+
+impl CivilSecond {
+    /// Formats the civil time in GMT as the preferred HTTP-date form
+    /// (IMF-fixdate), e.g. "Tue, 03 Feb 2015 04:05:06 GMT".
+    ///
+    /// HTTP-date only represents years from 0 to 9999, other years are
+    /// errors of [Field::Year].
+    ///
+    /// ```rust
+    /// use civil_time::{CivilSecond, Field};
+    ///
+    /// let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+    /// let s = ss.format_http_date().unwrap().to_string();
+    /// assert_eq!("Tue, 03 Feb 2015 04:05:06 GMT", s);
+    ///
+    /// let e = CivilSecond::new(10000, 1, 1, 0, 0, 0).format_http_date().unwrap_err();
+    /// assert_eq!((Field::Year, 0..=9999), (e.field(), e.range()));
+    /// ```
+    pub fn format_http_date(&self) -> Result<DelayedFormat<'static>, FieldError> {
+        check_year(*self)?;
+        Ok(DelayedFormat::from_items(*self, &IMF_FIXDATE))
+    }
+
+    /// Parses an HTTP-date in GMT, in any of the three forms:
+    ///
+    /// - IMF-fixdate, e.g. "Tue, 03 Feb 2015 04:05:06 GMT".
+    /// - The obsolete RFC 850 form, e.g. "Tuesday, 03-Feb-15 04:05:06 GMT".
+    /// - The obsolete asctime() form, e.g. "Tue Feb  3 04:05:06 2015".
+    ///
+    /// The weekday must match the date. As required by RFC 7231, a two-digit
+    /// year of the RFC 850 form that appears to be more than 50 years after
+    /// the `reference` year, usually the current year, refers to the most
+    /// recent year in the past with the same last two digits.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilSecond, CivilYear, ParseErrorKind};
+    ///
+    /// let now = CivilYear::new(2015);
+    /// let ss = CivilSecond::new(1994, 11, 6, 8, 49, 37);
+    /// let parse = |input| CivilSecond::parse_http_date(input, now).unwrap();
+    /// assert_eq!(ss, parse("Sun, 06 Nov 1994 08:49:37 GMT"));
+    /// assert_eq!(ss, parse("Sunday, 06-Nov-94 08:49:37 GMT"));
+    /// assert_eq!(ss, parse("Sun Nov  6 08:49:37 1994"));
+    ///
+    /// // 2065 is 50 years after the reference, 2066 is more.
+    /// assert_eq!(2065, parse("Tuesday, 03-Feb-65 04:05:06 GMT").year());
+    /// assert_eq!(1966, parse("Thursday, 03-Feb-66 04:05:06 GMT").year());
+    ///
+    /// let e = CivilSecond::parse_http_date("Mon, 06 Nov 1994 08:49:37 GMT", now).unwrap_err();
+    /// assert_eq!(ParseErrorKind::Inconsistent, e.kind());
+    /// ```
+    pub fn parse_http_date(input: &str, reference: CivilYear) -> Result<Self, ParseError> {
+        let (items, two_digit_year): (&[Item<'_>], _) = match input.as_bytes().get(3) {
+            Some(b',') => (&IMF_FIXDATE, TwoDigitYear::default()),
+            Some(b' ') => (&ASCTIME_DATE, TwoDigitYear::default()),
+            _ => {
+                let window = TwoDigitYear::sliding(reference, 50).ok_or_else(|| {
+                    ParseError::new(ParseErrorKind::OutOfRange, Some(Field::Year))
+                })?;
+                (&RFC850_DATE, window)
+            }
+        };
+        let options = ParseOptions {
+            exact_width: true,
+            mode: ParseMode::Strict,
+            two_digit_year,
+        };
+        parse_items(input, items, options).map(Self::from_fields)
+    }
+
+    /// Formats the civil time at the UTC `offset` as an RFC 2822 date-time,
+    /// e.g. "Tue, 03 Feb 2015 04:05:06 +0800".
+    ///
+    /// RFC 2822 only represents years from 0 to 9999, other years are errors
+    /// of [Field::Year].
+    ///
+    /// ```rust
+    /// use civil_time::{CivilSecond, UtcOffset};
+    ///
+    /// let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+    /// let offset = UtcOffset::from_minutes(-300);
+    /// let s = ss.format_rfc2822(offset).unwrap().to_string();
+    /// assert_eq!("Tue, 03 Feb 2015 04:05:06 -0500", s);
+    /// assert!(CivilSecond::new(-1, 12, 31, 0, 0, 0).format_rfc2822(offset).is_err());
+    /// ```
+    pub fn format_rfc2822(&self, offset: UtcOffset) -> Result<DelayedFormat<'static>, FieldError> {
+        check_year(*self)?;
+        Ok(DelayedFormat::from_offset(*self, &RFC2822_DATE, offset))
+    }
+
+    /// Parses an RFC 2822 date-time, returns the civil time and its UTC
+    /// offset.
+    ///
+    /// The weekday and the second are optional, and the weekday must match
+    /// the date if present. Comments in parentheses, e.g. "+0000 (UTC)", are
+    /// skipped wherever white space is allowed. The obsolete syntax is also accepted: two-digit
+    /// years from 50 to 99 refer to 1950 to 1999 and others refer to 2000 to
+    /// 2049, three-digit years are added to 1900, and the zone may be "UT",
+    /// "GMT", one of the North American zones such as "EST" and "PDT", or a
+    /// military zone, which is treated as UTC.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilSecond, UtcOffset};
+    ///
+    /// let (ss, offset) = CivilSecond::parse_rfc2822("Tue, 3 Feb 2015 04:05:06 +0800").unwrap();
+    /// assert_eq!(CivilSecond::new(2015, 2, 3, 4, 5, 6), ss);
+    /// assert_eq!(UtcOffset::from_minutes(480), offset);
+    ///
+    /// let (ss, offset) = CivilSecond::parse_rfc2822("3 Feb 15 04:05 EST").unwrap();
+    /// assert_eq!(CivilSecond::new(2015, 2, 3, 4, 5, 0), ss);
+    /// assert_eq!(UtcOffset::from_minutes(-300), offset);
+    ///
+    /// let (ss, offset) = CivilSecond::parse_rfc2822("Tue, 3 Feb 2015 04:05:06 +0000 (UTC)").unwrap();
+    /// assert_eq!((CivilSecond::new(2015, 2, 3, 4, 5, 6), UtcOffset::UTC), (ss, offset));
+    /// ```
+    pub fn parse_rfc2822(input: &str) -> Result<(Self, UtcOffset), ParseError> {
+        let (fields, offset) = parse_rfc2822(input).map_err(|e| e.at(0, input.len()))?;
+        Ok((Self::from_fields(fields), offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Weekday, YearType};
+
+    #[test]
+    fn test_format_http_date() {
+        let ss = CivilSecond::new(1994, 11, 6, 8, 49, 37);
+        assert_eq!(
+            "Sun, 06 Nov 1994 08:49:37 GMT",
+            ss.format_http_date().unwrap().to_string()
+        );
+        let ss = CivilSecond::new(5, 1, 1, 0, 0, 0);
+        assert_eq!(
+            "Sat, 01 Jan 0005 00:00:00 GMT",
+            ss.format_http_date().unwrap().to_string()
+        );
+        let ss = CivilSecond::new(9999, 12, 31, 23, 59, 59);
+        assert_eq!(
+            "Fri, 31 Dec 9999 23:59:59 GMT",
+            ss.format_http_date().unwrap().to_string()
+        );
+
+        for y in [-1, 10000, YearType::MIN, YearType::MAX].iter() {
+            let e = CivilSecond::new(*y, 1, 1, 0, 0, 0)
+                .format_http_date()
+                .unwrap_err();
+            assert_eq!(
+                (Field::Year, *y, 0..=9999),
+                (e.field(), e.value(), e.range())
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_http_date() {
+        let now = CivilYear::new(2015);
+        let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+        let inputs = [
+            "Tue, 03 Feb 2015 04:05:06 GMT",
+            "Tuesday, 03-Feb-15 04:05:06 GMT",
+            "Tue Feb  3 04:05:06 2015",
+        ];
+        for input in inputs.iter() {
+            assert_eq!(
+                ss,
+                CivilSecond::parse_http_date(input, now).unwrap(),
+                "{}",
+                input
+            );
+        }
+        let ss = CivilSecond::new(1999, 12, 31, 23, 59, 59);
+        assert_eq!(
+            ss,
+            CivilSecond::parse_http_date("Friday, 31-Dec-99 23:59:59 GMT", now).unwrap()
+        );
+        assert_eq!(
+            ss,
+            CivilSecond::parse_http_date("Fri Dec 31 23:59:59 1999", now).unwrap()
+        );
+
+        // RFC 850 years more than 50 years in the future are in the past.
+        let table = [
+            (2015, "Tuesday, 03-Feb-65 04:05:06 GMT", 2065),
+            (2015, "Thursday, 03-Feb-66 04:05:06 GMT", 1966),
+            (2015, "Tuesday, 03-Feb-15 04:05:06 GMT", 2015),
+            (2050, "Tuesday, 03-Feb-99 04:05:06 GMT", 2099),
+            (2050, "Wednesday, 03-Feb-00 04:05:06 GMT", 2100),
+            (2050, "Saturday, 03-Feb-01 04:05:06 GMT", 2001),
+        ];
+        for (reference, input, year) in table.iter() {
+            let ss = CivilSecond::parse_http_date(input, CivilYear::new(*reference)).unwrap();
+            assert_eq!(*year, ss.year(), "{}", input);
+        }
+        let e = CivilSecond::parse_http_date("Thursday, 01-Jan-70 00:00:00 GMT", CivilYear::MIN);
+        assert_eq!(ParseErrorKind::OutOfRange, e.unwrap_err().kind());
+        // Four-digit years do not depend on the reference.
+        let ss = CivilSecond::parse_http_date("Tue, 03 Feb 2015 04:05:06 GMT", CivilYear::MAX);
+        assert_eq!(Ok(CivilSecond::new(2015, 2, 3, 4, 5, 6)), ss);
+
+        // Round trip of every weekday.
+        let start = CivilSecond::new(2015, 8, 10, 12, 0, 0);
+        for i in 0..7 {
+            let ss = start + i * 86400;
+            let s = ss.format_http_date().unwrap().to_string();
+            assert_eq!(ss, CivilSecond::parse_http_date(&s, now).unwrap(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_parse_http_date_error() {
+        use ParseErrorKind::*;
+
+        let expect_err = |input: &str, kind: ParseErrorKind, field: Option<Field>| {
+            let e = CivilSecond::parse_http_date(input, CivilYear::new(2015)).unwrap_err();
+            assert_eq!(kind, e.kind(), "input: {}", input);
+            assert_eq!(field, e.field(), "input: {}", input);
+        };
+        expect_err("", TooShort, Some(Field::Day));
+        expect_err(
+            "Wed, 03 Feb 2015 04:05:06 GMT",
+            Inconsistent,
+            Some(Field::Day),
+        );
+        expect_err("Tue, 3 Feb 2015 04:05:06 GMT", Invalid, Some(Field::Day));
+        expect_err("Tue, 03 Feb 2015 04:05:06 UTC", Invalid, None);
+        expect_err("Tue, 03 Feb 2015 04:05:06 GMT ", TooLong, None);
+        expect_err(
+            "Tue, 30 Feb 2015 04:05:06 GMT",
+            OutOfRange,
+            Some(Field::Day),
+        );
+        expect_err(
+            "Tue, 03 Feb 2015 24:05:06 GMT",
+            OutOfRange,
+            Some(Field::Hour),
+        );
+        expect_err("Tuesday, 03-Feb-2015 04:05:06 GMT", Invalid, None);
+    }
+
+    #[test]
+    fn test_format_rfc2822() {
+        let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+        let table = [
+            (0, "Tue, 03 Feb 2015 04:05:06 +0000"),
+            (480, "Tue, 03 Feb 2015 04:05:06 +0800"),
+            (-210, "Tue, 03 Feb 2015 04:05:06 -0330"),
+        ];
+        for (minutes, expect) in table.iter() {
+            let offset = UtcOffset::from_minutes(*minutes);
+            assert_eq!(*expect, ss.format_rfc2822(offset).unwrap().to_string());
+            assert_eq!((ss, offset), CivilSecond::parse_rfc2822(expect).unwrap());
+        }
+
+        let offset = UtcOffset::from_minutes(0);
+        let ss = CivilSecond::new(0, 1, 1, 0, 0, 0);
+        assert_eq!(
+            "Sat, 01 Jan 0000 00:00:00 +0000",
+            ss.format_rfc2822(offset).unwrap().to_string()
+        );
+        let e = (ss - 1).format_rfc2822(offset).unwrap_err();
+        assert_eq!((Field::Year, -1), (e.field(), e.value()));
+        let e = CivilSecond::new(10000, 1, 1, 0, 0, 0)
+            .format_rfc2822(offset)
+            .unwrap_err();
+        assert_eq!((Field::Year, 10000), (e.field(), e.value()));
+    }
+
+    #[test]
+    fn test_parse_rfc2822() {
+        let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+        let utc = UtcOffset::UTC;
+        let parse = |s: &str| CivilSecond::parse_rfc2822(s).unwrap();
+        assert_eq!((ss, utc), parse("Tue, 03 Feb 2015 04:05:06 +0000"));
+        assert_eq!((ss, utc), parse("  Tue ,  3  Feb  2015  04:05:06  GMT  "));
+        assert_eq!((ss, utc), parse("3 Feb 2015 04:05:06 -0000"));
+        assert_eq!((ss, utc), parse("3 Feb 2015 04:05:06 UT"));
+        assert_eq!((ss, utc), parse("3 Feb 2015 04:05:06 z"));
+        assert_eq!((ss - 6, utc), parse("3 Feb 2015 04:05 Z"));
+
+        // Obsolete years.
+        let years = [
+            ("15", 2015),
+            ("49", 2049),
+            ("50", 1950),
+            ("115", 2015),
+            ("0015", 15),
+        ];
+        for (y, year) in years.iter() {
+            let input = format!("3 Feb {} 04:05:06 GMT", y);
+            let expect = CivilSecond::new(*year, 2, 3, 4, 5, 6);
+            assert_eq!(expect, parse(&input).0, "{}", input);
+        }
+
+        let zones = [
+            ("EST", -5),
+            ("EDT", -4),
+            ("CST", -6),
+            ("CDT", -5),
+            ("MST", -7),
+            ("MDT", -6),
+            ("PST", -8),
+            ("pdt", -7),
+        ];
+        for (zone, hours) in zones.iter() {
+            let input = format!("Tue, 3 Feb 2015 04:05:06 {}", zone);
+            assert_eq!((ss, UtcOffset::from_minutes(hours * 60)), parse(&input));
+        }
+        assert_eq!(Weekday::Tue, parse("3 Feb 2015 04:05 +0100").0.weekday());
+    }
+
+    #[test]
+    fn test_parse_rfc2822_comments() {
+        let ss = CivilSecond::new(2015, 2, 3, 4, 5, 6);
+        let utc = UtcOffset::UTC;
+        let parse = |s: &str| CivilSecond::parse_rfc2822(s).unwrap();
+        assert_eq!((ss, utc), parse("Tue, 3 Feb 2015 04:05:06 +0000 (UTC)"));
+        assert_eq!((ss, utc), parse("Tue, 3 Feb 2015 04:05:06 GMT(UTC)"));
+        assert_eq!(
+            (ss, UtcOffset::from_minutes(-300)),
+            parse("Tue, 3 Feb 2015 04:05:06 -0500 (EST) (Eastern (Standard) Time)")
+        );
+        assert_eq!(
+            (ss, utc),
+            parse("(sent) Tue (day) , (comma) 3 (d) Feb (m) 2015 (y) 04:05:06 (t) +0000")
+        );
+        assert_eq!((ss, utc), parse("3(d)Feb(m)2015(y)04:05:06(t)+0000"));
+        assert_eq!(
+            (ss, utc),
+            parse(r"3 Feb 2015 04:05:06 +0000 (\(escaped\) \))")
+        );
+        assert_eq!((ss, utc), parse("3 Feb 2015 04:05:06 +0000 (Zürich)"));
+    }
+
+    #[test]
+    fn test_parse_rfc2822_error() {
+        use ParseErrorKind::*;
+
+        let expect_err = |input: &str, kind: ParseErrorKind, field: Option<Field>| {
+            let e = CivilSecond::parse_rfc2822(input).unwrap_err();
+            assert_eq!(kind, e.kind(), "input: {}", input);
+            assert_eq!(field, e.field(), "input: {}", input);
+        };
+        expect_err("", TooShort, Some(Field::Day));
+        expect_err(
+            "Mon, 3 Feb 2015 04:05:06 +0000",
+            Inconsistent,
+            Some(Field::Day),
+        );
+        expect_err("Tue 3 Feb 2015 04:05:06 +0000", Invalid, None);
+        expect_err("3Feb 2015 04:05:06 +0000", Invalid, Some(Field::Month));
+        expect_err("3 Feb 5 04:05:06 +0000", Invalid, Some(Field::Year));
+        expect_err("3 Feb 2015 4:05:06 +0000", Invalid, Some(Field::Hour));
+        expect_err("3 Feb 2015 04:05:06", TooShort, None);
+        expect_err("3 Feb 2015 04:05:06 +000", TooShort, None);
+        expect_err("3 Feb 2015 04:05:06 +2400", OutOfRange, None);
+        expect_err("3 Feb 2015 04:05:06 +0060", OutOfRange, None);
+        expect_err("3 Feb 2015 04:05:06 CET", Invalid, None);
+        expect_err("3 Feb 2015 04:05:06 J", Invalid, None);
+        expect_err("3 Feb 2015 04:05:06 +0000 x", TooLong, None);
+        expect_err("29 Feb 2015 04:05:06 +0000", OutOfRange, Some(Field::Day));
+        expect_err("3 Feb 2015 04:05:06 +0000 (UTC", TooShort, None);
+        expect_err("3 Feb 2015 04:05:06 +0000 ((UTC)", TooShort, None);
+        expect_err("3 Feb 2015 04:05:06 +0000 (UTC\\)", TooShort, None);
+        expect_err("3 Feb 2015 04:05:06 +0000 (UTC))", TooLong, None);
+        expect_err("3 Feb 2015 04:05:06 +0000 (UTC) x", TooLong, None);
+    }
+
+    #[test]
+    fn test_parse_rfc2822_error_span() {
+        let expect_span = |input: &str, span: std::ops::Range<usize>, expected| {
+            let e = CivilSecond::parse_rfc2822(input).unwrap_err();
+            assert_eq!(span, e.span(), "input: {}", input);
+            assert_eq!(expected, e.expected(), "input: {}", input);
+        };
+        expect_span("3 Fub 2015 04:05:06 +0000", 2..5, Some(Expected::MonthName));
+        expect_span(
+            "3 Feb 2015 04:05:06 +2400",
+            21..23,
+            Some(Expected::Range { min: 0, max: 23 }),
+        );
+        expect_span("3 Feb 2015 04:05:06 CET", 20..23, Some(Expected::Zone));
+        expect_span("3 Feb 2015 04:05:06 +0000 x", 26..27, Some(Expected::End));
+        expect_span(
+            "3 Feb 2015 04:05:06 +0000 (UTC",
+            30..30,
+            Some(Expected::Char(')')),
+        );
+        expect_span(
+            "3 Feb 2015 04:05:06 +0000 (UTC))",
+            31..32,
+            Some(Expected::End),
+        );
+        expect_span(
+            "29 Feb 2015 04:05:06 +0000",
+            0..2,
+            Some(Expected::Range { min: 1, max: 28 }),
+        );
+    }
+}
@@ -0,0 +1,352 @@
+//! Timestamps of syslog messages.
+
+use crate::core::{DiffType, Field};
+use crate::format::{Item, Spec};
+use crate::offset::UtcOffset;
+use crate::parse::{
+    scan_items, set, Expected, ParseError, ParseErrorKind, ParseMode, ParseOptions, Parsed, Scanner,
+};
+use crate::CivilSecond;
+
+/// RFC 3164 timestamp, e.g. "Feb  3 04:05:06".
+const RFC3164_TIMESTAMP: [Item<'static>; 9] = [
+    Item::Spec(Spec::MonthShort),
+    Item::Literal(" "),
+    Item::Spec(Spec::DaySpacePadded),
+    Item::Literal(" "),
+    Item::Spec(Spec::Hour),
+    Item::Literal(":"),
+    Item::Spec(Spec::Minute),
+    Item::Literal(":"),
+    Item::Spec(Spec::Second),
+];
+
+/// Maximum digits of the fractional second.
+const MAX_FRACTION_DIGITS: usize = 9;
+
+/// The timestamp of a syslog message.
+///
+/// An RFC 5424 timestamp is an RFC 3339 date-time with an optional
+/// fractional second and a UTC offset, e.g.
+/// "2015-02-03T04:05:06.789+08:00". An RFC 3164 timestamp is the local time
+/// of the sender without a year, e.g. "Feb  3 04:05:06", so its year is
+/// inferred from a reference time.
+///
+/// ```rust
+/// use civil_time::{CivilSecond, SyslogTimestamp, UtcOffset};
+///
+/// let ts = SyslogTimestamp::parse_rfc5424("2015-02-03T04:05:06.789+08:00").unwrap();
+/// assert_eq!(CivilSecond::new(2015, 2, 3, 4, 5, 6), ts.civil_second());
+/// assert_eq!(789_000_000, ts.nanosecond());
+/// assert_eq!(Some(UtcOffset::from_minutes(480)), ts.offset());
+///
+/// // December logs read in January are of the last year.
+/// let reference = CivilSecond::new(2016, 1, 2, 0, 0, 0);
+/// let ts = SyslogTimestamp::parse_rfc3164("Dec 31 23:59:59", reference).unwrap();
+/// assert_eq!(CivilSecond::new(2015, 12, 31, 23, 59, 59), ts.civil_second());
+/// assert_eq!(None, ts.offset());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyslogTimestamp {
+    cs: CivilSecond,
+    nanosecond: u32,
+    offset: Option<UtcOffset>,
+}
+
+impl SyslogTimestamp {
+    /// Returns the civil time of the timestamp.
+    pub const fn civil_second(&self) -> CivilSecond {
+        self.cs
+    }
+
+    /// Returns the fractional second in nanoseconds, which is always 0 for
+    /// RFC 3164 timestamps.
+    pub const fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+
+    /// Returns the UTC offset of the timestamp, or `None` for RFC 3164
+    /// timestamps, which are in the unknown local time of the sender.
+    pub const fn offset(&self) -> Option<UtcOffset> {
+        self.offset
+    }
+
+    /// Parses an RFC 3164 timestamp, e.g. "Feb  3 04:05:06", whose day is
+    /// padded by a space.
+    ///
+    /// Of the years around the year of `reference`, the timestamp takes the
+    /// one that puts it closest to `reference`, and the earlier one if two
+    /// years are equally close. A date that is invalid in all these years,
+    /// such as "Feb 29" far from a leap year, is an error.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilSecond, SyslogTimestamp};
+    ///
+    /// let reference = CivilSecond::new(2015, 12, 31, 23, 0, 0);
+    /// let ts = SyslogTimestamp::parse_rfc3164("Jan  1 00:00:01", reference).unwrap();
+    /// assert_eq!(CivilSecond::new(2016, 1, 1, 0, 0, 1), ts.civil_second());
+    ///
+    /// let ts = SyslogTimestamp::parse_rfc3164("Oct  3 04:05:06", reference).unwrap();
+    /// assert_eq!(CivilSecond::new(2015, 10, 3, 4, 5, 6), ts.civil_second());
+    /// ```
+    pub fn parse_rfc3164(input: &str, reference: CivilSecond) -> Result<Self, ParseError> {
+        let options = ParseOptions {
+            exact_width: true,
+            mode: ParseMode::Strict,
+            ..ParseOptions::default()
+        };
+        let mut parsed = scan_items(input, &RFC3164_TIMESTAMP, options)?;
+
+        let year = reference.year();
+        let mut closest: Option<(DiffType, CivilSecond)> = None;
+        let mut error = None;
+        for y in (-1..=1).filter_map(|i| year.checked_add(i)) {
+            parsed.year = Some(y);
+            match parsed.resolve() {
+                Ok(fields) => {
+                    let cs = CivilSecond::from_fields(fields);
+                    let distance = (cs - reference).saturating_abs();
+                    if closest.is_none_or(|(min, _)| distance < min) {
+                        closest = Some((distance, cs));
+                    }
+                }
+                Err(e) => {
+                    if y == year {
+                        error = Some(e);
+                    }
+                }
+            }
+        }
+
+        match (closest, error) {
+            (Some((_, cs)), _) => Ok(SyslogTimestamp {
+                cs,
+                nanosecond: 0,
+                offset: None,
+            }),
+            (None, e) => Err(e
+                .unwrap_or(ParseError::new(
+                    ParseErrorKind::OutOfRange,
+                    Some(Field::Year),
+                ))
+                .at(0, input.len())),
+        }
+    }
+
+    /// Parses an RFC 5424 timestamp, which is an RFC 3339 date-time with an
+    /// upper case "T" and "Z", e.g. "2015-02-03T04:05:06.789Z".
+    ///
+    /// The fractional second may have up to 9 digits, more than the 6 digits
+    /// RFC 5424 allows. Leap seconds and the NILVALUE "-" are errors.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilSecond, SyslogTimestamp, UtcOffset};
+    ///
+    /// let ts = SyslogTimestamp::parse_rfc5424("2015-02-03T04:05:06.000789-05:00").unwrap();
+    /// assert_eq!(CivilSecond::new(2015, 2, 3, 4, 5, 6), ts.civil_second());
+    /// assert_eq!(789_000, ts.nanosecond());
+    /// assert_eq!(Some(UtcOffset::from_minutes(-300)), ts.offset());
+    /// ```
+    pub fn parse_rfc5424(input: &str) -> Result<Self, ParseError> {
+        Self::parse_rfc5424_inner(input).map_err(|e| e.at(0, input.len()))
+    }
+
+    fn parse_rfc5424_inner(input: &str) -> Result<Self, ParseError> {
+        let mut sc = Scanner::new(input);
+        let mut parsed = Parsed::new(ParseMode::Strict);
+        let year = sc.number(4, 4, Field::Year)?;
+        set(&mut parsed.year, year, Field::Year)?;
+        parsed.set_span(Field::Year, 0, sc.pos());
+        sc.literal("-")?;
+        parsed.parse_spec(&mut sc, Spec::Month, 2, 0)?;
+        sc.literal("-")?;
+        parsed.parse_spec(&mut sc, Spec::Day, 2, 0)?;
+        sc.literal("T")?;
+        parsed.parse_spec(&mut sc, Spec::Hour, 2, 0)?;
+        sc.literal(":")?;
+        parsed.parse_spec(&mut sc, Spec::Minute, 2, 0)?;
+        sc.literal(":")?;
+        parsed.parse_spec(&mut sc, Spec::Second, 2, 0)?;
+
+        let mut nanosecond = 0;
+        if sc.peek() == Some(b'.') {
+            sc.literal(".")?;
+            let start = sc.pos();
+            let v = sc.number(1, MAX_FRACTION_DIGITS, Field::Second)?;
+            let digits = sc.pos() - start;
+            if matches!(sc.peek(), Some(c) if c.is_ascii_digit()) {
+                return Err(
+                    ParseError::new(ParseErrorKind::TooLong, Some(Field::Second))
+                        .at(sc.pos(), sc.pos() + 1)
+                        .expecting(Expected::Zone),
+                );
+            }
+            nanosecond = v as u32 * 10u32.pow((MAX_FRACTION_DIGITS - digits) as u32);
+        }
+
+        let offset = if sc.peek() == Some(b'Z') {
+            sc.literal("Z")?;
+            UtcOffset::UTC
+        } else {
+            UtcOffset::parse(&mut sc, true)?
+        };
+        if !sc.at_end() {
+            return Err(sc.trailing());
+        }
+
+        Ok(SyslogTimestamp {
+            cs: CivilSecond::from_fields(parsed.resolve()?),
+            nanosecond,
+            offset: Some(offset),
+        })
+    }
+
+    /// Parses either an RFC 5424 or an RFC 3164 timestamp, telling them apart
+    /// by the leading digit of RFC 5424. `reference` is only used to infer
+    /// the year of RFC 3164 timestamps.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilSecond, SyslogTimestamp};
+    ///
+    /// let reference = CivilSecond::new(2015, 6, 1, 0, 0, 0);
+    /// let a = SyslogTimestamp::parse("Feb  3 04:05:06", reference).unwrap();
+    /// let b = SyslogTimestamp::parse("2015-02-03T04:05:06Z", reference).unwrap();
+    /// assert_eq!(a.civil_second(), b.civil_second());
+    /// ```
+    pub fn parse(input: &str, reference: CivilSecond) -> Result<Self, ParseError> {
+        match input.as_bytes().first() {
+            Some(c) if c.is_ascii_digit() => Self::parse_rfc5424(input),
+            _ => Self::parse_rfc3164(input, reference),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rfc3164() {
+        let parse = |input: &str, reference: CivilSecond| {
+            SyslogTimestamp::parse_rfc3164(input, reference)
+                .unwrap()
+                .civil_second()
+        };
+        let reference = CivilSecond::new(2016, 1, 1, 0, 0, 30);
+        let table = [
+            (
+                "Dec 31 23:59:59",
+                CivilSecond::new(2015, 12, 31, 23, 59, 59),
+            ),
+            ("Jan  1 00:00:00", CivilSecond::new(2016, 1, 1, 0, 0, 0)),
+            ("Jan  1 00:01:00", CivilSecond::new(2016, 1, 1, 0, 1, 0)),
+            ("Jul  2 00:00:00", CivilSecond::new(2016, 7, 2, 0, 0, 0)),
+            ("Jul  3 00:00:00", CivilSecond::new(2015, 7, 3, 0, 0, 0)),
+            ("Feb 29 00:00:00", CivilSecond::new(2016, 2, 29, 0, 0, 0)),
+        ];
+        for (input, expect) in table.iter() {
+            assert_eq!(*expect, parse(input, reference), "{}", input);
+        }
+
+        // Leap day of the next or the last year.
+        let reference = CivilSecond::new(2015, 12, 1, 0, 0, 0);
+        assert_eq!(
+            CivilSecond::new(2016, 2, 29, 0, 0, 0),
+            parse("Feb 29 00:00:00", reference)
+        );
+        let reference = CivilSecond::new(2017, 1, 1, 0, 0, 0);
+        assert_eq!(
+            CivilSecond::new(2016, 2, 29, 0, 0, 0),
+            parse("Feb 29 00:00:00", reference)
+        );
+    }
+
+    #[test]
+    fn test_parse_rfc3164_error() {
+        use ParseErrorKind::*;
+
+        let reference = CivilSecond::new(2018, 6, 1, 0, 0, 0);
+        let expect_err = |input: &str, kind: ParseErrorKind, field: Option<Field>| {
+            let e = SyslogTimestamp::parse_rfc3164(input, reference).unwrap_err();
+            assert_eq!(kind, e.kind(), "input: {}", input);
+            assert_eq!(field, e.field(), "input: {}", input);
+        };
+        expect_err("", TooShort, Some(Field::Month));
+        expect_err("Feb 3 04:05:06", Invalid, Some(Field::Day));
+        expect_err("Feb 30 04:05:06", OutOfRange, Some(Field::Day));
+        expect_err("Feb 29 04:05:06", OutOfRange, Some(Field::Day));
+        expect_err("Feb  3 24:05:06", OutOfRange, Some(Field::Hour));
+        expect_err("Feb  3 04:05:06 host", TooLong, None);
+    }
+
+    #[test]
+    fn test_parse_rfc5424() {
+        let table = [
+            ("2015-02-03T04:05:06Z", 0, 0),
+            ("2015-02-03T04:05:06.7Z", 700_000_000, 0),
+            ("2015-02-03T04:05:06.000007+00:00", 7_000, 0),
+            ("2015-02-03T04:05:06.123456789-23:59", 123_456_789, -1439),
+            ("2015-02-03T04:05:06+05:30", 0, 330),
+        ];
+        for (input, nanosecond, minutes) in table.iter() {
+            let ts = SyslogTimestamp::parse_rfc5424(input).unwrap();
+            assert_eq!(
+                CivilSecond::new(2015, 2, 3, 4, 5, 6),
+                ts.civil_second(),
+                "{}",
+                input
+            );
+            assert_eq!(*nanosecond, ts.nanosecond(), "{}", input);
+            assert_eq!(
+                Some(UtcOffset::from_minutes(*minutes)),
+                ts.offset(),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_rfc5424_error() {
+        use ParseErrorKind::*;
+
+        let expect_err = |input: &str, kind: ParseErrorKind, field: Option<Field>| {
+            let e = SyslogTimestamp::parse_rfc5424(input).unwrap_err();
+            assert_eq!(kind, e.kind(), "input: {}", input);
+            assert_eq!(field, e.field(), "input: {}", input);
+        };
+        expect_err("-", Invalid, Some(Field::Year));
+        expect_err("2015-02-03", TooShort, None);
+        expect_err("2015-02-03t04:05:06Z", Invalid, None);
+        expect_err("2015-02-03T04:05:06", TooShort, None);
+        expect_err("2015-02-03T04:05:06z", Invalid, None);
+        expect_err("2015-02-03T04:05:06+0800", Invalid, None);
+        expect_err("2015-02-03T04:05:06+24:00", OutOfRange, None);
+        expect_err("2015-02-03T04:05:06.Z", Invalid, Some(Field::Second));
+        expect_err(
+            "2015-02-03T04:05:06.1234567890Z",
+            TooLong,
+            Some(Field::Second),
+        );
+        expect_err("2015-02-03T04:05:60Z", OutOfRange, Some(Field::Second));
+        expect_err("2015-02-30T04:05:06Z", OutOfRange, Some(Field::Day));
+        expect_err("2015-02-03T04:05:06Z ", TooLong, None);
+    }
+
+    #[test]
+    fn test_parse() {
+        let reference = CivilSecond::new(2016, 1, 1, 0, 0, 0);
+        let ts = SyslogTimestamp::parse("Dec 31 23:59:59", reference).unwrap();
+        assert_eq!(
+            CivilSecond::new(2015, 12, 31, 23, 59, 59),
+            ts.civil_second()
+        );
+        assert_eq!(None, ts.offset());
+        let ts = SyslogTimestamp::parse("1999-12-31T23:59:59Z", reference).unwrap();
+        assert_eq!(
+            CivilSecond::new(1999, 12, 31, 23, 59, 59),
+            ts.civil_second()
+        );
+        assert_eq!(Some(UtcOffset::UTC), ts.offset());
+    }
+}
@@ -23,6 +23,17 @@ pub enum Weekday {
     Sun,
 }
 
+/// All weekdays, starting from Monday.
+pub(crate) const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
 impl Weekday {
     pub(crate) const fn from_second(cs: CivilSecond) -> Self {
         const WEEKDAY_BY_MON_OFF: [Weekday; 13] = [
@@ -54,11 +65,20 @@ impl Weekday {
         // Can't call PartialEq/Eq in const function.
         *self as usize == other as usize
     }
-}
 
-impl fmt::Debug for Weekday {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match *self {
+    /// Returns the day number of the weekday, from 1 (Monday) to 7 (Sunday).
+    pub(crate) const fn number_from_monday(&self) -> i32 {
+        *self as i32 + 1
+    }
+
+    /// Returns the number of days since Sunday, from 0 (Sunday) to 6 (Saturday).
+    pub(crate) const fn days_from_sunday(&self) -> i32 {
+        self.number_from_monday() % 7
+    }
+
+    /// Returns the abbreviated English name.
+    pub(crate) const fn short_name(&self) -> &'static str {
+        match *self {
             Weekday::Mon => "Mon",
             Weekday::Tue => "Tue",
             Weekday::Wed => "Wed",
@@ -66,7 +86,138 @@ impl fmt::Debug for Weekday {
             Weekday::Fri => "Fri",
             Weekday::Sat => "Sat",
             Weekday::Sun => "Sun",
-        })
+        }
+    }
+
+    /// Returns the full English name.
+    pub(crate) const fn long_name(&self) -> &'static str {
+        match *self {
+            Weekday::Mon => "Monday",
+            Weekday::Tue => "Tuesday",
+            Weekday::Wed => "Wednesday",
+            Weekday::Thu => "Thursday",
+            Weekday::Fri => "Friday",
+            Weekday::Sat => "Saturday",
+            Weekday::Sun => "Sunday",
+        }
+    }
+}
+
+impl fmt::Debug for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.short_name())
+    }
+}
+
+/// Returns the week of year (from 0 to 53) of the given civil time, weeks
+/// start on the weekday `first` and days before the first `first` of the year
+/// are in week 0.
+pub(crate) const fn week_of_year(cs: CivilSecond, first: Weekday) -> i32 {
+    let days_from_first = (cs.weekday() as i32 - first as i32 + 7) % 7;
+    (cs.yearday() + 6 - days_from_first) / 7
+}
+
+/// Returns the ISO 8601 week-numbering year and week number (from 1 to 53)
+/// of the given day.
+///
+/// Weeks start on Monday and the first week of a year is the week that
+/// contains the first Thursday of that year.
+///
+/// The week is computed within the calendar year of the day, so that the
+/// year never overflows. The days of [`YearType::MIN`] before its first week
+/// belong to a week-numbering year that is not representable, they saturate
+/// to week 0 of [`YearType::MIN`] instead.
+pub(crate) const fn iso_week(cd: CivilDay) -> (YearType, i32) {
+    let y = cd.year();
+    let week = week_in_year(cd);
+    if week < 1 {
+        if y == YearType::MIN {
+            return (y, 0);
+        }
+        (y - 1, weeks_in_year(y - 1))
+    } else if week > weeks_in_year(y) && y != YearType::MAX {
+        (y + 1, 1)
+    } else {
+        (y, week)
+    }
+}
+
+/// Returns the ISO week of the day counted from the first week of its
+/// calendar year, which is 0 or 53 for the days in the weeks of the
+/// neighbouring years.
+const fn week_in_year(cd: CivilDay) -> i32 {
+    (cd.yearday() - cd.weekday().number_from_monday() + 10) / 7
+}
+
+/// Returns the number of ISO weeks in the week-numbering year `y`, which is
+/// the week of December 28th.
+const fn weeks_in_year(y: YearType) -> i32 {
+    week_in_year(CivilDay::new(y, 12, 28))
+}
+
+/// The number of ISO weeks in 400 years, which are exactly 146097 days.
+const WEEKS_PER_400_YEARS: DiffType = 20871;
+
+/// Returns the ISO week date as a number of years after `year`, which is a
+/// multiple of 400, and a day of January of that year, which fits without
+/// overflow for any week.
+const fn iso_week_date_offset(year: YearType, week: DiffType, wd: Weekday) -> (DiffType, DiffType) {
+    // January 4th is always in the first week, and its weekday repeats every
+    // 400 years.
+    let jan4 = CivilDay::new(year, 1, 4);
+    let years = week.div_euclid(WEEKS_PER_400_YEARS) * 400;
+    let week = week.rem_euclid(WEEKS_PER_400_YEARS);
+    (
+        years,
+        7 * week - 3 - jan4.weekday() as DiffType + wd as DiffType,
+    )
+}
+
+impl CivilDay {
+    /// Construct a [CivilDay] instance by given ISO 8601 week-numbering year
+    /// `year`, week `week` and weekday `wd`.
+    ///
+    /// Out-of-range weeks are normalized like the fields of
+    /// [`new()`](CivilDay::new), so week 53 of a year of 52 weeks is the
+    /// first week of the next year, and days beyond [`CivilDay::MAX`] or
+    /// [`CivilDay::MIN`] wrap around. See
+    /// [`checked_from_iso_week_date()`](CivilDay::checked_from_iso_week_date)
+    /// to detect the overflow.
+    ///
+    /// ```rust
+    /// use civil_time::{CivilDay, Weekday};
+    ///
+    /// let d = CivilDay::from_iso_week_date(2015, 1, Weekday::Mon);
+    /// assert_eq!(CivilDay::new(2014, 12, 29), d);
+    /// ```
+    pub const fn from_iso_week_date(year: YearType, week: DiffType, wd: Weekday) -> Self {
+        let (years, day) = iso_week_date_offset(year, week, wd);
+        CivilDay::new(year.wrapping_add(years), 1, 1)
+            .overflowing_add(day - 1)
+            .0
+    }
+
+    /// Same as [`from_iso_week_date()`](CivilDay::from_iso_week_date), but
+    /// returns `None` if the day is beyond [`CivilDay::MAX`] or
+    /// [`CivilDay::MIN`].
+    ///
+    /// ```rust
+    /// use civil_time::{CivilDay, Weekday};
+    ///
+    /// let d = CivilDay::checked_from_iso_week_date(2015, 1, Weekday::Mon);
+    /// assert_eq!(Some(CivilDay::new(2014, 12, 29)), d);
+    /// assert_eq!(None, CivilDay::checked_from_iso_week_date(i64::MAX, 54, Weekday::Mon));
+    /// ```
+    pub const fn checked_from_iso_week_date(
+        year: YearType,
+        week: DiffType,
+        wd: Weekday,
+    ) -> Option<Self> {
+        let (years, day) = iso_week_date_offset(year, week, wd);
+        match year.checked_add(years) {
+            Some(year) => CivilDay::new(year, 1, 1).checked_add(day - 1),
+            None => None,
+        }
     }
 }
 
@@ -152,6 +303,27 @@ macro_rules! impl_weekday_ops {
                 let cd = CivilDay::from_fields(self.0);
                 prev_weekday(cd, wd)
             }
+
+            /// Returns the ISO 8601 week date of the day of given civil time,
+            /// as the week-numbering year, the week (from 1 to 53) and the
+            /// weekday.
+            ///
+            /// The week-numbering year differs from the calendar year near
+            /// the start and the end of a year. The days of [`YearType::MIN`]
+            /// before its first week are in week 0 of [`YearType::MIN`], as
+            /// their week-numbering year is not representable.
+            ///
+            /// ```rust
+            /// use civil_time::{CivilDay, Weekday};
+            ///
+            /// let d = CivilDay::new(2014, 12, 29);
+            /// assert_eq!((2015, 1, Weekday::Mon), d.iso_week_date());
+            /// ```
+            pub const fn iso_week_date(self) -> (YearType, i32, Weekday) {
+                let cd = CivilDay::from_fields(self.0);
+                let (year, week) = iso_week(cd);
+                (year, week, cd.weekday())
+            }
         }
     };
 }
@@ -230,4 +402,122 @@ mod tests {
         let thanksgiving = thursday + 7 * 3;
         expect_eq("2014-11-27", thanksgiving);
     }
+
+    #[test]
+    fn test_iso_week_date() {
+        // (date, ISO week date)
+        let table = [
+            ((2014, 12, 28), (2014, 52, Weekday::Sun)),
+            ((2014, 12, 29), (2015, 1, Weekday::Mon)),
+            ((2015, 1, 1), (2015, 1, Weekday::Thu)),
+            ((2015, 12, 31), (2015, 53, Weekday::Thu)),
+            ((2016, 1, 3), (2015, 53, Weekday::Sun)),
+            ((2016, 1, 4), (2016, 1, Weekday::Mon)),
+            ((2008, 12, 29), (2009, 1, Weekday::Mon)),
+            ((2010, 1, 3), (2009, 53, Weekday::Sun)),
+            ((0, 1, 1), (-1, 52, Weekday::Sat)),
+        ];
+        for ((y, m, d), (iy, w, wd)) in table.iter() {
+            let cd = CivilDay::new(*y, *m, *d);
+            assert_eq!((*iy, *w, *wd), cd.iso_week_date(), "{:?}", cd);
+            assert_eq!(cd, CivilDay::from_iso_week_date(*iy, *w as DiffType, *wd));
+        }
+
+        let ss = CivilSecond::new(2014, 12, 29, 4, 5, 6);
+        assert_eq!((2015, 1, Weekday::Mon), ss.iso_week_date());
+
+        // Every day of several years.
+        let start = CivilDay::new(2014, 1, 1);
+        for i in 0..365 * 4 {
+            let cd = start + i;
+            let (y, w, wd) = cd.iso_week_date();
+            assert_eq!(cd, CivilDay::from_iso_week_date(y, w as DiffType, wd));
+        }
+
+        // Out-of-range weeks are normalized.
+        expect_eq(
+            "2016-01-04",
+            CivilDay::from_iso_week_date(2016, 1, Weekday::Mon),
+        );
+        expect_eq(
+            "2016-01-04",
+            CivilDay::from_iso_week_date(2015, 54, Weekday::Mon),
+        );
+        expect_eq(
+            "2014-12-29",
+            CivilDay::from_iso_week_date(2014, 53, Weekday::Mon),
+        );
+        expect_eq(
+            "2014-12-22",
+            CivilDay::from_iso_week_date(2015, 0, Weekday::Mon),
+        );
+    }
+
+    #[test]
+    fn test_iso_week_date_limits() {
+        let (min, max) = (YearType::MIN, YearType::MAX);
+
+        // January 1st of the minimum year is a Sunday, before its first week.
+        assert_eq!((min, 0, Weekday::Sun), CivilDay::MIN.iso_week_date());
+        assert_eq!((min, 1, Weekday::Mon), (CivilDay::MIN + 1).iso_week_date());
+        assert_eq!((min, 0, Weekday::Sun), CivilSecond::MIN.iso_week_date());
+        // December 31st of the maximum year is a Thursday, in its last week.
+        assert_eq!((max, 53, Weekday::Thu), CivilDay::MAX.iso_week_date());
+        assert_eq!((max, 53, Weekday::Thu), CivilSecond::MAX.iso_week_date());
+
+        for cd in [
+            CivilDay::MIN,
+            CivilDay::MIN + 1,
+            CivilDay::MAX,
+            CivilDay::MAX - 3,
+        ] {
+            let (y, w, wd) = cd.iso_week_date();
+            assert_eq!(cd, CivilDay::from_iso_week_date(y, w as DiffType, wd));
+        }
+
+        // Weeks far out of range are normalized without overflow, by whole
+        // 400-year cycles of 20871 weeks.
+        let cycles = DiffType::MAX / WEEKS_PER_400_YEARS;
+        let far = CivilDay::from_iso_week_date(2015, DiffType::MAX, Weekday::Mon);
+        assert_eq!(
+            CivilDay::from_iso_week_date(
+                2015 + cycles * 400,
+                DiffType::MAX % WEEKS_PER_400_YEARS,
+                Weekday::Mon
+            ),
+            far
+        );
+        assert_eq!(Weekday::Mon, far.weekday());
+        assert_eq!(
+            Some(far),
+            CivilDay::checked_from_iso_week_date(2015, DiffType::MAX, Weekday::Mon)
+        );
+        let d = CivilDay::from_iso_week_date(2015, -WEEKS_PER_400_YEARS + 1, Weekday::Thu);
+        assert_eq!(CivilDay::new(1615, 1, 1), d);
+
+        // Days beyond the limits wrap around like new().
+        assert_eq!(
+            CivilDay::MAX.overflowing_add(1).0,
+            CivilDay::from_iso_week_date(max, 53, Weekday::Fri)
+        );
+        assert_eq!(
+            CivilDay::MIN.overflowing_sub(1).0,
+            CivilDay::from_iso_week_date(min, 0, Weekday::Sat)
+        );
+        assert_eq!(
+            CivilDay::from_iso_week_date(max, 1, Weekday::Mon)
+                .overflowing_add(59 * 7)
+                .0,
+            CivilDay::from_iso_week_date(max, 60, Weekday::Mon)
+        );
+        let checked = CivilDay::checked_from_iso_week_date;
+        assert_eq!(Some(CivilDay::MAX), checked(max, 53, Weekday::Thu));
+        assert_eq!(None, checked(max, 53, Weekday::Fri));
+        assert_eq!(None, checked(max, 60, Weekday::Mon));
+        assert_eq!(Some(CivilDay::MIN), checked(min, 0, Weekday::Sun));
+        assert_eq!(None, checked(min, 0, Weekday::Sat));
+        assert_eq!(None, checked(min, -1, Weekday::Mon));
+        assert_eq!(None, checked(min, DiffType::MIN, Weekday::Sun));
+        assert_eq!(None, checked(max, DiffType::MAX, Weekday::Sun));
+    }
 }